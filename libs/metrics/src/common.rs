@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
 use metrics::{Key, Label};
 use serde::{Deserialize, Serialize};
@@ -35,12 +36,20 @@ pub(crate) struct Histogram {
     pub count: u64,
 }
 
+#[derive(Serialize, Clone)]
+pub(crate) struct Summary {
+    pub quantiles: Vec<(f64, f64)>,
+    pub sum: f64,
+    pub count: u64,
+}
+
 #[derive(Serialize, Clone)]
 #[serde(untagged)]
 pub(crate) enum MetricValue {
     Counter(u64),
     Gauge(f64),
     Histogram(Histogram),
+    Summary(Summary),
 }
 
 #[derive(Serialize, Clone)]
@@ -98,6 +107,51 @@ pub(crate) struct Snapshot {
     pub counters: Vec<Metric>,
     pub gauges: Vec<Metric>,
     pub histograms: Vec<Metric>,
+    pub summaries: Vec<Metric>,
+}
+
+impl Snapshot {
+    /// Computes the per-second rate of change for each counter present in both `self` and
+    /// `previous`, using `elapsed` as the time delta between the two snapshots. This lets
+    /// lightweight embedders that don't run a full Prometheus scrape loop derive rate gauges
+    /// from two point-in-time snapshots, without depending on a metrics backend.
+    ///
+    /// Counters that are only present in one of the two snapshots, or a zero or negative
+    /// `elapsed`, are skipped.
+    pub(crate) fn counter_rates_per_second(&self, previous: &Snapshot, elapsed: Duration) -> Vec<Metric> {
+        let elapsed_secs = elapsed.as_secs_f64();
+
+        if elapsed_secs <= 0.0 {
+            return Vec::new();
+        }
+
+        self.counters
+            .iter()
+            .filter_map(|current| {
+                let MetricValue::Counter(current_value) = current.value else {
+                    return None;
+                };
+
+                let previous_counter = previous
+                    .counters
+                    .iter()
+                    .find(|c| c.key == current.key && c.labels == current.labels)?;
+
+                let MetricValue::Counter(previous_value) = previous_counter.value else {
+                    return None;
+                };
+
+                let rate = current_value.saturating_sub(previous_value) as f64 / elapsed_secs;
+
+                Some(Metric {
+                    key: current.key.clone(),
+                    labels: current.labels.clone(),
+                    description: current.description.clone(),
+                    value: MetricValue::Gauge(rate),
+                })
+            })
+            .collect()
+    }
 }
 
 impl From<Key> for KeyLabels {
@@ -135,3 +189,82 @@ impl From<metrics_util::Histogram> for Histogram {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter(key: &str, value: u64) -> Metric {
+        Metric {
+            key: key.to_string(),
+            labels: HashMap::new(),
+            value: MetricValue::Counter(value),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn counter_rates_per_second_computes_delta_over_elapsed() {
+        let previous = Snapshot {
+            counters: vec![counter("prisma_client_queries_total", 100)],
+            gauges: Vec::new(),
+            histograms: Vec::new(),
+            summaries: Vec::new(),
+        };
+
+        let current = Snapshot {
+            counters: vec![counter("prisma_client_queries_total", 600)],
+            gauges: Vec::new(),
+            histograms: Vec::new(),
+            summaries: Vec::new(),
+        };
+
+        let rates = current.counter_rates_per_second(&previous, Duration::from_secs(5));
+
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].key, "prisma_client_queries_total");
+        assert!(matches!(rates[0].value, MetricValue::Gauge(rate) if rate == 100.0));
+    }
+
+    #[test]
+    fn counter_rates_per_second_skips_counters_missing_from_previous_snapshot() {
+        let previous = Snapshot {
+            counters: Vec::new(),
+            gauges: Vec::new(),
+            histograms: Vec::new(),
+            summaries: Vec::new(),
+        };
+
+        let current = Snapshot {
+            counters: vec![counter("prisma_client_queries_total", 10)],
+            gauges: Vec::new(),
+            histograms: Vec::new(),
+            summaries: Vec::new(),
+        };
+
+        let rates = current.counter_rates_per_second(&previous, Duration::from_secs(1));
+
+        assert!(rates.is_empty());
+    }
+
+    #[test]
+    fn counter_rates_per_second_returns_nothing_for_zero_elapsed() {
+        let previous = Snapshot {
+            counters: vec![counter("prisma_client_queries_total", 100)],
+            gauges: Vec::new(),
+            histograms: Vec::new(),
+            summaries: Vec::new(),
+        };
+
+        let current = Snapshot {
+            counters: vec![counter("prisma_client_queries_total", 600)],
+            gauges: Vec::new(),
+            histograms: Vec::new(),
+            summaries: Vec::new(),
+        };
+
+        let rates = current.counter_rates_per_second(&previous, Duration::ZERO);
+
+        assert!(rates.is_empty());
+    }
+}
@@ -22,6 +22,7 @@ pub(crate) fn metrics_to_json(snapshot: Snapshot) -> Value {
         counters,
         histograms,
         gauges,
+        summaries,
     } = snapshot;
 
     // For json output we convert the histogram where a value is only recorded in a single bucket
@@ -60,6 +61,7 @@ pub(crate) fn metrics_to_json(snapshot: Snapshot) -> Value {
         counters,
         histograms: normalised_histograms,
         gauges,
+        summaries,
     };
 
     serde_json::to_value(snapshot).unwrap()
@@ -70,6 +72,7 @@ pub(crate) fn metrics_to_prometheus(snapshot: Snapshot) -> String {
         counters,
         histograms,
         gauges,
+        summaries,
     } = snapshot;
 
     let mut output = String::new();
@@ -148,5 +151,177 @@ pub(crate) fn metrics_to_prometheus(snapshot: Snapshot) -> String {
         output.push('\n');
     }
 
+    for summary in summaries {
+        let desc = sanitize_description(summary.description.as_str());
+        write_help_line(&mut output, summary.key.as_str(), desc.as_str());
+
+        write_type_line(&mut output, summary.key.as_str(), "summary");
+        let labels = create_label_string(&summary.labels);
+
+        if let MetricValue::Summary(summary_value) = summary.value {
+            for (quantile, value) in summary_value.quantiles {
+                write_metric_line(
+                    &mut output,
+                    summary.key.as_str(),
+                    None,
+                    &labels,
+                    Some(("quantile", quantile)),
+                    value,
+                );
+            }
+
+            write_metric_line::<&str, f64>(
+                &mut output,
+                summary.key.as_str(),
+                Some("sum"),
+                &labels,
+                None,
+                summary_value.sum,
+            );
+            write_metric_line::<&str, u64>(
+                &mut output,
+                summary.key.as_str(),
+                Some("count"),
+                &labels,
+                None,
+                summary_value.count,
+            );
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Renders a snapshot in OpenMetrics text format. This is the same layout as
+/// [`metrics_to_prometheus`], except counters are exposed with the `_total` suffix required by
+/// the OpenMetrics spec, and the output is terminated with `# EOF`.
+pub(crate) fn metrics_to_openmetrics(snapshot: Snapshot) -> String {
+    let Snapshot {
+        counters,
+        histograms,
+        gauges,
+        summaries,
+    } = snapshot;
+
+    let mut output = String::new();
+
+    for counter in counters {
+        let desc = sanitize_description(counter.description.as_str());
+        write_help_line(&mut output, counter.key.as_str(), desc.as_str());
+
+        write_type_line(&mut output, counter.key.as_str(), "counter");
+        let labels = create_label_string(&counter.labels);
+
+        if let MetricValue::Counter(value) = counter.value {
+            write_metric_line::<&str, u64>(&mut output, counter.key.as_str(), Some("total"), &labels, None, value);
+        }
+        output.push('\n');
+    }
+
+    for gauge in gauges {
+        let desc = sanitize_description(gauge.description.as_str());
+        write_help_line(&mut output, gauge.key.as_str(), desc.as_str());
+
+        write_type_line(&mut output, gauge.key.as_str(), "gauge");
+        let labels = create_label_string(&gauge.labels);
+
+        if let MetricValue::Gauge(value) = gauge.value {
+            write_metric_line::<&str, f64>(&mut output, gauge.key.as_str(), None, &labels, None, value);
+        }
+        output.push('\n');
+    }
+
+    for histogram in histograms {
+        let desc = sanitize_description(histogram.description.as_str());
+        write_help_line(&mut output, histogram.key.as_str(), desc.as_str());
+
+        write_type_line(&mut output, histogram.key.as_str(), "histogram");
+        let labels = create_label_string(&histogram.labels);
+
+        if let MetricValue::Histogram(histogram_values) = histogram.value {
+            for (le, count) in histogram_values.buckets {
+                write_metric_line(
+                    &mut output,
+                    histogram.key.as_str(),
+                    Some("bucket"),
+                    &labels,
+                    Some(("le", le)),
+                    count,
+                );
+            }
+
+            write_metric_line(
+                &mut output,
+                histogram.key.as_str(),
+                Some("bucket"),
+                &labels,
+                Some(("le", "+Inf")),
+                histogram_values.count,
+            );
+            write_metric_line::<&str, f64>(
+                &mut output,
+                histogram.key.as_str(),
+                Some("sum"),
+                &labels,
+                None,
+                histogram_values.sum,
+            );
+            write_metric_line::<&str, u64>(
+                &mut output,
+                histogram.key.as_str(),
+                Some("count"),
+                &labels,
+                None,
+                histogram_values.count,
+            );
+        }
+
+        output.push('\n');
+    }
+
+    for summary in summaries {
+        let desc = sanitize_description(summary.description.as_str());
+        write_help_line(&mut output, summary.key.as_str(), desc.as_str());
+
+        write_type_line(&mut output, summary.key.as_str(), "summary");
+        let labels = create_label_string(&summary.labels);
+
+        if let MetricValue::Summary(summary_value) = summary.value {
+            for (quantile, value) in summary_value.quantiles {
+                write_metric_line(
+                    &mut output,
+                    summary.key.as_str(),
+                    None,
+                    &labels,
+                    Some(("quantile", quantile)),
+                    value,
+                );
+            }
+
+            write_metric_line::<&str, f64>(
+                &mut output,
+                summary.key.as_str(),
+                Some("sum"),
+                &labels,
+                None,
+                summary_value.sum,
+            );
+            write_metric_line::<&str, u64>(
+                &mut output,
+                summary.key.as_str(),
+                Some("count"),
+                &labels,
+                None,
+                summary_value.count,
+            );
+        }
+
+        output.push('\n');
+    }
+
+    output.push_str("# EOF\n");
+
     output
 }
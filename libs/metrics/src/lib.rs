@@ -27,6 +27,7 @@ mod formatters;
 mod instrument;
 mod recorder;
 mod registry;
+mod summary;
 
 pub mod guards;
 
@@ -38,7 +39,7 @@ pub use metrics::{self, counter, describe_counter, describe_gauge, describe_hist
 
 pub use instrument::*;
 pub use recorder::MetricRecorder;
-pub use registry::MetricRegistry;
+pub use registry::{MetricKind, MetricRegistry};
 
 // Metrics that we emit from the engines, third party metrics emitted by libraries and that we rename are omitted.
 pub const PRISMA_CLIENT_QUERIES_TOTAL: &str = "prisma_client_queries_total"; // counter
@@ -150,6 +151,8 @@ pub enum MetricFormat {
     Json,
     #[serde(alias = "prometheus")]
     Prometheus,
+    #[serde(alias = "openmetrics")]
+    OpenMetrics,
 }
 
 #[cfg(test)]
@@ -209,6 +212,31 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_drain_json() {
+        RT.block_on(async {
+            let metrics = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec());
+            let recorder = MetricRecorder::new(metrics.clone());
+            async move {
+                counter!("test_counter").increment(3);
+
+                let first = metrics.drain_json(Default::default());
+                assert_eq!(first["counters"][0]["value"], 3);
+
+                // The drain reset the counter, so a fresh to_json/drain_json call starts
+                // from zero again instead of continuing to accumulate.
+                assert_eq!(metrics.counter_value("test_counter").unwrap(), 0);
+
+                counter!("test_counter").increment(2);
+
+                let second = metrics.drain_json(Default::default());
+                assert_eq!(second["counters"][0]["value"], 2);
+            }
+            .with_recorder(recorder)
+            .await;
+        });
+    }
+
     #[test]
     fn test_gauges() {
         RT.block_on(async {
@@ -317,6 +345,148 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_custom_histogram_buckets() {
+        RT.block_on(async {
+            let mut bucket_bounds = HashMap::new();
+            bucket_bounds.insert("test_histogram", vec![0.0, 2.0, 4.0, 8.0]);
+
+            let metrics =
+                MetricRegistry::new_with_accept_list_and_buckets(TESTING_ACCEPT_LIST.to_vec(), bucket_bounds);
+            let recorder = MetricRecorder::new(metrics.clone());
+            async move {
+                histogram!("test_histogram").record(Duration::from_millis(1));
+                histogram!("test_histogram").record(Duration::from_millis(3));
+                histogram!("test_histogram").record(Duration::from_millis(9));
+
+                // The custom bounds are used instead of the default HISTOGRAM_BOUNDS.
+                let hist = metrics.histogram_values("test_histogram").unwrap();
+                let expected: Vec<(f64, u64)> = Vec::from([(0.0, 0), (2.0, 1), (4.0, 2), (8.0, 2)]);
+                assert_eq!(hist.buckets(), expected);
+
+                // A metric with no custom bounds still falls back to HISTOGRAM_BOUNDS unchanged.
+                histogram!("histogram_1").record(Duration::from_millis(9));
+                let other = metrics.histogram_values("histogram_1").unwrap();
+                assert_eq!(other.buckets().len(), HISTOGRAM_BOUNDS.len());
+            }
+            .with_recorder(recorder)
+            .await;
+        });
+    }
+
+    #[test]
+    fn test_summary_metrics() {
+        RT.block_on(async {
+            let metrics = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec())
+                .with_summary_metrics(["test_histogram"]);
+            let recorder = MetricRecorder::new(metrics.clone());
+            async move {
+                for ms in 1..=100 {
+                    histogram!("test_histogram").record(Duration::from_millis(ms));
+                }
+
+                let json = metrics.to_json(Default::default());
+                let summaries = json["summaries"].as_array().unwrap();
+
+                assert_eq!(summaries.len(), 1);
+                assert_eq!(summaries[0]["key"], "test_histogram");
+                assert_eq!(summaries[0]["value"]["count"], 100);
+                assert_eq!(summaries[0]["value"]["sum"], 5050.0);
+                assert_eq!(
+                    summaries[0]["value"]["quantiles"],
+                    json!([[0.5, 50.0], [0.95, 95.0], [0.99, 99.0]])
+                );
+
+                // A histogram not opted into `with_summary_metrics` isn't turned into a summary.
+                histogram!("histogram_1").record(Duration::from_millis(1));
+                let json = metrics.to_json(Default::default());
+                assert_eq!(json["summaries"].as_array().unwrap().len(), 1);
+            }
+            .with_recorder(recorder)
+            .await;
+        });
+    }
+
+    #[test]
+    fn test_label_filter_drops_matching_counter_samples() {
+        RT.block_on(async {
+            let metrics = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec())
+                .with_label_filter(|labels| !labels.get("pool").is_some_and(|p| p.ends_with("_shadow")));
+            let recorder = MetricRecorder::new(metrics.clone());
+            async move {
+                counter!("test_counter", "pool" => "primary").increment(1);
+                counter!("test_counter", "pool" => "migrations_shadow").increment(1);
+
+                let json = metrics.to_json(Default::default());
+                let counters = json["counters"].as_array().unwrap();
+
+                assert_eq!(counters.len(), 1);
+                assert_eq!(counters[0]["labels"], json!({"pool": "primary"}));
+            }
+            .with_recorder(recorder)
+            .await;
+        });
+    }
+
+    #[test]
+    fn test_label_filter_drops_matching_histogram_samples() {
+        RT.block_on(async {
+            let metrics = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec())
+                .with_label_filter(|labels| !labels.get("pool").is_some_and(|p| p.ends_with("_shadow")));
+            let recorder = MetricRecorder::new(metrics.clone());
+            async move {
+                histogram!("test_histogram", "pool" => "primary").record(Duration::from_millis(9));
+                histogram!("test_histogram", "pool" => "migrations_shadow").record(Duration::from_millis(9));
+
+                let json = metrics.to_json(Default::default());
+                let histograms = json["histograms"].as_array().unwrap();
+
+                assert_eq!(histograms.len(), 1);
+                assert_eq!(histograms[0]["labels"], json!({"pool": "primary"}));
+            }
+            .with_recorder(recorder)
+            .await;
+        });
+    }
+
+    #[test]
+    fn test_list_metrics() {
+        RT.block_on(async {
+            let metrics = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec());
+            let recorder = MetricRecorder::new(metrics.clone());
+            async move {
+                describe_counter!("test_counter", "This is a counter");
+                counter!("test_counter").increment(1);
+
+                describe_gauge!("test_gauge", "This is a gauge");
+                gauge!("test_gauge").set(1.0);
+
+                describe_histogram!("test_histogram", "This is a hist");
+                histogram!("test_histogram").record(Duration::from_millis(1));
+
+                let listed = metrics.list_metrics();
+
+                assert!(listed.contains(&(
+                    "test_counter".to_string(),
+                    MetricKind::Counter,
+                    "This is a counter".to_string()
+                )));
+                assert!(listed.contains(&(
+                    "test_gauge".to_string(),
+                    MetricKind::Gauge,
+                    "This is a gauge".to_string()
+                )));
+                assert!(listed.contains(&(
+                    "test_histogram".to_string(),
+                    MetricKind::Histogram,
+                    "This is a hist".to_string()
+                )));
+            }
+            .with_recorder(recorder)
+            .await;
+        });
+    }
+
     #[test]
     fn test_set_and_read_descriptions() {
         RT.block_on(async {
@@ -357,7 +527,8 @@ mod tests {
                 let empty = json!({
                     "counters": [],
                     "gauges": [],
-                    "histograms": []
+                    "histograms": [],
+                    "summaries": []
                 });
 
                 assert_eq!(metrics.to_json(Default::default()), empty);
@@ -419,7 +590,8 @@ mod tests {
                                 "count":3
                             },
                             "description":""
-                        }]
+                        }],
+                    "summaries": []
                     });
 
                 assert_eq!(json, expected);
@@ -463,7 +635,8 @@ mod tests {
                             "count": 1
                         },
                         "description":""
-                    }]
+                    }],
+                    "summaries": []
                 });
                 assert_eq!(expected, json);
             }
@@ -554,4 +727,88 @@ mod tests {
             .await;
         });
     }
+
+    #[test]
+    fn test_openmetrics_format() {
+        RT.block_on(async {
+            let metrics = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec());
+            let recorder = MetricRecorder::new(metrics.clone());
+            async move {
+                counter!("counter_1", "label" => "one").absolute(4);
+                describe_counter!("counter_2", "this is a description for counter 2");
+                counter!("counter_2", "label" => "one", "another_label" => "two").absolute(2);
+
+                describe_gauge!("gauge_1", "a description for gauge 1");
+                gauge!("gauge_1").set(7.0);
+                gauge!("gauge_2", "label" => "three").set(3.0);
+
+                describe_histogram!("histogram_1", "a description for histogram");
+                let hist = histogram!("histogram_1", "label" => "one", "hist_two" => "two");
+                hist.record(Duration::from_millis(9));
+
+                histogram!("histogram_2").record(Duration::from_millis(1000));
+
+                let mut global_labels: HashMap<String, String> = HashMap::new();
+                global_labels.insert("global_two".to_string(), "two".to_string());
+                global_labels.insert("global_one".to_string(), "one".to_string());
+
+                let openmetrics = metrics.to_openmetrics(global_labels);
+                let snapshot = expect_test::expect![[r#"
+                    # HELP counter_1 
+                    # TYPE counter_1 counter
+                    counter_1_total{global_one="one",global_two="two",label="one"} 4
+
+                    # HELP counter_2 this is a description for counter 2
+                    # TYPE counter_2 counter
+                    counter_2_total{another_label="two",global_one="one",global_two="two",label="one"} 2
+
+                    # HELP gauge_1 a description for gauge 1
+                    # TYPE gauge_1 gauge
+                    gauge_1{global_one="one",global_two="two"} 7
+
+                    # HELP gauge_2 
+                    # TYPE gauge_2 gauge
+                    gauge_2{global_one="one",global_two="two",label="three"} 3
+
+                    # HELP histogram_1 a description for histogram
+                    # TYPE histogram_1 histogram
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="0"} 0
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="1"} 0
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="5"} 0
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="10"} 1
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="50"} 1
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="100"} 1
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="500"} 1
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="1000"} 1
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="5000"} 1
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="50000"} 1
+                    histogram_1_bucket{global_one="one",global_two="two",hist_two="two",label="one",le="+Inf"} 1
+                    histogram_1_sum{global_one="one",global_two="two",hist_two="two",label="one"} 9
+                    histogram_1_count{global_one="one",global_two="two",hist_two="two",label="one"} 1
+
+                    # HELP histogram_2 
+                    # TYPE histogram_2 histogram
+                    histogram_2_bucket{global_one="one",global_two="two",le="0"} 0
+                    histogram_2_bucket{global_one="one",global_two="two",le="1"} 0
+                    histogram_2_bucket{global_one="one",global_two="two",le="5"} 0
+                    histogram_2_bucket{global_one="one",global_two="two",le="10"} 0
+                    histogram_2_bucket{global_one="one",global_two="two",le="50"} 0
+                    histogram_2_bucket{global_one="one",global_two="two",le="100"} 0
+                    histogram_2_bucket{global_one="one",global_two="two",le="500"} 0
+                    histogram_2_bucket{global_one="one",global_two="two",le="1000"} 1
+                    histogram_2_bucket{global_one="one",global_two="two",le="5000"} 1
+                    histogram_2_bucket{global_one="one",global_two="two",le="50000"} 1
+                    histogram_2_bucket{global_one="one",global_two="two",le="+Inf"} 1
+                    histogram_2_sum{global_one="one",global_two="two"} 1000
+                    histogram_2_count{global_one="one",global_two="two"} 1
+
+                    # EOF
+                "#]];
+
+                snapshot.assert_eq(&openmetrics);
+            }
+            .with_recorder(recorder)
+            .await;
+        });
+    }
 }
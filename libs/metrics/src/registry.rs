@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::sync::{atomic::Ordering, Arc};
 
@@ -11,26 +11,45 @@ use parking_lot::RwLock;
 use serde_json::Value;
 
 use super::formatters::metrics_to_json;
+use super::summary::SummaryEstimator;
 use super::{
     common::{Metric, MetricAction, MetricType, MetricValue, Snapshot},
-    formatters::metrics_to_prometheus,
+    formatters::{metrics_to_openmetrics, metrics_to_prometheus},
 };
 use super::{ACCEPT_LIST, HISTOGRAM_BOUNDS};
 
+/// A predicate deciding whether a sample's labels should be recorded at all.
+type LabelFilter = Arc<dyn Fn(&HashMap<String, String>) -> bool + Send + Sync>;
+
 struct Inner {
     descriptions: RwLock<HashMap<String, String>>,
     register: Registry<Key, GenerationalAtomicStorage>,
     accept_list: Vec<&'static str>,
+    bucket_bounds: HashMap<&'static str, Vec<f64>>,
+    label_filter: RwLock<Option<LabelFilter>>,
+    summary_metrics: RwLock<HashSet<&'static str>>,
+    summaries: RwLock<HashMap<Key, SummaryEstimator>>,
 }
 
 impl Inner {
-    fn new(accept_list: Vec<&'static str>) -> Self {
+    fn new(accept_list: Vec<&'static str>, bucket_bounds: HashMap<&'static str, Vec<f64>>) -> Self {
         Self {
             descriptions: RwLock::new(HashMap::new()),
             register: Registry::new(GenerationalStorage::atomic()),
             accept_list,
+            bucket_bounds,
+            label_filter: RwLock::new(None),
+            summary_metrics: RwLock::new(HashSet::new()),
+            summaries: RwLock::new(HashMap::new()),
         }
     }
+
+    fn bucket_bounds_for(&self, name: &str) -> &[f64] {
+        self.bucket_bounds
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or(&HISTOGRAM_BOUNDS)
+    }
 }
 
 #[derive(Clone)]
@@ -57,11 +76,44 @@ impl MetricRegistry {
 
     // for internal and testing usage only
     pub(crate) fn new_with_accept_list(accept_list: Vec<&'static str>) -> Self {
+        Self::new_with_accept_list_and_buckets(accept_list, HashMap::new())
+    }
+
+    /// Like [`Self::new_with_accept_list`], but also lets individual histograms be recorded with
+    /// bucket boundaries other than [`HISTOGRAM_BOUNDS`], keyed by metric name. For internal and
+    /// testing usage only.
+    pub(crate) fn new_with_accept_list_and_buckets(
+        accept_list: Vec<&'static str>,
+        bucket_bounds: HashMap<&'static str, Vec<f64>>,
+    ) -> Self {
         MetricRegistry {
-            inner: Arc::new(Inner::new(accept_list)),
+            inner: Arc::new(Inner::new(accept_list, bucket_bounds)),
         }
     }
 
+    /// Restricts recorded samples to those whose labels satisfy `predicate`, on top of the
+    /// name-based accept list. Samples that fail the predicate are dropped at ingestion time and
+    /// never appear in any output format - useful when, e.g., a pooling library like `mobc`
+    /// reuses the same metric name across pools we don't want to export (a shadow database pool,
+    /// for instance): `with_label_filter(|labels| !labels.get("pool").is_some_and(|p| p.ends_with("_shadow")))`.
+    pub fn with_label_filter<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&HashMap<String, String>) -> bool + Send + Sync + 'static,
+    {
+        *self.inner.label_filter.write() = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Additionally maintains a rolling p50/p95/p99 quantile estimate for each of `names`,
+    /// derived from the same raw samples recorded through `histogram!()`. The `metrics` facade
+    /// crate underlying this registry has no separate summary-recording hook or macro, so a
+    /// summary here isn't a fourth first-class metric type - it's an opt-in alternate view over
+    /// an existing histogram's samples, gated the same way the accept list gates everything else.
+    pub fn with_summary_metrics(self, names: impl IntoIterator<Item = &'static str>) -> Self {
+        self.inner.summary_metrics.write().extend(names);
+        self
+    }
+
     pub(crate) fn record(&self, metric: &MetricVisitor) {
         if self.is_accepted_metric(metric) {
             match metric.metric_type {
@@ -113,6 +165,17 @@ impl MetricRegistry {
                 HistogramFn::record(c, val * 1000.0)
             }
         });
+
+        if let MetricAction::HistRecord(val) = metric.action {
+            if self.inner.summary_metrics.read().contains(metric.name.name()) {
+                self.inner
+                    .summaries
+                    .write()
+                    .entry(metric.name.clone())
+                    .or_insert_with(SummaryEstimator::new)
+                    .record(val * 1000.0);
+            }
+        }
     }
 
     pub fn counter_value(&self, name: &'static str) -> Option<u64> {
@@ -131,7 +194,7 @@ impl MetricRegistry {
     }
 
     pub fn histogram_values(&self, name: &'static str) -> Option<HistogramUtil> {
-        let mut histogram = HistogramUtil::new(&HISTOGRAM_BOUNDS)?;
+        let mut histogram = HistogramUtil::new(self.inner.bucket_bounds_for(name))?;
         let key = Key::from_name(name);
         let histograms = self.inner.register.get_histogram_handles();
         let samples = histograms.get(&key)?;
@@ -147,7 +210,52 @@ impl MetricRegistry {
         descriptions.clone()
     }
 
+    /// Lists every metric name currently registered (regardless of its labels), along with its
+    /// kind and description, without any recorded values. Meant for self-describing `/metrics`
+    /// endpoints that want to build a dashboard from the shape of the metrics rather than a
+    /// snapshot of them.
+    pub fn list_metrics(&self) -> Vec<(String, MetricKind, String)> {
+        let descriptions = self.get_descriptions();
+        let mut seen = HashSet::new();
+        let mut metrics = Vec::new();
+
+        Self::collect_metric_names(self.inner.register.get_counter_handles().into_keys(), MetricKind::Counter)
+            .chain(Self::collect_metric_names(
+                self.inner.register.get_gauge_handles().into_keys(),
+                MetricKind::Gauge,
+            ))
+            .chain(Self::collect_metric_names(
+                self.inner.register.get_histogram_handles().into_keys(),
+                MetricKind::Histogram,
+            ))
+            .for_each(|(name, kind)| {
+                if seen.insert(name.clone()) {
+                    let description = descriptions.get(&name).cloned().unwrap_or_default();
+                    metrics.push((name, kind, description));
+                }
+            });
+
+        metrics.sort_by(|a, b| a.0.cmp(&b.0));
+        metrics
+    }
+
+    fn collect_metric_names(
+        keys: impl Iterator<Item = Key>,
+        kind: MetricKind,
+    ) -> impl Iterator<Item = (String, MetricKind)> {
+        keys.map(move |key| (key.name().to_string(), kind))
+    }
+
     fn get_snapshot(&self, global_labels: HashMap<String, String>) -> Snapshot {
+        self.snapshot(global_labels, false)
+    }
+
+    /// Builds a [`Snapshot`], optionally draining counters and gauges as it goes.
+    ///
+    /// When draining, counters and gauges are read with an atomic swap rather than a load, so a value is either
+    /// captured in this snapshot or lands in the fresh post-drain baseline - never lost in
+    /// between, regardless of what `trace!`-driven updates are racing the drain concurrently.
+    fn snapshot(&self, global_labels: HashMap<String, String>, drain: bool) -> Snapshot {
         let counter_handles = self.inner.register.get_counter_handles();
         let gauge_handles = self.inner.register.get_gauge_handles();
         let histogram_handles = self.inner.register.get_histogram_handles();
@@ -156,7 +264,11 @@ impl MetricRegistry {
         let mut counters: Vec<Metric> = counter_handles
             .into_iter()
             .map(|(key, counter)| {
-                let value = counter.get_inner().load(Ordering::Acquire);
+                let value = if drain {
+                    counter.get_inner().swap(0, Ordering::AcqRel)
+                } else {
+                    counter.get_inner().load(Ordering::Acquire)
+                };
                 Metric::renamed(key, &descriptions, MetricValue::Counter(value), &global_labels)
             })
             .collect();
@@ -164,15 +276,19 @@ impl MetricRegistry {
         let mut gauges: Vec<Metric> = gauge_handles
             .into_iter()
             .map(|(key, gauge)| {
-                let value = f64::from_bits(gauge.get_inner().load(Ordering::Acquire));
-                Metric::renamed(key, &descriptions, MetricValue::Gauge(value), &global_labels)
+                let bits = if drain {
+                    gauge.get_inner().swap(0, Ordering::AcqRel)
+                } else {
+                    gauge.get_inner().load(Ordering::Acquire)
+                };
+                Metric::renamed(key, &descriptions, MetricValue::Gauge(f64::from_bits(bits)), &global_labels)
             })
             .collect();
 
         let mut histograms: Vec<Metric> = histogram_handles
             .into_iter()
             .map(|(key, samples)| {
-                let mut histogram = HistogramUtil::new(&HISTOGRAM_BOUNDS).unwrap();
+                let mut histogram = HistogramUtil::new(self.inner.bucket_bounds_for(key.name())).unwrap();
                 samples.get_inner().data_with(|s| {
                     histogram.record_many(s);
                 });
@@ -186,15 +302,27 @@ impl MetricRegistry {
             })
             .collect();
 
+        let mut summaries: Vec<Metric> = self
+            .inner
+            .summaries
+            .read()
+            .iter()
+            .map(|(key, estimator)| {
+                Metric::renamed(key.clone(), &descriptions, MetricValue::Summary(estimator.summary()), &global_labels)
+            })
+            .collect();
+
         // Sort them so that they are in ordered by key name
         counters.sort_by(|a, b| a.key.cmp(&b.key));
         gauges.sort_by(|a, b| a.key.cmp(&b.key));
         histograms.sort_by(|a, b| a.key.cmp(&b.key));
+        summaries.sort_by(|a, b| a.key.cmp(&b.key));
 
         Snapshot {
             counters,
             gauges,
             histograms,
+            summaries,
         }
     }
 
@@ -208,13 +336,37 @@ impl MetricRegistry {
         metrics_to_prometheus(metrics)
     }
 
+    pub fn to_openmetrics(&self, global_labels: HashMap<String, String>) -> String {
+        let metrics = self.get_snapshot(global_labels);
+        metrics_to_openmetrics(metrics)
+    }
+
+    /// Like [`Self::to_json`], but atomically captures the current counter/gauge values and
+    /// resets them to zero, so a long-running process can export to an external sink on a
+    /// schedule without double-counting across scrape intervals. Histograms are left as-is:
+    /// resetting a histogram mid-interval would bias whatever exporter is still aggregating it.
+    pub fn drain_json(&self, global_labels: HashMap<String, String>) -> Value {
+        let metrics = self.snapshot(global_labels, true);
+        metrics_to_json(metrics)
+    }
+
     fn is_accepted_metric(&self, visitor: &MetricVisitor) -> bool {
         let name = visitor.name.name();
-        if self.inner.accept_list.contains(&name) {
-            return true;
+        if !self.inner.accept_list.contains(&name) {
+            return false;
         }
 
-        false
+        match self.inner.label_filter.read().as_ref() {
+            Some(predicate) => {
+                let labels: HashMap<String, String> = visitor
+                    .name
+                    .labels()
+                    .map(|l| (l.key().to_string(), l.value().to_string()))
+                    .collect();
+                predicate(&labels)
+            }
+            None => true,
+        }
     }
 }
 
@@ -224,3 +376,11 @@ pub(crate) struct MetricVisitor {
     pub(crate) action: MetricAction,
     pub(crate) name: Key,
 }
+
+/// The kind of a registered metric, as returned by [`MetricRegistry::list_metrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
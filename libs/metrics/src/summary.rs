@@ -0,0 +1,126 @@
+//! A from-scratch rolling quantile estimator backing "summary" metrics.
+//!
+//! The `metrics` facade crate that [`crate::MetricRecorder`] implements only exposes hooks for
+//! counters, gauges and histograms - there is no `register_summary`/`record_summary` hook and no
+//! `summary!()` macro, so a summary here is not a fourth first-class metric type recorded through
+//! its own macro. It is instead a derived, opt-in view over the exact same raw samples already
+//! flowing into a histogram through `histogram!()`, kept in a small rolling window per metric so
+//! quantiles can be estimated without pulling in an unvetted CKMS/t-digest dependency.
+
+use crate::common::Summary;
+
+/// Quantiles reported by every summary, mirroring what a typical p50/p95/p99 dashboard expects.
+const QUANTILES: [f64; 3] = [0.5, 0.95, 0.99];
+
+/// Number of most-recent samples kept for quantile estimation. Older samples are evicted first,
+/// so the estimate tracks recent behavior rather than being a uniform sample over the metric's
+/// entire lifetime - the trade-off made to avoid adding `rand` as a dependency for reservoir
+/// sampling.
+const WINDOW_SIZE: usize = 1000;
+
+pub(crate) struct SummaryEstimator {
+    window: Vec<f64>,
+    next_write: usize,
+    count: u64,
+    sum: f64,
+}
+
+impl SummaryEstimator {
+    pub(crate) fn new() -> Self {
+        Self {
+            window: Vec::with_capacity(WINDOW_SIZE),
+            next_write: 0,
+            count: 0,
+            sum: 0.0,
+        }
+    }
+
+    pub(crate) fn record(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+
+        if self.window.len() < WINDOW_SIZE {
+            self.window.push(value);
+        } else {
+            self.window[self.next_write] = value;
+        }
+
+        self.next_write = (self.next_write + 1) % WINDOW_SIZE;
+    }
+
+    /// Computes the current quantile/sum/count view. `sum` and `count` are cumulative over the
+    /// metric's entire lifetime, like a histogram's; the quantiles themselves are only estimated
+    /// over the current rolling window.
+    pub(crate) fn summary(&self) -> Summary {
+        let mut sorted = self.window.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let quantiles = QUANTILES.iter().map(|&q| (q, Self::quantile(&sorted, q))).collect();
+
+        Summary {
+            quantiles,
+            sum: self.sum,
+            count: self.count,
+        }
+    }
+
+    fn quantile(sorted: &[f64], q: f64) -> f64 {
+        if sorted.is_empty() {
+            return 0.0;
+        }
+
+        let rank = ((q * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+        sorted[rank - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantiles_over_a_known_uniform_sample() {
+        let mut estimator = SummaryEstimator::new();
+
+        for i in 1..=100 {
+            estimator.record(i as f64);
+        }
+
+        let summary = estimator.summary();
+
+        assert_eq!(summary.count, 100);
+        assert_eq!(summary.sum, (1..=100).sum::<i64>() as f64);
+        assert_eq!(summary.quantiles, vec![(0.5, 50.0), (0.95, 95.0), (0.99, 99.0)]);
+    }
+
+    #[test]
+    fn window_evicts_oldest_samples_once_full() {
+        let mut estimator = SummaryEstimator::new();
+
+        for _ in 0..WINDOW_SIZE {
+            estimator.record(1.0);
+        }
+        estimator.record(100.0);
+
+        let summary = estimator.summary();
+
+        // Cumulative count/sum still reflect every sample ever recorded...
+        assert_eq!(summary.count, WINDOW_SIZE as u64 + 1);
+        assert_eq!(summary.sum, WINDOW_SIZE as f64 + 100.0);
+
+        // ...but the window only holds the most recent WINDOW_SIZE samples: one 1.0 was evicted to
+        // make room for the 100.0, which is now the window's single largest sample and so only
+        // reached by a quantile whose nearest rank is the very last one.
+        assert_eq!(summary.quantiles, vec![(0.5, 1.0), (0.95, 1.0), (0.99, 1.0)]);
+    }
+
+    #[test]
+    fn empty_summary_reports_zeroed_quantiles() {
+        let estimator = SummaryEstimator::new();
+        let summary = estimator.summary();
+
+        assert_eq!(summary.count, 0);
+        assert_eq!(summary.sum, 0.0);
+        assert_eq!(summary.quantiles, vec![(0.5, 0.0), (0.95, 0.0), (0.99, 0.0)]);
+    }
+}
@@ -3,7 +3,7 @@ pub mod arithmetic;
 mod error;
 mod raw_json;
 
-use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive};
+use bigdecimal::{num_bigint::BigInt, BigDecimal, FromPrimitive, ToPrimitive};
 use chrono::prelude::*;
 use serde::de::Unexpected;
 use serde::ser::SerializeMap;
@@ -40,12 +40,30 @@ pub enum PrismaValue {
     #[serde(serialize_with = "serialize_date")]
     DateTime(DateTime<FixedOffset>),
 
+    /// A date without a time component, for `@db.Date` columns. Kept separate from `DateTime`
+    /// rather than defaulting the time to midnight, so a date-only value round-trips without
+    /// growing a spurious `00:00:00` time component.
+    #[serde(serialize_with = "serialize_date_only")]
+    Date(NaiveDate),
+
+    /// A time without a date component, for `@db.Time` columns. Kept separate from `DateTime`
+    /// rather than defaulting the date to the Unix epoch, so a time-only value round-trips
+    /// without growing a spurious `1970-01-01` date component.
+    #[serde(serialize_with = "serialize_time_only")]
+    Time(NaiveTime),
+
     #[serde(serialize_with = "serialize_decimal", deserialize_with = "deserialize_decimal")]
     Float(BigDecimal),
 
     #[serde(serialize_with = "serialize_bigint")]
     BigInt(i64),
 
+    /// An integer that doesn't fit in `BigInt`'s `i64`, e.g. a Postgres `numeric` column used as
+    /// an ID in a legacy schema. Kept as a separate variant rather than widening `BigInt` itself
+    /// so every existing `i64`-based call site is unaffected.
+    #[serde(serialize_with = "serialize_huge_int")]
+    HugeInt(BigInt),
+
     #[serde(serialize_with = "serialize_bytes")]
     Bytes(Vec<u8>),
 
@@ -54,6 +72,16 @@ pub enum PrismaValue {
         name: String,
         r#type: PlaceholderType,
     },
+
+    /// A SQL `INTERVAL`/duration value, modeled after Postgres's own interval representation:
+    /// months and days are kept separate from microseconds because they don't have a fixed
+    /// length (a month can be 28-31 days, a day can be 23-25 hours around a DST transition).
+    #[serde(serialize_with = "serialize_duration")]
+    Duration {
+        months: i32,
+        days: i32,
+        microseconds: i64,
+    },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
@@ -69,6 +97,7 @@ pub enum PlaceholderType {
     Array(Box<PlaceholderType>),
     Object,
     Bytes,
+    Duration,
 }
 
 impl std::fmt::Display for PlaceholderType {
@@ -85,6 +114,7 @@ impl std::fmt::Display for PlaceholderType {
             PlaceholderType::Array(t) => write!(f, "Array<{t}>"),
             PlaceholderType::Object => write!(f, "Object"),
             PlaceholderType::Bytes => write!(f, "Bytes"),
+            PlaceholderType::Duration => write!(f, "Duration"),
         }
     }
 }
@@ -113,6 +143,19 @@ pub fn decode_bytes(s: impl AsRef<[u8]>) -> PrismaValueResult<Vec<u8>> {
     base64::decode(s).map_err(|_| ConversionFailure::new("base64 encoded bytes", "PrismaValue::Bytes"))
 }
 
+/// Like [`encode_bytes`], but uses the URL-safe, unpadded alphabet (`-`/`_` instead of `+`/`/`,
+/// no trailing `=`), for interop with systems that emit that variant. Prefer [`encode_bytes`] for
+/// new serialization: it stays the default so existing consumers don't see their output change.
+pub fn encode_bytes_url(bytes: &[u8]) -> String {
+    base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)
+}
+
+/// Like [`decode_bytes`], but expects the URL-safe, unpadded alphabet. See [`encode_bytes_url`].
+pub fn decode_bytes_url(s: impl AsRef<[u8]>) -> PrismaValueResult<Vec<u8>> {
+    base64::decode_config(s, base64::URL_SAFE_NO_PAD)
+        .map_err(|_| ConversionFailure::new("URL-safe base64 encoded bytes", "PrismaValue::Bytes"))
+}
+
 impl TryFrom<serde_json::Value> for PrismaValue {
     type Error = crate::error::ConversionFailure;
 
@@ -148,15 +191,41 @@ impl TryFrom<serde_json::Value> for PrismaValue {
                     Ok(PrismaValue::DateTime(date))
                 }
 
+                Some("date_only") => {
+                    let value = obj
+                        .get("prisma__value")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| ConversionFailure::new("JSON date-only value", "PrismaValue"))?;
+
+                    NaiveDate::from_str(value)
+                        .map(PrismaValue::Date)
+                        .map_err(|_| ConversionFailure::new("JSON date-only value", "PrismaValue"))
+                }
+
+                Some("time_only") => {
+                    let value = obj
+                        .get("prisma__value")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| ConversionFailure::new("JSON time-only value", "PrismaValue"))?;
+
+                    NaiveTime::from_str(value)
+                        .map(PrismaValue::Time)
+                        .map_err(|_| ConversionFailure::new("JSON time-only value", "PrismaValue"))
+                }
+
                 Some("bigint") => {
                     let value = obj
                         .get("prisma__value")
                         .and_then(|v| v.as_str())
                         .ok_or_else(|| ConversionFailure::new("JSON bigint value", "PrismaValue"))?;
 
-                    i64::from_str(value)
-                        .map(PrismaValue::BigInt)
-                        .map_err(|_| ConversionFailure::new("JSON bigint value", "PrismaValue"))
+                    // Falls back to the arbitrary-precision representation when the value
+                    // overflows i64, e.g. a legacy schema using Postgres `numeric` as an ID.
+                    i64::from_str(value).map(PrismaValue::BigInt).or_else(|_| {
+                        BigInt::from_str(value)
+                            .map(PrismaValue::HugeInt)
+                            .map_err(|_| ConversionFailure::new("JSON bigint value", "PrismaValue"))
+                    })
                 }
 
                 Some("decimal") => {
@@ -170,13 +239,45 @@ impl TryFrom<serde_json::Value> for PrismaValue {
                         .map_err(|_| ConversionFailure::new("JSON decimal value", "PrismaValue"))
                 }
 
+                Some("duration") => {
+                    let value = obj
+                        .get("prisma__value")
+                        .and_then(|v| v.as_object())
+                        .ok_or_else(|| ConversionFailure::new("JSON duration value", "PrismaValue"))?;
+
+                    let months = value
+                        .get("months")
+                        .and_then(|v| v.as_i64())
+                        .ok_or_else(|| ConversionFailure::new("JSON duration value", "PrismaValue"))?;
+
+                    let days = value
+                        .get("days")
+                        .and_then(|v| v.as_i64())
+                        .ok_or_else(|| ConversionFailure::new("JSON duration value", "PrismaValue"))?;
+
+                    let microseconds = value
+                        .get("microseconds")
+                        .and_then(|v| v.as_i64())
+                        .ok_or_else(|| ConversionFailure::new("JSON duration value", "PrismaValue"))?;
+
+                    Ok(PrismaValue::Duration {
+                        months: months as i32,
+                        days: days as i32,
+                        microseconds,
+                    })
+                }
+
                 Some("bytes") => {
                     let value = obj
                         .get("prisma__value")
                         .and_then(|v| v.as_str())
                         .ok_or_else(|| ConversionFailure::new("JSON bytes value", "PrismaValue"))?;
 
-                    decode_bytes(value).map(PrismaValue::Bytes)
+                    // Accept either alphabet: we mostly emit standard base64 ourselves, but some
+                    // systems we interop with send URL-safe, unpadded values instead.
+                    decode_bytes(value)
+                        .or_else(|_| decode_bytes_url(value))
+                        .map(PrismaValue::Bytes)
                 }
 
                 Some("param") => {
@@ -210,6 +311,30 @@ where
     stringify_datetime(date).serialize(serializer)
 }
 
+fn serialize_date_only<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(2))?;
+
+    map.serialize_entry("prisma__type", "date_only")?;
+    map.serialize_entry("prisma__value", &date.to_string())?;
+
+    map.end()
+}
+
+fn serialize_time_only<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(2))?;
+
+    map.serialize_entry("prisma__type", "time_only")?;
+    map.serialize_entry("prisma__value", &time.to_string())?;
+
+    map.end()
+}
+
 fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -231,6 +356,13 @@ where
     int.to_string().serialize(serializer)
 }
 
+fn serialize_huge_int<S>(int: &BigInt, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    int.to_string().serialize(serializer)
+}
+
 fn serialize_decimal<S>(decimal: &BigDecimal, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -276,6 +408,25 @@ where
     map.end()
 }
 
+fn serialize_duration<S>(months: &i32, days: &i32, microseconds: &i64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(2))?;
+
+    map.serialize_entry("prisma__type", "duration")?;
+    map.serialize_entry(
+        "prisma__value",
+        &json!({
+            "months": months,
+            "days": days,
+            "microseconds": microseconds,
+        }),
+    )?;
+
+    map.end()
+}
+
 struct BigDecimalVisitor;
 
 impl serde::de::Visitor<'_> for BigDecimalVisitor {
@@ -373,18 +524,54 @@ impl PrismaValue {
         }
     }
 
+    #[deprecated(note = "panics on NaN/infinite input, use `try_new_float` or `new_float_opt` instead")]
     pub fn new_float(float: f64) -> PrismaValue {
         PrismaValue::Float(BigDecimal::from_f64(float).unwrap())
     }
 
+    /// Like [`PrismaValue::new_float`], but returns `None` instead of panicking on NaN or infinite input.
+    pub fn new_float_opt(float: f64) -> Option<PrismaValue> {
+        Self::try_new_float(float).ok()
+    }
+
+    /// Like [`PrismaValue::new_float`], but returns a `ConversionFailure` instead of panicking on NaN or infinite
+    /// input.
+    pub fn try_new_float(float: f64) -> PrismaValueResult<PrismaValue> {
+        BigDecimal::from_f64(float)
+            .map(PrismaValue::Float)
+            .ok_or_else(|| ConversionFailure::new("f64", "PrismaValue::Float"))
+    }
+
+    #[deprecated(note = "panics on malformed input, use `try_new_datetime` or `new_datetime_opt` instead")]
     pub fn new_datetime(datetime: &str) -> PrismaValue {
         PrismaValue::DateTime(parse_datetime(datetime).unwrap())
     }
 
+    /// Like [`PrismaValue::new_datetime`], but returns `None` instead of panicking on malformed input.
+    pub fn new_datetime_opt(datetime: &str) -> Option<PrismaValue> {
+        Self::try_new_datetime(datetime).ok()
+    }
+
+    /// Like [`PrismaValue::new_datetime`], but returns a `ConversionFailure` instead of panicking on malformed
+    /// input.
+    pub fn try_new_datetime(datetime: &str) -> PrismaValueResult<PrismaValue> {
+        parse_datetime(datetime)
+            .map(PrismaValue::DateTime)
+            .map_err(|_| ConversionFailure::new("datetime string", "PrismaValue::DateTime"))
+    }
+
     pub fn placeholder(name: String, r#type: PlaceholderType) -> PrismaValue {
         PrismaValue::Placeholder { name, r#type }
     }
 
+    pub fn duration(months: i32, days: i32, microseconds: i64) -> PrismaValue {
+        PrismaValue::Duration {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
     pub fn as_boolean(&self) -> Option<&bool> {
         match self {
             PrismaValue::Boolean(bool) => Some(bool),
@@ -399,6 +586,38 @@ impl PrismaValue {
             None
         }
     }
+
+    /// Returns a normalized JSON string for structural equality checks, e.g. for deduplication.
+    /// `PrismaValue::Json`'s `Eq`/`Hash` compare the raw string, so two objects that only differ
+    /// in key order (`{"a":1,"b":2}` vs `{"b":2,"a":1}`) are otherwise treated as distinct. This
+    /// recursively sorts object keys before re-serializing; array order is preserved, since
+    /// arrays are ordered by definition and `[1, 2]` and `[2, 1]` are not the same value.
+    ///
+    /// Returns `None` if `self` isn't a `Json` value or doesn't contain valid JSON.
+    pub fn json_normalized(&self) -> Option<String> {
+        let value: serde_json::Value = serde_json::from_str(self.as_json()?).ok()?;
+        Some(serde_json::to_string(&normalize_json_object_keys(&value)).unwrap())
+    }
+}
+
+fn normalize_json_object_keys(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(normalize_json_object_keys).collect())
+        }
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let mut normalized = serde_json::Map::new();
+            for (key, val) in entries {
+                normalized.insert(key.clone(), normalize_json_object_keys(val));
+            }
+
+            serde_json::Value::Object(normalized)
+        }
+        other => other.clone(),
+    }
 }
 
 impl fmt::Display for PrismaValue {
@@ -408,12 +627,15 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Float(x) => x.fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
+            PrismaValue::Date(x) => x.fmt(f),
+            PrismaValue::Time(x) => x.fmt(f),
             PrismaValue::Enum(x) => x.fmt(f),
             PrismaValue::Int(x) => x.fmt(f),
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
             PrismaValue::Json(x) => x.fmt(f),
             PrismaValue::BigInt(x) => x.fmt(f),
+            PrismaValue::HugeInt(x) => x.fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{x:?}");
                 as_string.fmt(f)
@@ -429,6 +651,11 @@ impl fmt::Display for PrismaValue {
                 write!(f, "{{ {joined} }}")
             }
             PrismaValue::Placeholder { name, r#type } => write!(f, "var({name}: {type})"),
+            PrismaValue::Duration {
+                months,
+                days,
+                microseconds,
+            } => write!(f, "{months} months {days} days {microseconds} microseconds"),
         }
     }
 }
@@ -491,6 +718,30 @@ impl From<PrismaListValue> for PrismaValue {
     }
 }
 
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<rust_decimal::Decimal> for PrismaValue {
+    type Error = ConversionFailure;
+
+    fn try_from(d: rust_decimal::Decimal) -> PrismaValueResult<PrismaValue> {
+        BigDecimal::from_str(&d.to_string())
+            .map(PrismaValue::Float)
+            .map_err(|_| ConversionFailure::new("rust_decimal::Decimal", "Decimal"))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<PrismaValue> for rust_decimal::Decimal {
+    type Error = ConversionFailure;
+
+    fn try_from(value: PrismaValue) -> PrismaValueResult<rust_decimal::Decimal> {
+        match value {
+            PrismaValue::Float(f) => rust_decimal::Decimal::from_str(&f.to_string())
+                .map_err(|_| ConversionFailure::new("PrismaValue", "rust_decimal::Decimal")),
+            _ => Err(ConversionFailure::new("PrismaValue", "rust_decimal::Decimal")),
+        }
+    }
+}
+
 impl TryFrom<PrismaValue> for i64 {
     type Error = ConversionFailure;
 
@@ -512,3 +763,216 @@ impl TryFrom<PrismaValue> for String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_round_trips_through_json_for_negative_interval() {
+        let value = PrismaValue::duration(-1, -15, -3_600_000_000);
+        let json = serde_json::to_value(&value).unwrap();
+        let round_tripped = PrismaValue::try_from(json).unwrap();
+
+        assert_eq!(value, round_tripped);
+    }
+
+    #[test]
+    fn duration_round_trips_through_json_for_mixed_sign_interval() {
+        let value = PrismaValue::duration(2, -5, 3_600_000_000);
+        let json = serde_json::to_value(&value).unwrap();
+        let round_tripped = PrismaValue::try_from(json).unwrap();
+
+        assert_eq!(value, round_tripped);
+    }
+
+    #[test]
+    fn date_only_round_trips_through_json() {
+        let value = PrismaValue::Date(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+        let json = serde_json::to_value(&value).unwrap();
+        let round_tripped = PrismaValue::try_from(json).unwrap();
+
+        assert_eq!(value, round_tripped);
+    }
+
+    #[test]
+    fn time_only_round_trips_through_json() {
+        let value = PrismaValue::Time(NaiveTime::from_hms_milli_opt(13, 45, 30, 250).unwrap());
+        let json = serde_json::to_value(&value).unwrap();
+        let round_tripped = PrismaValue::try_from(json).unwrap();
+
+        assert_eq!(value, round_tripped);
+    }
+
+    #[test]
+    fn try_new_float_rejects_nan() {
+        assert!(PrismaValue::try_new_float(f64::NAN).is_err());
+        assert_eq!(PrismaValue::new_float_opt(f64::NAN), None);
+    }
+
+    #[test]
+    fn try_new_float_rejects_infinity() {
+        assert!(PrismaValue::try_new_float(f64::INFINITY).is_err());
+        assert!(PrismaValue::try_new_float(f64::NEG_INFINITY).is_err());
+        assert_eq!(PrismaValue::new_float_opt(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn try_new_float_accepts_finite_values() {
+        assert!(PrismaValue::try_new_float(1.5).is_ok());
+    }
+
+    #[test]
+    fn try_new_datetime_rejects_empty_string() {
+        assert!(PrismaValue::try_new_datetime("").is_err());
+        assert_eq!(PrismaValue::new_datetime_opt(""), None);
+    }
+
+    #[test]
+    fn try_new_datetime_accepts_valid_rfc3339() {
+        assert!(PrismaValue::try_new_datetime("1999-05-01T00:00:00.000Z").is_ok());
+    }
+
+    #[test]
+    fn json_normalized_ignores_object_key_order() {
+        let a = PrismaValue::Json(r#"{"a":1,"b":2}"#.to_owned());
+        let b = PrismaValue::Json(r#"{"b":2,"a":1}"#.to_owned());
+
+        assert_ne!(a, b);
+        assert_eq!(a.json_normalized(), b.json_normalized());
+    }
+
+    #[test]
+    fn json_normalized_ignores_key_order_in_nested_objects() {
+        let a = PrismaValue::Json(r#"{"outer":{"a":1,"b":2},"z":true}"#.to_owned());
+        let b = PrismaValue::Json(r#"{"z":true,"outer":{"b":2,"a":1}}"#.to_owned());
+
+        assert_eq!(a.json_normalized(), b.json_normalized());
+    }
+
+    #[test]
+    fn json_normalized_preserves_array_order() {
+        let a = PrismaValue::Json(r#"[1,2,3]"#.to_owned());
+        let b = PrismaValue::Json(r#"[3,2,1]"#.to_owned());
+
+        assert_ne!(a.json_normalized(), b.json_normalized());
+    }
+
+    #[test]
+    fn json_normalized_ignores_key_order_within_array_elements() {
+        let a = PrismaValue::Json(r#"[{"a":1,"b":2}]"#.to_owned());
+        let b = PrismaValue::Json(r#"[{"b":2,"a":1}]"#.to_owned());
+
+        assert_eq!(a.json_normalized(), b.json_normalized());
+    }
+
+    #[test]
+    fn json_normalized_returns_none_for_non_json_values() {
+        assert_eq!(PrismaValue::Int(1).json_normalized(), None);
+    }
+
+    #[test]
+    fn json_normalized_returns_none_for_invalid_json() {
+        assert_eq!(PrismaValue::Json("not json".to_owned()).json_normalized(), None);
+    }
+
+    #[test]
+    fn bytes_url_round_trips_values_needing_url_safe_characters() {
+        // Chosen so the standard alphabet would encode this as containing `+` and `/`.
+        let bytes = vec![0xfb, 0xff, 0xbf];
+
+        let standard = encode_bytes(&bytes);
+        assert!(standard.contains('+') || standard.contains('/'));
+
+        let url_safe = encode_bytes_url(&bytes);
+        assert!(url_safe.contains('-') || url_safe.contains('_'));
+        assert!(!url_safe.contains('='));
+
+        assert_eq!(decode_bytes_url(&url_safe).unwrap(), bytes);
+    }
+
+    #[test]
+    fn tagged_bytes_json_accepts_both_standard_and_url_safe_encoding() {
+        let bytes = vec![0xfb, 0xff, 0xbf];
+
+        let standard_json = json!({ "prisma__type": "bytes", "prisma__value": encode_bytes(&bytes) });
+        let url_safe_json = json!({ "prisma__type": "bytes", "prisma__value": encode_bytes_url(&bytes) });
+
+        assert_eq!(PrismaValue::try_from(standard_json).unwrap(), PrismaValue::Bytes(bytes.clone()));
+        assert_eq!(PrismaValue::try_from(url_safe_json).unwrap(), PrismaValue::Bytes(bytes));
+    }
+
+    #[test]
+    fn tagged_bigint_json_within_i64_range_parses_as_bigint() {
+        let json = json!({ "prisma__type": "bigint", "prisma__value": "9223372036854775807" });
+
+        assert_eq!(PrismaValue::try_from(json).unwrap(), PrismaValue::BigInt(i64::MAX));
+    }
+
+    #[test]
+    fn tagged_bigint_json_beyond_i64_range_falls_back_to_hugeint() {
+        let json = json!({ "prisma__type": "bigint", "prisma__value": "99999999999999999999999999999" });
+
+        assert_eq!(
+            PrismaValue::try_from(json).unwrap(),
+            PrismaValue::HugeInt(BigInt::from_str("99999999999999999999999999999").unwrap())
+        );
+    }
+
+    #[test]
+    fn hugeint_serializes_to_a_json_string_like_bigint() {
+        let value = PrismaValue::HugeInt(BigInt::from_str("99999999999999999999999999999").unwrap());
+
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            serde_json::Value::String("99999999999999999999999999999".to_owned())
+        );
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_round_trips_ordinary_values() {
+        let decimal = rust_decimal::Decimal::from_str("1234.5678").unwrap();
+        let value = PrismaValue::try_from(decimal).unwrap();
+
+        assert_eq!(value, PrismaValue::Float(BigDecimal::from_str("1234.5678").unwrap()));
+        assert_eq!(rust_decimal::Decimal::try_from(value).unwrap(), decimal);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_round_trips_negative_values() {
+        let decimal = rust_decimal::Decimal::from_str("-99.01").unwrap();
+        let value = PrismaValue::try_from(decimal).unwrap();
+
+        assert_eq!(rust_decimal::Decimal::try_from(value).unwrap(), decimal);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_conversion_rejects_non_float_prisma_values() {
+        assert!(rust_decimal::Decimal::try_from(PrismaValue::Int(1)).is_err());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_conversion_loses_precision_beyond_its_own_range() {
+        // `rust_decimal::Decimal` caps scale at 28 fractional digits, so a `BigDecimal` with more
+        // than that isn't representable and gets rounded on conversion; round-tripping it back
+        // loses precision rather than erroring.
+        let high_precision = BigDecimal::from_str("1.23456789012345678901234567890123456789").unwrap();
+
+        let as_decimal = rust_decimal::Decimal::try_from(PrismaValue::Float(high_precision.clone())).unwrap();
+        let round_tripped = PrismaValue::try_from(as_decimal).unwrap();
+
+        let PrismaValue::Float(round_tripped) = round_tripped else {
+            panic!("expected a Float value");
+        };
+
+        // The round trip is lossy: the value changed...
+        assert_ne!(round_tripped, high_precision);
+        // ...but is exactly what re-parsing `rust_decimal`'s own rounded string produces, i.e. the
+        // loss happens once, at the `rust_decimal::Decimal` boundary, not again on the way back.
+        assert_eq!(round_tripped, BigDecimal::from_str(&as_decimal.to_string()).unwrap());
+    }
+}
@@ -139,6 +139,8 @@ pub struct Column<'a> {
     pub column_name: Cow<'a, str>,
     pub not_null: bool,
     pub column_type: Cow<'a, str>,
+    pub character_set: Option<Cow<'a, str>>,
+    pub collation: Option<Cow<'a, str>>,
     pub default: Option<Cow<'a, str>>,
     pub auto_increment: bool,
     pub primary_key: bool,
@@ -151,6 +153,14 @@ impl Display for Column<'_> {
         f.write_str(" ")?;
         Display::fmt(&self.column_type, f)?;
 
+        if let Some(character_set) = &self.character_set {
+            write!(f, " CHARACTER SET {character_set}")?;
+        }
+
+        if let Some(collation) = &self.collation {
+            write!(f, " COLLATE {collation}")?;
+        }
+
         if self.not_null {
             f.write_str(" NOT NULL")?;
         } else {
@@ -194,6 +204,7 @@ impl Display for CreateIndex<'_> {
             IndexType::Normal => (),
             IndexType::Unique => f.write_str("UNIQUE ")?,
             IndexType::Fulltext => f.write_str("FULLTEXT ")?,
+            IndexType::Spatial => f.write_str("SPATIAL ")?,
         }
 
         f.write_str("INDEX `")?;
@@ -319,6 +330,7 @@ pub enum IndexType {
     Normal,
     Unique,
     Fulltext,
+    Spatial,
 }
 
 impl Default for IndexType {
@@ -340,6 +352,7 @@ impl Display for IndexClause<'_> {
             IndexType::Normal => (),
             IndexType::Unique => f.write_str("UNIQUE ")?,
             IndexType::Fulltext => f.write_str("FULLTEXT ")?,
+            IndexType::Spatial => f.write_str("SPATIAL ")?,
         }
 
         f.write_str("INDEX ")?;
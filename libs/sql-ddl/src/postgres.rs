@@ -347,6 +347,11 @@ pub struct CreateIndex<'a> {
     pub table_reference: &'a dyn Display,
     pub columns: Vec<IndexColumn<'a>>,
     pub using: Option<IndexAlgorithm>,
+    /// The value of the `fillfactor` storage parameter, if it deviates from the connector default.
+    pub fillfactor: Option<u32>,
+    /// Whether to create the index without taking a write lock on the table (`CREATE INDEX
+    /// CONCURRENTLY`). This statement cannot be run inside a transaction.
+    pub concurrently: bool,
 }
 
 impl Display for CreateIndex<'_> {
@@ -362,8 +367,9 @@ impl Display for CreateIndex<'_> {
 
         write!(
             f,
-            "CREATE {uniqueness}INDEX {index_name} ON {table_reference}{using}(",
+            "CREATE {uniqueness}INDEX {concurrently}{index_name} ON {table_reference}{using}(",
             uniqueness = if self.is_unique { "UNIQUE " } else { "" },
+            concurrently = if self.concurrently { "CONCURRENTLY " } else { "" },
             index_name = self.index_name,
             table_reference = self.table_reference,
             using = using,
@@ -388,7 +394,13 @@ impl Display for CreateIndex<'_> {
             })
             .join(", ", f)?;
 
-        f.write_str(")")
+        f.write_str(")")?;
+
+        if let Some(fillfactor) = self.fillfactor {
+            write!(f, " WITH (fillfactor={fillfactor})")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -432,6 +444,8 @@ mod tests {
             table_reference: &PostgresIdentifier::Simple(Cow::Borrowed("Cat")),
             columns,
             using: None,
+            fillfactor: None,
+            concurrently: false,
         };
 
         assert_eq!(
@@ -450,6 +464,8 @@ mod tests {
             table_reference: &PostgresIdentifier::Simple(Cow::Borrowed("Cat")),
             columns,
             using: Some(IndexAlgorithm::Hash),
+            fillfactor: None,
+            concurrently: false,
         };
 
         assert_eq!(
@@ -479,6 +495,8 @@ mod tests {
             table_reference: &PostgresIdentifier::Simple("Cat".into()),
             columns,
             using: None,
+            fillfactor: None,
+            concurrently: false,
         };
 
         assert_eq!(
@@ -487,6 +505,26 @@ mod tests {
         )
     }
 
+    #[test]
+    fn create_index_concurrently() {
+        let columns = vec![IndexColumn::new("name")];
+
+        let create_index = CreateIndex {
+            is_unique: false,
+            index_name: "meow_idx".into(),
+            table_reference: &PostgresIdentifier::Simple(Cow::Borrowed("Cat")),
+            columns,
+            using: None,
+            fillfactor: None,
+            concurrently: true,
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE INDEX CONCURRENTLY \"meow_idx\" ON \"Cat\"(\"name\")"
+        )
+    }
+
     #[test]
     fn full_alter_table_add_foreign_key() {
         let alter_table = AlterTable {
@@ -14,6 +14,8 @@ pub struct CreateTable<'a> {
     pub columns: Vec<Column<'a>>,
     pub primary_key: Option<Vec<Cow<'a, str>>>,
     pub foreign_keys: Vec<ForeignKey<'a>>,
+    /// Whether the table should be created `WITHOUT ROWID`.
+    pub without_rowid: bool,
 }
 
 impl Display for CreateTable<'_> {
@@ -34,7 +36,13 @@ impl Display for CreateTable<'_> {
             write!(f, ",\n{SQL_INDENTATION}{foreign_key}")?;
         }
 
-        write!(f, "\n)")
+        write!(f, "\n)")?;
+
+        if self.without_rowid {
+            write!(f, " WITHOUT ROWID")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -173,6 +181,7 @@ mod tests {
             ],
             primary_key: None,
             foreign_keys: Vec::new(),
+            without_rowid: false,
         };
 
         let expected = indoc::indoc!(
@@ -187,6 +196,32 @@ mod tests {
         assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
     }
 
+    #[test]
+    fn create_table_without_rowid() {
+        let create_table = CreateTable {
+            table_name: &SqliteIdentifier("Cat"),
+            columns: vec![Column {
+                name: "id".into(),
+                r#type: "integer".into(),
+                primary_key: true,
+                ..Default::default()
+            }],
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            without_rowid: true,
+        };
+
+        let expected = indoc::indoc!(
+            r#"
+            CREATE TABLE "Cat" (
+                "id" integer PRIMARY KEY
+            ) WITHOUT ROWID
+            "#
+        );
+
+        assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
+    }
+
     #[test]
     fn create_table_with_primary_key() {
         let create_table = CreateTable {
@@ -206,6 +241,7 @@ mod tests {
             ],
             primary_key: Some(vec!["id".into(), "boxId".into()]),
             foreign_keys: Vec::new(),
+            without_rowid: false,
         };
 
         let expected = indoc!(
@@ -253,6 +289,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            without_rowid: false,
         };
 
         let expected = indoc!(
@@ -24,6 +24,21 @@ impl Files {
         Self(asts)
     }
 
+    /// Replace one file's source text and AST in place, without touching the others. Used by
+    /// [`crate::ParserDatabase::reparse_file`] to avoid re-lexing and re-parsing every file in
+    /// the project when only one of them changed.
+    pub fn replace_file(
+        &mut self,
+        file_id: crate::FileId,
+        new_source: schema_ast::SourceFile,
+        diagnostics: &mut Diagnostics,
+    ) {
+        let idx = file_id.0 as usize;
+        let path = self.0[idx].0.clone();
+        let ast = schema_ast::parse_schema(new_source.as_str(), diagnostics, file_id);
+        self.0[idx] = (path, new_source, ast);
+    }
+
     /// Iterate all parsed files.
     #[allow(clippy::should_implement_trait)]
     pub fn iter(&self) -> impl Iterator<Item = (FileId, &String, &schema_ast::SourceFile, &ast::SchemaAst)> {
@@ -55,6 +70,20 @@ impl Files {
         String::from_utf8(out).unwrap()
     }
 
+    /// Like [`Files::render_diagnostics`], but only renders the diagnostics whose span belongs
+    /// to `file_id`. Useful for editor/LSP integrations that only want the diagnostics for the
+    /// file currently open, without reprocessing the whole project.
+    pub fn render_diagnostics_for_file(&self, diagnostics: &Diagnostics, file_id: crate::FileId) -> String {
+        let mut out = Vec::new();
+
+        for error in diagnostics.errors().iter().filter(|error| error.span().file_id == file_id) {
+            let (file_name, source, _) = &self[error.span().file_id];
+            error.pretty_print(&mut out, file_name, source.as_str()).unwrap();
+        }
+
+        String::from_utf8(out).unwrap()
+    }
+
     /// Returns the number of files.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
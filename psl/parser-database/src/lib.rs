@@ -128,12 +128,64 @@ impl ParserDatabase {
         }
     }
 
+    /// Reparse a single file after it changed, replacing its AST and re-running validation.
+    /// Meant for editor/LSP integrations that reparse on every keystroke and can't afford
+    /// [`ParserDatabase::new`]'s full from-scratch parse of every file in the project.
+    ///
+    /// ## Invalidation scope
+    ///
+    /// Only `file_id`'s source text and AST are replaced without re-lexing or re-parsing the
+    /// other files. The string interner is also reused as-is: names that already existed
+    /// resolve to the same interned string id they always did, and only genuinely new or
+    /// changed identifiers get interned.
+    ///
+    /// Name resolution, type resolution, attribute validation and relation inference, however,
+    /// are re-run for the *whole* project, not just `file_id`. `Names`, `Types` and `Relations`
+    /// are flat structures built in one pass over every file; they have no notion of "this
+    /// entry came from file X" that would let us remove and rebuild just one file's
+    /// contribution, and relations in particular are inherently cross-file (a relation field in
+    /// file A can point at a model in file B). Giving those passes the same file-scoped
+    /// invalidation this method gives the AST would mean reworking those structures to track
+    /// per-file ownership, which is a much bigger change than this method.
+    pub fn reparse_file(&mut self, file_id: FileId, new_source: SourceFile, diagnostics: &mut Diagnostics) {
+        self.asts.replace_file(file_id, new_source, diagnostics);
+
+        let mut names = Default::default();
+        let mut types = Default::default();
+        let mut relations = Default::default();
+
+        let mut ctx = Context::new(
+            &self.asts,
+            &mut self.interner,
+            &mut names,
+            &mut types,
+            &mut relations,
+            diagnostics,
+        );
+
+        names::resolve_names(&mut ctx);
+        types::resolve_types(&mut ctx);
+        attributes::resolve_attributes(&mut ctx);
+        relations::infer_relations(&mut ctx);
+
+        self.names = names;
+        self.types = types;
+        self.relations = relations;
+    }
+
     /// Render the given diagnostics (warnings + errors) into a String.
     /// This method is multi-file aware.
     pub fn render_diagnostics(&self, diagnostics: &Diagnostics) -> String {
         self.asts.render_diagnostics(diagnostics)
     }
 
+    /// Like [`ParserDatabase::render_diagnostics`], but only renders the diagnostics belonging to
+    /// `file_id`. Useful for editor/LSP integrations that only want diagnostics for the file
+    /// currently open, without reprocessing the whole project.
+    pub fn render_diagnostics_for_file(&self, diagnostics: &Diagnostics, file_id: FileId) -> String {
+        self.asts.render_diagnostics_for_file(diagnostics, file_id)
+    }
+
     /// The parsed AST. This methods asserts that there is a single prisma schema file. As
     /// multi-file schemas are implemented, calls to this methods should be replaced with
     /// `ParserDatabase::ast()` and `ParserDatabase::iter_asts()`.
@@ -221,6 +273,29 @@ impl ParserDatabase {
     pub fn generators(&self) -> impl Iterator<Item = &GeneratorConfig> {
         self.iter_asts().flat_map(|ast| ast.generators())
     }
+
+    /// Enums that are not referenced by any field in the schema. Useful for schema hygiene
+    /// tooling that wants to flag dead schema for pruning.
+    pub fn unused_enums(&self) -> impl Iterator<Item = walkers::EnumWalker<'_>> {
+        self.walk_enums().filter(|enm| {
+            !self
+                .walk_models()
+                .flat_map(|model| model.scalar_fields())
+                .any(|field| field.field_type_as_enum().map(|used| used.id) == Some(enm.id))
+        })
+    }
+
+    /// Models that are not referenced by any relation, and that hold no relation themselves.
+    /// Useful for schema hygiene tooling that wants to flag dead schema for pruning.
+    pub fn orphan_models(&self) -> impl Iterator<Item = walkers::ModelWalker<'_>> {
+        self.walk_models().filter(|model| {
+            model.relation_fields().next().is_none()
+                && !self
+                    .walk_models()
+                    .flat_map(|other| other.relation_fields())
+                    .any(|field| field.related_model().id == model.id)
+        })
+    }
 }
 
 impl std::ops::Index<FileId> for ParserDatabase {
@@ -244,3 +319,187 @@ impl std::ops::Index<StringId> for ParserDatabase {
         self.interner.get(index).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(schema: &str) -> ParserDatabase {
+        let mut diagnostics = Diagnostics::new();
+        let db = ParserDatabase::new_single_file(SourceFile::new_allocated(schema.to_owned().into()), &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{:?}", diagnostics);
+        db
+    }
+
+    #[test]
+    fn unused_enums_reports_enums_with_no_referencing_field() {
+        let db = parse(
+            r#"
+            enum Used { A B }
+            enum Unused { A B }
+
+            model M {
+                id Int @id
+                field Used
+            }
+            "#,
+        );
+
+        let unused: Vec<_> = db.unused_enums().map(|e| e.name()).collect();
+        assert_eq!(unused, vec!["Unused"]);
+    }
+
+    #[test]
+    fn orphan_models_reports_models_with_no_relations() {
+        let db = parse(
+            r#"
+            model Connected {
+                id Int @id
+                other Other[]
+            }
+
+            model Other {
+                id Int @id
+                connectedId Int
+                connected Connected @relation(fields: [connectedId], references: [id])
+            }
+
+            model Lonely {
+                id Int @id
+            }
+            "#,
+        );
+
+        let orphans: Vec<_> = db.orphan_models().map(|m| m.name()).collect();
+        assert_eq!(orphans, vec!["Lonely"]);
+    }
+
+    #[test]
+    fn reparse_file_clears_a_syntax_error_fixed_in_the_new_source() {
+        let mut diagnostics = Diagnostics::new();
+        let mut db = ParserDatabase::new(
+            &[
+                ("a.prisma".to_owned(), SourceFile::new_allocated("model A {\n  id Int @id\n}\n".into())),
+                (
+                    "b.prisma".to_owned(),
+                    SourceFile::new_allocated("model B {\n  id Int @id\n".into()), // missing closing brace
+                ),
+            ],
+            &mut diagnostics,
+        );
+        assert!(diagnostics.has_errors());
+
+        let file_b = db.file_id("b.prisma").unwrap();
+
+        let mut diagnostics = Diagnostics::new();
+        db.reparse_file(
+            file_b,
+            SourceFile::new_allocated("model B {\n  id Int @id\n}\n".into()),
+            &mut diagnostics,
+        );
+
+        assert!(!diagnostics.has_errors(), "{:?}", diagnostics);
+        assert!(db.find_model("A").is_some());
+        assert!(db.find_model("B").is_some());
+    }
+
+    #[test]
+    fn render_diagnostics_for_file_only_shows_errors_from_that_file() {
+        let mut diagnostics = Diagnostics::new();
+        let db = ParserDatabase::new(
+            &[
+                ("a.prisma".to_owned(), SourceFile::new_allocated("model A {\n  id Int @id\n}\n".into())),
+                (
+                    "b.prisma".to_owned(),
+                    SourceFile::new_allocated("model B {\n  id Int @id\n  bad Undefined\n}\n".into()),
+                ),
+            ],
+            &mut diagnostics,
+        );
+        assert!(diagnostics.has_errors());
+
+        let file_a = db.file_id("a.prisma").unwrap();
+        let file_b = db.file_id("b.prisma").unwrap();
+
+        assert_eq!(db.render_diagnostics_for_file(&diagnostics, file_a), "");
+
+        let rendered_b = db.render_diagnostics_for_file(&diagnostics, file_b);
+        assert!(rendered_b.contains("Undefined"));
+        assert!(rendered_b.contains("b.prisma"));
+    }
+
+    #[test]
+    fn find_model_by_database_name_falls_back_to_the_model_name() {
+        let db = parse(
+            r#"
+            model Unmapped {
+                id Int @id
+            }
+            "#,
+        );
+
+        let model = db.find_model_by_database_name("Unmapped", None).unwrap();
+        assert_eq!(model.name(), "Unmapped");
+
+        assert!(db.find_model_by_database_name("unmapped", None).is_none());
+    }
+
+    #[test]
+    fn find_model_by_database_name_uses_the_map_attribute() {
+        let db = parse(
+            r#"
+            model Mapped {
+                id Int @id
+
+                @@map("mapped_table")
+            }
+            "#,
+        );
+
+        assert!(db.find_model_by_database_name("Mapped", None).is_none());
+
+        let model = db.find_model_by_database_name("mapped_table", None).unwrap();
+        assert_eq!(model.name(), "Mapped");
+    }
+
+    #[test]
+    fn walk_indexes_flattens_indexes_across_all_models() {
+        let db = parse(
+            r#"
+            model A {
+                id Int @id
+                name String
+                age  Int
+
+                @@index([name])
+                @@unique([name, age])
+            }
+
+            model B {
+                id Int @id
+                email String @unique
+            }
+            "#,
+        );
+
+        let mut fields: Vec<_> = db
+            .walk_indexes()
+            .map(|index| {
+                (
+                    index.model().name(),
+                    index.fields().map(|f| f.name()).collect::<Vec<_>>().join(","),
+                )
+            })
+            .collect();
+        fields.sort();
+
+        assert_eq!(
+            fields,
+            vec![
+                ("A", "name".to_owned()),
+                ("A", "name,age".to_owned()),
+                ("B", "email".to_owned()),
+            ]
+        );
+    }
+}
@@ -110,6 +110,25 @@ impl crate::ParserDatabase {
             .map(|model_id| self.walk(model_id))
     }
 
+    /// Find a model by database (mapped) name, i.e. the name of the table it points to: the
+    /// `@@map`'d name if there is one, otherwise the model name itself (see the crate docs for
+    /// the general mapped name logic). Unlike [`ParserDatabase::find_model`], this is a linear
+    /// scan: there is no name to intern, since the database name is derived, not itself
+    /// interned as a top-level name.
+    ///
+    /// Two models in different namespaces (`@@schema`) can map to the same database name; pass
+    /// `namespace` to disambiguate. With `namespace: None`, the first matching model is
+    /// returned, in schema declaration order.
+    pub fn find_model_by_database_name<'db>(
+        &'db self,
+        db_name: &str,
+        namespace: Option<&str>,
+    ) -> Option<ModelWalker<'db>> {
+        self.walk_models().find(|model| {
+            model.database_name() == db_name && namespace.is_none_or(|ns| model.schema_name() == Some(ns))
+        })
+    }
+
     /// Find a composite type by name.
     pub fn find_composite_type<'db>(&'db self, name: &str) -> Option<CompositeTypeWalker<'db>> {
         self.interner
@@ -171,6 +190,11 @@ impl crate::ParserDatabase {
             .filter(move |walker| walker.is_defined_in_file(file_id))
     }
 
+    /// Walk all the indexes defined on every model and view in the schema, connector-agnostic.
+    pub fn walk_indexes(&self) -> impl Iterator<Item = IndexWalker<'_>> {
+        self.walk_models().chain(self.walk_views()).flat_map(|m| m.indexes())
+    }
+
     /// Walk all the composite types in the schema.
     pub fn walk_composite_types(&self) -> impl Iterator<Item = CompositeTypeWalker<'_>> + '_ {
         self.iter_tops()
@@ -48,6 +48,7 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     NamedForeignKeys |
     RelationFieldsInArbitraryOrder |
     ScalarLists |
+    ScalarListDefaults |
     UpdateableId |
     WritableAutoincField |
     ImplicitManyToManyRelation |
@@ -63,7 +64,8 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     DeleteReturning |
     SupportsFiltersOnRelationsWithoutJoins |
     LateralJoin |
-    SupportsDefaultInInsert
+    SupportsDefaultInInsert |
+    ExpressionIndexes
 });
 
 const SCALAR_TYPE_DEFAULTS: &[(ScalarType, CockroachType)] = &[
@@ -22,6 +22,7 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     RelationFieldsInArbitraryOrder |
     CreateMany |
     ScalarLists |
+    ScalarListDefaults |
     JsonLists |
     InsensitiveFilters |
     CompositeTypes |
@@ -51,7 +51,8 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     SupportsTxIsolationSerializable |
     SupportsTxIsolationSnapshot |
     SupportsFiltersOnRelationsWithoutJoins |
-    SupportsDefaultInInsert
+    SupportsDefaultInInsert |
+    Merge
     // InsertReturning | DeleteReturning - unimplemented.
 });
 
@@ -92,6 +93,10 @@ impl Connector for MsSqlDatamodelConnector {
         128
     }
 
+    fn max_rows_per_insert(&self) -> Option<usize> {
+        Some(1000)
+    }
+
     fn foreign_key_referential_actions(&self) -> BitFlags<ReferentialAction> {
         use ReferentialAction::*;
 
@@ -61,7 +61,9 @@ pub const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Conne
     RowIn |
     SupportsFiltersOnRelationsWithoutJoins |
     CorrelatedSubqueries |
-    SupportsDefaultInInsert
+    SupportsDefaultInInsert |
+    NativeUpsert |
+    ExpressionIndexes // Requires MySQL 8.0.13+; this connector doesn't distinguish versions or MariaDB statically.
 });
 
 const CONSTRAINT_SCOPES: &[ConstraintScope] = &[ConstraintScope::GlobalForeignKey, ConstraintScope::ModelKeyIndex];
@@ -53,6 +53,7 @@ pub const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Conne
     NamedPrimaryKeys |
     RelationFieldsInArbitraryOrder |
     ScalarLists |
+    ScalarListDefaults |
     JsonLists |
     UpdateableId |
     WritableAutoincField |
@@ -72,7 +73,8 @@ pub const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Conne
     DeleteReturning |
     SupportsFiltersOnRelationsWithoutJoins |
     LateralJoin |
-    SupportsDefaultInInsert
+    SupportsDefaultInInsert |
+    ExpressionIndexes
 });
 
 pub struct PostgresDatamodelConnector;
@@ -310,6 +312,12 @@ impl Connector for PostgresDatamodelConnector {
             Xml => ScalarType::String,
             Inet => ScalarType::String,
             Citext => ScalarType::String,
+            Int4Range => ScalarType::String,
+            Int8Range => ScalarType::String,
+            NumRange => ScalarType::String,
+            TsRange => ScalarType::String,
+            TstzRange => ScalarType::String,
+            DateRange => ScalarType::String,
             // Boolean
             Boolean => ScalarType::Boolean,
             // Int
@@ -26,4 +26,10 @@ crate::native_type_definition! {
     Xml -> String,
     Json -> Json,
     JsonB -> Json,
+    Int4Range -> String,
+    Int8Range -> String,
+    NumRange -> String,
+    TsRange -> String,
+    TstzRange -> String,
+    DateRange -> String,
 }
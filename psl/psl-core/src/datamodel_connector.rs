@@ -68,6 +68,13 @@ pub trait Connector: Send + Sync {
     /// limit should return usize::MAX.
     fn max_identifier_length(&self) -> usize;
 
+    /// The maximum number of rows a single multi-values `INSERT` can carry, if the connector
+    /// caps it (SQL Server's 1000-row limit is the classic example). `None` means the only
+    /// limit is on the number of bind parameters, via `max_bind_values` in the query builder.
+    fn max_rows_per_insert(&self) -> Option<usize> {
+        None
+    }
+
     // Relation mode
 
     /// The relation modes that can be set through the relationMode datasource
@@ -155,6 +162,10 @@ pub trait Connector: Send + Sync {
     fn validate_enum(&self, _enum: walkers::EnumWalker<'_>, _: &mut Diagnostics) {}
     fn validate_model(&self, _model: walkers::ModelWalker<'_>, _: RelationMode, _: &mut Diagnostics) {}
     fn validate_relation_field(&self, _field: walkers::RelationFieldWalker<'_>, _: &mut Diagnostics) {}
+
+    /// Connector-specific validation for a single index, called once per index on every model.
+    /// Prefer this over cramming index-shaped checks into `validate_model`.
+    fn validate_index(&self, _index: walkers::IndexWalker<'_>, _errors: &mut Diagnostics) {}
     fn validate_datasource(&self, _: BitFlags<PreviewFeature>, _: &Datasource, _: &mut Diagnostics) {}
 
     fn validate_scalar_field_unknown_default_functions(
@@ -196,9 +207,14 @@ pub trait Connector: Send + Sync {
     fn native_type_to_parts(&self, native_type: &NativeTypeInstance) -> (&'static str, Vec<String>);
 
     fn find_native_type_constructor(&self, name: &str) -> Option<&NativeTypeConstructor> {
-        self.available_native_type_constructors()
-            .iter()
-            .find(|constructor| constructor.name == name)
+        self.native_type_constructors_by_name().get(name).copied()
+    }
+
+    /// A `name -> constructor` lookup for [`Connector::available_native_type_constructors`],
+    /// backing [`Connector::find_native_type_constructor`] so it doesn't linear-scan on every
+    /// call. Built once per connector and cached for the life of the process.
+    fn native_type_constructors_by_name(&self) -> &'static HashMap<&'static str, &'static NativeTypeConstructor> {
+        native_types::constructors_by_name(self.available_native_type_constructors())
     }
 
     /// This function is used during Schema parsing to calculate the concrete native type.
@@ -219,6 +235,19 @@ pub trait Connector: Send + Sync {
             || self.capabilities().contains(ConnectorCapability::CorrelatedSubqueries)
     }
 
+    /// Whether the connector supports row-value constructors, e.g. `(a, b) IN (c, d)`. Backed by
+    /// [`ConnectorCapability::RowIn`], the capability already covering this.
+    fn supports_row_value_constructors(&self) -> bool {
+        self.capabilities().contains(ConnectorCapability::RowIn)
+    }
+
+    /// Whether the connector supports indexing an expression over a column rather than the
+    /// column itself, e.g. Postgres' `CREATE INDEX ON t (lower(email))`. Backed by
+    /// [`ConnectorCapability::ExpressionIndexes`].
+    fn supports_expression_indexes(&self) -> bool {
+        self.capabilities().contains(ConnectorCapability::ExpressionIndexes)
+    }
+
     // Returns whether the connector supports the `RelationLoadStrategy::Join`.
     /// On some connectors, this might return `UnknownYet`.
     fn runtime_join_strategy_support(&self) -> JoinStrategySupport {
@@ -401,3 +430,47 @@ pub enum JoinStrategySupport {
     /// For example, the MySQL connector supports relation join strategy, but only for versions >= 8.0.14.
     UnknownYet,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_connectors::{MSSQL, MYSQL, POSTGRES, SQLITE};
+
+    #[test]
+    fn supports_row_value_constructors_matches_the_row_in_capability_per_connector() {
+        assert!(POSTGRES.supports_row_value_constructors());
+        assert!(MYSQL.supports_row_value_constructors());
+        assert!(SQLITE.supports_row_value_constructors());
+        assert!(!MSSQL.supports_row_value_constructors());
+    }
+
+    #[test]
+    fn supports_expression_indexes_matches_the_capability_per_connector() {
+        assert!(POSTGRES.supports_expression_indexes());
+        assert!(MYSQL.supports_expression_indexes());
+        assert!(!SQLITE.supports_expression_indexes());
+        assert!(!MSSQL.supports_expression_indexes());
+    }
+
+    #[test]
+    fn max_rows_per_insert_is_only_capped_on_mssql() {
+        assert_eq!(MSSQL.max_rows_per_insert(), Some(1000));
+        assert_eq!(POSTGRES.max_rows_per_insert(), None);
+        assert_eq!(MYSQL.max_rows_per_insert(), None);
+        assert_eq!(SQLITE.max_rows_per_insert(), None);
+    }
+
+    #[test]
+    fn find_native_type_constructor_resolves_known_and_unknown_names_via_the_cached_map() {
+        let constructor = POSTGRES.find_native_type_constructor("VarChar").unwrap();
+        assert_eq!(constructor.name, "VarChar");
+
+        assert!(POSTGRES.find_native_type_constructor("NotARealNativeType").is_none());
+
+        // The lookup map itself is built once and cached: repeated calls hand back the exact same
+        // map instead of rebuilding it from `available_native_type_constructors()` every time.
+        let first = POSTGRES.native_type_constructors_by_name() as *const _;
+        let second = POSTGRES.native_type_constructors_by_name() as *const _;
+        assert_eq!(first, second);
+    }
+}
@@ -45,6 +45,7 @@ macro_rules! capabilities {
 capabilities!(
     // General capabilities, not specific to any part of Prisma.
     ScalarLists,
+    ScalarListDefaults, // Connector supports `@default` on a list field.
     Enums,
     Json,
     JsonLists,
@@ -110,6 +111,8 @@ capabilities!(
     SupportsFiltersOnRelationsWithoutJoins, // Connector supports rendering filters on relation fields without joins.
     LateralJoin,                            // Connector supports lateral joins to resolve relations.
     CorrelatedSubqueries,                   // Connector supports correlated subqueries to resolve relations.
+    Merge,                                   // Connector supports the `MERGE` statement for upserts.
+    ExpressionIndexes, // Connector supports indexing an expression rather than a plain column, e.g. `lower(email)`.
 );
 
 /// Contains all capabilities that the connector is able to serve.
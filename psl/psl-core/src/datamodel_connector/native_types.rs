@@ -1,5 +1,10 @@
 use crate::datamodel_connector::ScalarType;
-use std::{any::Any, sync::Arc};
+use once_cell::sync::Lazy;
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 /// Represents an available native type.
 pub struct NativeTypeConstructor {
@@ -16,6 +21,27 @@ pub struct NativeTypeConstructor {
     pub prisma_types: &'static [ScalarType],
 }
 
+type ConstructorsByName = HashMap<&'static str, &'static NativeTypeConstructor>;
+
+/// A process-lifetime cache of `name -> constructor` lookups, one entry per distinct connector
+/// (keyed by the address of its `available_native_type_constructors()` slice, which is a
+/// `'static` const and therefore stable for the life of the process). Backs
+/// `Connector::native_type_constructors_by_name`, so introspection resolving hundreds of native
+/// types builds the map once per connector instead of linear-scanning
+/// `available_native_type_constructors()` on every lookup.
+static CONSTRUCTORS_BY_NAME_CACHE: Lazy<Mutex<HashMap<usize, &'static ConstructorsByName>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// See [`CONSTRUCTORS_BY_NAME_CACHE`].
+pub(crate) fn constructors_by_name(constructors: &'static [NativeTypeConstructor]) -> &'static ConstructorsByName {
+    let key = constructors.as_ptr() as usize;
+    let mut cache = CONSTRUCTORS_BY_NAME_CACHE.lock().unwrap();
+
+    *cache
+        .entry(key)
+        .or_insert_with(|| Box::leak(Box::new(constructors.iter().map(|c| (c.name, c)).collect())))
+}
+
 #[derive(Clone)]
 pub struct NativeTypeInstance(Arc<dyn Any + Send + Sync + 'static>);
 
@@ -66,12 +66,7 @@ impl<'db> ConstraintNamespace<'db> {
 
     /// Add all index and unique constraints from the data model to a global validation scope.
     pub(super) fn add_global_indexes(&mut self, scope: ConstraintScope, ctx: &super::Context<'db>) {
-        for index in ctx
-            .db
-            .walk_models()
-            .chain(ctx.db.walk_views())
-            .flat_map(|m| m.indexes())
-        {
+        for index in ctx.db.walk_indexes() {
             let counter = self
                 .global
                 .entry((scope, index.model().schema_name(), index.constraint_name(ctx.connector)))
@@ -242,6 +242,21 @@ pub(super) fn validate_default_value(field: ScalarFieldWalker<'_>, ctx: &mut Con
 
     default_value::validate_default_value(default_value, scalar_type, ctx);
     default_value::validate_auto_param(default_value, ctx);
+
+    if field.ast_field().arity.is_list()
+        && ctx.has_capability(ConnectorCapability::ScalarLists)
+        && !ctx.has_capability(ConnectorCapability::ScalarListDefaults)
+    {
+        if let Some(default_attribute) = default_attribute {
+            let msg = "The current connector does not support default values on list fields.";
+
+            ctx.push_error(DatamodelError::new_attribute_validation_error(
+                msg,
+                "@default",
+                default_attribute.span,
+            ));
+        }
+    }
 }
 
 pub(super) fn validate_scalar_field_connector_specific(field: ScalarFieldWalker<'_>, ctx: &mut Context<'_>) {
@@ -369,6 +369,10 @@ pub(crate) fn composite_type_in_compound_unique_index(index: IndexWalker<'_>, ct
     }
 }
 
+pub(super) fn connector_specific(index: IndexWalker<'_>, ctx: &mut Context<'_>) {
+    ctx.connector.validate_index(index, ctx.diagnostics)
+}
+
 pub(super) fn unique_client_name_does_not_clash_with_field(index: IndexWalker<'_>, ctx: &mut Context<'_>) {
     if !index.is_unique() {
         return;
@@ -398,3 +402,39 @@ pub(super) fn unique_client_name_does_not_clash_with_field(index: IndexWalker<'_
         ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::builtin_connectors::BUILTIN_CONNECTORS;
+
+    // `connector_specific` (this file's `Connector::validate_index` call site) runs for every
+    // index on every model, right alongside the other index validations. This exercises the full
+    // pipeline to make sure that addition didn't disturb the existing, connector-independent
+    // fulltext validations that already live in this file.
+    #[test]
+    fn bad_fulltext_index_still_produces_a_diagnostic() {
+        let schema = r#"
+            datasource db {
+              provider = "mysql"
+              url      = "mysql://"
+            }
+
+            model A {
+              id Int    @id
+              a  String
+              b  String
+
+              @@fulltext([a(sort: Desc), b])
+            }
+        "#;
+
+        let validated = crate::validate(schema.into(), BUILTIN_CONNECTORS);
+        assert!(validated.diagnostics.has_errors());
+
+        let message = validated.diagnostics.errors()[0].message();
+        assert!(
+            message.contains("The sort argument is not supported in a @@fulltext attribute"),
+            "unexpected message: {message}"
+        );
+    }
+}
@@ -103,6 +103,32 @@ fn must_error_on_bad_value_inside_list_default() {
     expect_error(dml, &expectation);
 }
 
+#[test]
+fn must_error_on_list_default_for_connector_without_scalar_lists() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "mysql"
+          url = "mysql://"
+        }
+
+        model Model {
+          id  Int @id
+          tags String[] @default(["a", "b"])
+        }
+    "#};
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mField "tags" in model "Model" can't be a list. The current connector does not support lists of primitive types.[0m
+          [1;94m-->[0m  [4mschema.prisma:8[0m
+        [1;94m   | [0m
+        [1;94m 7 | [0m  id  Int @id
+        [1;94m 8 | [0m  [1;91mtags String[] @default(["a", "b"])[0m
+        [1;94m 9 | [0m}
+        [1;94m   | [0m
+    "#]];
+    expect_error(dml, &expectation);
+}
+
 #[test]
 fn must_error_if_default_value_type_mismatch() {
     let dml = indoc! {r#"
@@ -11,6 +11,7 @@ pub struct Merge<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) using: Using<'a>,
     pub(crate) when_not_matched: Option<Query<'a>>,
+    pub(crate) when_matched: Option<Update<'a>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
 }
 
@@ -24,6 +25,7 @@ impl<'a> Merge<'a> {
             table: table.into(),
             using: using.into(),
             when_not_matched: None,
+            when_matched: None,
             returning: None,
         }
     }
@@ -36,6 +38,11 @@ impl<'a> Merge<'a> {
         self
     }
 
+    pub(crate) fn when_matched(mut self, update: Update<'a>) -> Self {
+        self.when_matched = Some(update);
+        self
+    }
+
     pub(crate) fn returning<K, I>(mut self, columns: I) -> Self
     where
         K: Into<Column<'a>>,
@@ -102,6 +109,7 @@ impl<'a> TryFrom<Insert<'a>> for Merge<'a> {
             return Err(Error::builder(kind).build());
         }
 
+        let on_conflict = insert.on_conflict;
         let columns = insert.columns;
 
         let query = match insert.values.kind {
@@ -152,6 +160,10 @@ impl<'a> TryFrom<Insert<'a>> for Merge<'a> {
         let not_matched = Insert::multi(bare_columns).values(dual_columns);
         let mut merge = Merge::new(table, using).when_not_matched(not_matched);
 
+        if let Some(OnConflict::Update(update, _constraints)) = on_conflict {
+            merge = merge.when_matched(update);
+        }
+
         if let Some(columns) = insert.returning {
             merge = merge.returning(columns);
         }
@@ -16,6 +16,8 @@ pub struct Select<'a> {
     pub(crate) joins: Vec<Join<'a>>,
     pub(crate) ctes: Vec<CommonTableExpression<'a>>,
     pub(crate) comment: Option<Cow<'a, str>>,
+    pub(crate) locking: Option<Locking>,
+    pub(crate) index_hint: Option<IndexHint<'a>>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -24,6 +26,31 @@ pub enum DistinctType<'a> {
     OnClause(Vec<Expression<'a>>),
 }
 
+/// A row-level lock taken by a `SELECT`, requested through [`Select::lock_for_update`] or
+/// [`Select::lock_for_no_key_update`]. Connectors that can't take the requested lock return a
+/// clear error instead of silently ignoring it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Locking {
+    /// `FOR UPDATE`: locks the row against concurrent updates and deletes.
+    Update,
+    /// `FOR NO KEY UPDATE`: like `Update`, but doesn't block inserts of rows that reference this
+    /// one through a foreign key, making it cheaper for high-concurrency workflows that only need
+    /// to protect a row's own columns.
+    NoKeyUpdate,
+}
+
+/// A query planner hint requested through [`Select::use_index`] or [`Select::force_index`]. This
+/// is a performance-tuning escape hatch for rescuing a bad query plan, not a portable part of the
+/// query: only MySQL renders it, other connectors ignore it and log a warning.
+#[derive(Debug, PartialEq, Clone)]
+pub enum IndexHint<'a> {
+    /// `USE INDEX (...)`: suggests the optimizer prefer these indexes over others it might pick.
+    Use(Vec<Cow<'a, str>>),
+    /// `FORCE INDEX (...)`: like `Use`, but also rules out a full table scan unless none of the
+    /// given indexes can be used at all.
+    Force(Vec<Cow<'a, str>>),
+}
+
 impl<'a> From<Select<'a>> for Expression<'a> {
     fn from(sel: Select<'a>) -> Expression<'a> {
         Expression {
@@ -603,6 +630,45 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Locks the selected rows with `FOR UPDATE`, blocking concurrent updates, deletes and other
+    /// row locks until the current transaction ends. Connectors that don't support row locking
+    /// return an error when the query is built.
+    pub fn lock_for_update(mut self) -> Self {
+        self.locking = Some(Locking::Update);
+        self
+    }
+
+    /// Locks the selected rows with `FOR NO KEY UPDATE`, blocking concurrent updates and deletes
+    /// like [`Select::lock_for_update`], but without blocking inserts of rows that reference this
+    /// one through a foreign key. Connectors that don't support row locking return an error when
+    /// the query is built.
+    pub fn lock_for_no_key_update(mut self) -> Self {
+        self.locking = Some(Locking::NoKeyUpdate);
+        self
+    }
+
+    /// Adds a `USE INDEX (...)` planner hint, suggesting the given indexes over others the query
+    /// planner might otherwise pick. Only rendered on MySQL; other connectors ignore it and log a
+    /// warning, since this is a database-specific performance tuning escape hatch.
+    pub fn use_index<T>(mut self, indices: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.index_hint = Some(IndexHint::Use(indices.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Adds a `FORCE INDEX (...)` planner hint, ruling out a full table scan unless none of the
+    /// given indexes can be used at all. Only rendered on MySQL; other connectors ignore it and
+    /// log a warning.
+    pub fn force_index<T>(mut self, indices: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.index_hint = Some(IndexHint::Force(indices.into_iter().map(Into::into).collect()));
+        self
+    }
+
     /// Adds a common table expression to the select.
     ///
     /// ```rust
@@ -24,7 +24,10 @@ pub use self::postgres::Postgres;
 #[cfg(feature = "sqlite")]
 pub use self::sqlite::Sqlite;
 
-use crate::ast::*;
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind},
+};
 use std::{borrow::Cow, fmt};
 
 pub type Result = crate::Result<()>;
@@ -334,6 +337,10 @@ pub trait Visitor<'a> {
                 self.visit_table(table, true)?;
             }
 
+            if let Some(hint) = select.index_hint {
+                self.visit_index_hint(hint)?;
+            }
+
             if !select.joins.is_empty() {
                 self.visit_joins(select.joins)?;
             }
@@ -356,6 +363,11 @@ pub trait Visitor<'a> {
             }
 
             self.visit_limit_and_offset(select.limit, select.offset)?;
+
+            if let Some(locking) = select.locking {
+                self.write(" ")?;
+                self.visit_locking(locking)?;
+            }
         } else if select.columns.is_empty() {
             self.write(" *")?;
         } else {
@@ -658,7 +670,10 @@ pub trait Visitor<'a> {
                 Some(database) => self.delimited_identifiers(&[&*database, &*table_name])?,
                 None => self.delimited_identifiers(&[&*table_name])?,
             },
-            TableType::Values(values) => self.visit_values(values)?,
+            TableType::Values(values) => {
+                self.write("VALUES ")?;
+                self.visit_values(values)?;
+            }
             TableType::Query(select) => self.surround_with("(", ")", |ref mut s| s.visit_select(*select))?,
             TableType::JoinedTable(jt) => {
                 match table.database {
@@ -1219,6 +1234,27 @@ pub trait Visitor<'a> {
         self.surround_with("/* ", " */", |ref mut s| s.write(comment))
     }
 
+    /// Renders a row lock requested through [`Select::lock_for_update`] or
+    /// [`Select::lock_for_no_key_update`]. Connectors that support row locking override this;
+    /// the default rejects the query with a clear error instead of silently dropping the lock.
+    fn visit_locking(&mut self, locking: Locking) -> Result {
+        Err(Error::builder(ErrorKind::QueryInvalidInput(format!(
+            "Row locking ({locking:?}) is not supported by this connector"
+        )))
+        .build())
+    }
+
+    /// Renders an index hint requested through [`Select::use_index`] or [`Select::force_index`].
+    /// Unlike [`visit_locking`](Self::visit_locking), an unsupported hint is silently ignored
+    /// rather than rejected: it's a pure performance-tuning suggestion, not something the query's
+    /// correctness depends on. MySQL overrides this; the default logs a warning so a hint aimed at
+    /// MySQL doesn't silently vanish when the same query builder call runs against another
+    /// connector.
+    fn visit_index_hint(&mut self, hint: IndexHint<'a>) -> Result {
+        tracing::warn!(?hint, "Index hints are not supported by this connector, ignoring.");
+        Ok(())
+    }
+
     fn visit_decorated(&mut self, decorated: Decorated<'a>) -> Result {
         let Decorated { prefix, suffix, expr } = decorated;
 
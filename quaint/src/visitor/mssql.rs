@@ -257,9 +257,10 @@ impl<'a> Visitor<'a> for Mssql<'a> {
                 .convert_tuple_selects_to_ctes(true, &mut 0)
                 .expect_left("Top-level query was right")
                 .into(),
-            // Replacing the `ON CONFLICT DO NOTHING` clause with a `MERGE` statement.
+            // Replacing the `ON CONFLICT` clause with a `MERGE` statement, SQL Server's native
+            // upsert syntax.
             Query::Insert(insert) => match insert.on_conflict {
-                Some(OnConflict::DoNothing) => Merge::try_from(*insert).unwrap().into(),
+                Some(OnConflict::DoNothing) | Some(OnConflict::Update(_, _)) => Merge::try_from(*insert).unwrap().into(),
                 _ => Query::Insert(insert),
             },
             _ => query,
@@ -550,6 +551,11 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         self.write(" ON ")?;
         self.visit_conditions(merge.using.on_conditions)?;
 
+        if let Some(update) = merge.when_matched {
+            self.write(" WHEN MATCHED THEN UPDATE SET ")?;
+            self.visit_update_set(update)?;
+        }
+
         if let Some(query) = merge.when_not_matched {
             self.write(" WHEN NOT MATCHED THEN ")?;
             self.visit_query(query)?;
@@ -1644,6 +1650,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_single_insert_conflict_do_update() {
+        let table = Table::from("foo").add_unique_index("bar");
+
+        let insert: Insert<'_> = Insert::single_into(table)
+            .value(("foo", "bar"), "lol")
+            .value(("foo", "wtf"), "meow")
+            .into();
+
+        let update = Update::table("foo").set("wtf", "purr");
+
+        let query = insert.on_conflict(OnConflict::Update(update, vec![Column::from("bar")]));
+        let (sql, params) = Mssql::build(query).unwrap();
+
+        let expected_sql = indoc!(
+            "
+            MERGE INTO [foo]
+            USING (SELECT @P1 AS [bar], @P2 AS [wtf]) AS [dual] ([bar],[wtf])
+            ON [dual].[bar] = [foo].[bar]
+            WHEN MATCHED THEN UPDATE SET [wtf] = @P3
+            WHEN NOT MATCHED THEN
+            INSERT ([bar],[wtf]) VALUES ([dual].[bar],[dual].[wtf]);
+        "
+        );
+
+        assert_eq!(expected_sql.replace('\n', " ").trim(), sql);
+        assert_eq!(
+            vec![Value::from("lol"), Value::from("meow"), Value::from("purr")],
+            params
+        );
+    }
+
     #[test]
     fn test_distinct() {
         let expected_sql = "SELECT DISTINCT [bar] FROM [test]";
@@ -1828,6 +1866,21 @@ mod tests {
         assert_eq!("INSERT INTO [foo] ([foo],[baz]) VALUES (@P1,DEFAULT)", sql);
     }
 
+    #[test]
+    fn test_insert_on_conflict_update_uses_merge() {
+        let table = Table::from("users").add_unique_index("foo");
+        let update = Update::table(table.clone()).set("foo", 3);
+
+        let query: Insert = Insert::single_into(table).value("foo", 10).into();
+        let query = query.on_conflict(OnConflict::Update(update, Vec::new()));
+
+        let (sql, _) = Mssql::build(query).unwrap();
+
+        assert!(sql.starts_with("MERGE INTO [users] USING ("), "{sql}");
+        assert!(sql.contains("WHEN MATCHED THEN UPDATE SET"), "{sql}");
+        assert!(sql.contains("WHEN NOT MATCHED THEN INSERT"), "{sql}");
+    }
+
     #[test]
     fn join_is_inserted_positionally() {
         let joined_table = Table::from("User").left_join(
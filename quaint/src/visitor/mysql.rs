@@ -211,7 +211,9 @@ impl<'a> Visitor<'a> for Mysql<'a> {
     }
 
     fn visit_insert(&mut self, insert: Insert<'a>) -> visitor::Result {
-        match insert.on_conflict {
+        let on_conflict = insert.on_conflict;
+
+        match on_conflict {
             Some(OnConflict::DoNothing) => self.write("INSERT IGNORE ")?,
             _ => self.write("INSERT ")?,
         };
@@ -275,6 +277,11 @@ impl<'a> Visitor<'a> for Mysql<'a> {
             expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr))?,
         }
 
+        if let Some(OnConflict::Update(update, _constraints)) = on_conflict {
+            self.write(" ON DUPLICATE KEY UPDATE ")?;
+            self.visit_upsert(update)?;
+        }
+
         if let Some(comment) = insert.comment {
             self.write(" ")?;
             self.visit_comment(comment)?;
@@ -282,8 +289,8 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         Ok(())
     }
 
-    fn visit_upsert(&mut self, _update: crate::ast::Update<'a>) -> visitor::Result {
-        unimplemented!("Upsert not supported for the underlying database.")
+    fn visit_upsert(&mut self, update: crate::ast::Update<'a>) -> visitor::Result {
+        self.visit_update_set(update)
     }
 
     /// MySql will error if a `Update` or `Delete` query has a subselect
@@ -359,6 +366,27 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         }
     }
 
+    fn visit_index_hint(&mut self, hint: IndexHint<'a>) -> visitor::Result {
+        let (keyword, indices) = match hint {
+            IndexHint::Use(indices) => ("USE INDEX", indices),
+            IndexHint::Force(indices) => ("FORCE INDEX", indices),
+        };
+
+        self.write(" ")?;
+        self.write(keyword)?;
+        self.write(" (")?;
+
+        for (i, index) in indices.into_iter().enumerate() {
+            if i > 0 {
+                self.write(", ")?;
+            }
+
+            self.surround_with_backticks(&index)?;
+        }
+
+        self.write(")")
+    }
+
     fn visit_aggregate_to_string(&mut self, value: Expression<'a>) -> visitor::Result {
         self.write(" GROUP_CONCAT")?;
         self.surround_with("(", ")", |ref mut s| s.visit_expression(value))
@@ -737,6 +765,56 @@ mod tests {
         assert_eq!(expected.1, params);
     }
 
+    #[test]
+    fn test_insert_on_conflict_do_nothing() {
+        let expected = expected_values("INSERT IGNORE INTO `users` (`foo`) VALUES (?)", vec![10]);
+
+        let query: Insert = Insert::single_into("users").value("foo", 10).into();
+        let query = query.on_conflict(OnConflict::DoNothing);
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_insert_on_conflict_update() {
+        let expected = expected_values(
+            "INSERT INTO `users` (`foo`) VALUES (?) ON DUPLICATE KEY UPDATE `foo` = ?",
+            vec![10, 3],
+        );
+
+        let update = Update::table("users").set("foo", 3);
+
+        let query: Insert = Insert::single_into("users").value("foo", 10).into();
+        let query = query.on_conflict(OnConflict::Update(update, Vec::from(["foo".into()])));
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_use_index_hint() {
+        let query = Select::from_table("users").use_index(["idx_name"]);
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users` USE INDEX (`idx_name`)", sql);
+    }
+
+    #[test]
+    fn test_force_index_hint_with_multiple_indices() {
+        let query = Select::from_table("users").force_index(["idx_name", "idx_email"]);
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` FORCE INDEX (`idx_name`, `idx_email`)",
+            sql
+        );
+    }
+
     #[test]
     fn test_limit_and_offset_when_both_are_set() {
         let expected = expected_values("SELECT `users`.* FROM `users` LIMIT ? OFFSET ?", vec![10_i64, 2_i64]);
@@ -291,6 +291,44 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         })
     }
 
+    // Row-value comparisons (`(a, b) IN ((1, 2), (3, 4))`) are only supported since SQLite
+    // 3.15.0, and some builds Prisma connects to are older. Expand the comparison into
+    // `(a = 1 AND b = 2) OR (a = 3 AND b = 4)` instead, which works everywhere.
+    fn visit_multiple_tuple_comparison(&mut self, left: Row<'a>, right: Values<'a>, negate: bool) -> visitor::Result {
+        let row_len = left.len();
+        let values_len = right.len();
+
+        if negate {
+            self.write("NOT ")?;
+        }
+
+        self.surround_with("(", ")", |this| {
+            for (i, row) in right.into_iter().enumerate() {
+                this.surround_with("(", ")", |se| {
+                    let row_and_vals = left.values.clone().into_iter().zip(row.values.into_iter());
+
+                    for (j, (expr, val)) in row_and_vals.enumerate() {
+                        se.visit_expression(expr)?;
+                        se.write(" = ")?;
+                        se.visit_expression(val)?;
+
+                        if j < row_len - 1 {
+                            se.write(" AND ")?;
+                        }
+                    }
+
+                    Ok(())
+                })?;
+
+                if i < values_len - 1 {
+                    this.write(" OR ")?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
     fn visit_json_extract(&mut self, json_extract: JsonExtract<'a>) -> visitor::Result {
         self.visit_expression(*json_extract.column)?;
@@ -541,6 +579,7 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
 #[cfg(test)]
 mod tests {
     use crate::{val, visitor::*};
+    use tracing_test::traced_test;
 
     fn expected_values<'a, T>(sql: &'static str, params: Vec<T>) -> (String, Vec<Value<'a>>)
     where
@@ -559,6 +598,16 @@ mod tests {
         result
     }
 
+    #[test]
+    #[traced_test]
+    fn index_hint_is_ignored_with_a_warning_on_non_mysql_connectors() {
+        let query = Select::from_table("users").use_index(["idx_name"]);
+        let (sql, _) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users`", sql);
+        assert!(logs_contain("Index hints are not supported by this connector"));
+    }
+
     #[test]
     fn test_select_1() {
         let expected = expected_values("SELECT ?", vec![1]);
@@ -621,7 +670,7 @@ mod tests {
     fn test_in_values() {
         use crate::{col, values};
 
-        let expected_sql = "SELECT `test`.* FROM `test` WHERE (`id1`,`id2`) IN (VALUES (?,?),(?,?))";
+        let expected_sql = "SELECT `test`.* FROM `test` WHERE ((`id1` = ? AND `id2` = ?) OR (`id1` = ? AND `id2` = ?))";
         let query = Select::from_table("test")
             .so_that(Row::from((col!("id1"), col!("id2"))).in_selection(values!((1, 2), (3, 4))));
 
@@ -634,6 +683,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_not_in_values() {
+        use crate::{col, values};
+
+        let expected_sql =
+            "SELECT `test`.* FROM `test` WHERE NOT ((`id1` = ? AND `id2` = ?) OR (`id1` = ? AND `id2` = ?))";
+        let query = Select::from_table("test")
+            .so_that(Row::from((col!("id1"), col!("id2"))).not_in_selection(values!((1, 2), (3, 4))));
+
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+        assert_eq!(
+            vec![Value::int32(1), Value::int32(2), Value::int32(3), Value::int32(4),],
+            params
+        );
+    }
+
     #[test]
     fn test_in_values_singular() {
         let mut cols = Row::new();
@@ -152,6 +152,7 @@ pub(crate) async fn diff(schema: &str, url: String, connector: &mut dyn SchemaCo
         connection_string: url,
         preview_features: Default::default(),
         shadow_database_connection_string: None,
+        application_name: None,
     })?;
     let from = connector
         .database_schema_from_diff_target(DiffTarget::Empty, None, None)
@@ -20,7 +20,7 @@ pub(crate) async fn get_single_record(
     relation_load_strategy: RelationLoadStrategy,
     ctx: &Context<'_>,
 ) -> crate::Result<Option<SingleRecord>> {
-    match relation_load_strategy {
+    match ctx.resolve_relation_load_strategy(relation_load_strategy) {
         #[cfg(feature = "relation_joins")]
         RelationLoadStrategy::Join => get_single_record_joins(conn, model, filter, selected_fields, ctx).await,
         #[cfg(not(feature = "relation_joins"))]
@@ -122,7 +122,7 @@ pub(crate) async fn get_many_records(
     relation_load_strategy: RelationLoadStrategy,
     ctx: &Context<'_>,
 ) -> crate::Result<ManyRecords> {
-    match relation_load_strategy {
+    match ctx.resolve_relation_load_strategy(relation_load_strategy) {
         #[cfg(feature = "relation_joins")]
         RelationLoadStrategy::Join => get_many_records_joins(conn, model, query_arguments, selected_fields, ctx).await,
         #[cfg(not(feature = "relation_joins"))]
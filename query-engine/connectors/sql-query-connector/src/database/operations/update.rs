@@ -25,7 +25,7 @@ pub(crate) async fn update_one_with_selection(
     }
 
     let selected_fields = ModelProjection::from(selected_fields);
-    let update = update::update_one_with_selection(model, record_filter, args, &selected_fields, ctx);
+    let update = update::update_one_with_selection(model, record_filter, args, &selected_fields, ctx)?;
 
     let field_names: Vec<_> = selected_fields.db_names().collect();
     let idents = selected_fields.type_identifiers_with_arities();
@@ -72,7 +72,7 @@ pub(crate) async fn update_one_without_selection(
     // Note: We are _not_ getting back the ids from the update. Either we got some ids passed from the parent operation or we perform a read _before_ doing the update.
     let filter = record_filter.filter.clone();
     let ids = conn.filter_selectors(model, record_filter, ctx).await?;
-    let updates = update::update_many_from_ids_and_filter(model, filter, &ids, args, None, ctx);
+    let updates = update::update_many_from_ids_and_filter(model, filter, &ids, args, None, ctx)?;
     for update in updates {
         conn.execute(update).await?;
     }
@@ -16,10 +16,10 @@ pub(crate) async fn native_upsert(
     let meta = column_metadata::create(&field_names, &idents);
 
     let where_condition = FilterBuilder::without_top_level_joins().visit_filter(upsert.filter().clone(), ctx);
-    let update =
-        write::build_update_and_set_query(upsert.model(), upsert.update().clone(), None, ctx).so_that(where_condition);
+    let update = write::build_update_and_set_query(upsert.model(), upsert.update().clone(), None, ctx)?
+        .so_that(where_condition);
 
-    let insert = write::create_record(upsert.model(), upsert.create().clone(), &selected_fields, ctx);
+    let insert = write::create_record(upsert.model(), upsert.create().clone(), &selected_fields, ctx)?;
 
     let constraints: Vec<_> = upsert.unique_constraints().as_columns(ctx).collect();
     let query: Query = insert.on_conflict(OnConflict::Update(update, constraints)).into();
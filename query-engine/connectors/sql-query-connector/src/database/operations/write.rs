@@ -87,7 +87,7 @@ pub(crate) async fn create_record(
         _ => args,
     };
 
-    let insert = write::create_record(model, args, &ModelProjection::from(&selected_fields), ctx);
+    let insert = write::create_record(model, args, &ModelProjection::from(&selected_fields), ctx)?;
 
     let result_set = match conn.insert(insert).await {
         Ok(id) => id,
@@ -175,7 +175,7 @@ pub(crate) async fn create_records_count(
     skip_duplicates: bool,
     ctx: &Context<'_>,
 ) -> crate::Result<usize> {
-    let inserts = write::generate_insert_statements(model, args, skip_duplicates, None, ctx);
+    let inserts = write::generate_insert_statements(model, args, skip_duplicates, None, ctx)?;
     let mut count = 0;
     for insert in inserts {
         count += conn.execute(insert.into()).await?;
@@ -198,7 +198,7 @@ pub(crate) async fn create_records_returning(
     let idents = selected_fields.type_identifiers_with_arities();
     let meta = column_metadata::create(&field_names, &idents);
     let mut records = ManyRecords::new(field_names.clone());
-    let inserts = write::generate_insert_statements(model, args, skip_duplicates, Some(&selected_fields.into()), ctx);
+    let inserts = write::generate_insert_statements(model, args, skip_duplicates, Some(&selected_fields.into()), ctx)?;
 
     for insert in inserts {
         let result_set = conn.query(insert.into()).await?;
@@ -245,10 +245,10 @@ async fn generate_updates(
         let filter = record_filter.filter.clone();
         let ids = conn.filter_selectors(model, record_filter, ctx).await?;
         let slice = &ids[..limit.unwrap_or(ids.len()).min(ids.len())];
-        let queries = update::update_many_from_ids_and_filter(model, filter, slice, args, selected_fields, ctx);
+        let queries = update::update_many_from_ids_and_filter(model, filter, slice, args, selected_fields, ctx)?;
         Ok(queries)
     } else {
-        let query = update::update_many_from_filter(model, record_filter.filter, args, selected_fields, limit, ctx);
+        let query = update::update_many_from_filter(model, record_filter.filter, args, selected_fields, limit, ctx)?;
         Ok(vec![query])
     }
 }
@@ -332,7 +332,9 @@ pub(crate) async fn delete_records(
         let mut remaining_limit = limit;
         let slice = &selectors[..remaining_limit.unwrap_or(selectors.len()).min(selectors.len())];
 
-        for delete in write::delete_many_from_ids_and_filter(model, slice, filter_condition, remaining_limit, ctx) {
+        for delete in
+            write::delete_many_from_ids_and_filter(model, slice, filter_condition, None, remaining_limit, ctx)
+        {
             row_count += conn.execute(delete).await?;
             if let Some(old_remaining_limit) = remaining_limit {
                 // u64 to usize cast here cannot 'overflow' as the number of rows was limited to MAX usize in the first place.
@@ -345,7 +347,7 @@ pub(crate) async fn delete_records(
         }
         row_count
     } else {
-        conn.execute(write::delete_many_from_filter(model, filter_condition, limit, ctx))
+        conn.execute(write::delete_many_from_filter(model, filter_condition, None, limit, ctx))
             .await?
     };
 
@@ -306,6 +306,12 @@ impl From<query_structure::ConversionFailure> for SqlError {
     }
 }
 
+impl From<sql_query_builder::error::ConversionError> for SqlError {
+    fn from(e: sql_query_builder::error::ConversionError) -> Self {
+        Self::ConversionError(e.into())
+    }
+}
+
 impl From<quaint::error::Error> for SqlError {
     fn from(error: quaint::error::Error) -> Self {
         let quaint_kind = QuaintKind::from(error);
@@ -205,12 +205,14 @@ fn convert_lossy<'a>(pv: PrismaValue) -> Value<'a> {
         PrismaValue::Enum(e) => e.into(),
         PrismaValue::Int(i) => i.into(),
         PrismaValue::BigInt(i) => i.into(),
+        PrismaValue::HugeInt(i) => bigdecimal::BigDecimal::new(i, 0).into(),
         PrismaValue::Uuid(u) => u.to_string().into(),
         PrismaValue::List(l) => Value::array(l.into_iter().map(convert_lossy)),
         PrismaValue::Json(s) => Value::json(serde_json::from_str(&s).unwrap()),
         PrismaValue::Bytes(b) => Value::bytes(b),
         PrismaValue::Null => Value::null_int32(), // Can't tell which type the null is supposed to be.
         PrismaValue::Object(_) => unimplemented!(),
+        PrismaValue::Duration { .. } => unimplemented!(),
         PrismaValue::Placeholder { name, r#type } => Value::var(name, convert_placeholder_type_to_var_type(&r#type)),
     }
 }
@@ -228,5 +230,8 @@ fn convert_placeholder_type_to_var_type(pt: &PlaceholderType) -> VarType {
         PlaceholderType::Array(t) => VarType::Array(Box::new(convert_placeholder_type_to_var_type(t))),
         PlaceholderType::Object => VarType::Json,
         PlaceholderType::Bytes => VarType::Bytes,
+        // quaint's `VarType` has no interval/duration representation yet, so a duration-typed
+        // placeholder is left unbound the same way an unannotated one is.
+        PlaceholderType::Duration => VarType::Unknown,
     }
 }
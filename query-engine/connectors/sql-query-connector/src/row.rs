@@ -150,6 +150,11 @@ fn row_value_to_prisma_value(p_value: Value, meta: ColumnMetadata<'_>) -> Result
             ValueType::Bytes(Some(bytes)) if bytes.as_ref() == [0u8] => PrismaValue::Boolean(false),
             ValueType::Bytes(Some(bytes)) if bytes.as_ref() == [1u8] => PrismaValue::Boolean(true),
             ValueType::Double(Some(i)) => PrismaValue::Boolean(i.to_i64().unwrap() != 0),
+            // A legacy SQLite database may have booleans stored as `'t'`/`'f'` or `'Y'`/`'N'` text,
+            // per `SqliteBoolRepresentation`. Recognized on read regardless of which
+            // representation the `Context` that wrote the value was configured with.
+            ValueType::Text(Some(ref s)) if s.as_ref() == "t" || s.as_ref() == "Y" => PrismaValue::Boolean(true),
+            ValueType::Text(Some(ref s)) if s.as_ref() == "f" || s.as_ref() == "N" => PrismaValue::Boolean(false),
             _ => return Err(create_error(&p_value)),
         },
         TypeIdentifier::Enum(_) => match p_value.typed {
@@ -412,4 +417,36 @@ mod test {
             assert_eq!(roundtripped, i as i64);
         }
     }
+
+    fn boolean_column_metadata() -> ColumnMetadata<'static> {
+        sql_query_builder::column_metadata::create_anonymous(&[(TypeIdentifier::Boolean, FieldArity::Required)])[0]
+    }
+
+    #[test]
+    fn sqlite_tf_bool_representation_round_trips_on_read() {
+        let meta = boolean_column_metadata();
+
+        assert_eq!(
+            row_value_to_prisma_value(Value::from("t"), meta).unwrap(),
+            PrismaValue::Boolean(true)
+        );
+        assert_eq!(
+            row_value_to_prisma_value(Value::from("f"), meta).unwrap(),
+            PrismaValue::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn sqlite_ny_bool_representation_round_trips_on_read() {
+        let meta = boolean_column_metadata();
+
+        assert_eq!(
+            row_value_to_prisma_value(Value::from("Y"), meta).unwrap(),
+            PrismaValue::Boolean(true)
+        );
+        assert_eq!(
+            row_value_to_prisma_value(Value::from("N"), meta).unwrap(),
+            PrismaValue::Boolean(false)
+        );
+    }
 }
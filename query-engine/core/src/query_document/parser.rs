@@ -915,7 +915,9 @@ pub(crate) mod conversions {
             PrismaValue::DateTime(_) => "DateTime".to_string(),
             PrismaValue::Float(_) => "Float".to_string(),
             PrismaValue::BigInt(_) => "BigInt".to_string(),
+            PrismaValue::HugeInt(_) => "BigInt".to_string(),
             PrismaValue::Bytes(_) => "Bytes".to_string(),
+            PrismaValue::Duration { .. } => "Duration".to_string(),
             PrismaValue::Placeholder { r#type, .. } => r#type.to_string(),
         }
     }
@@ -324,6 +324,7 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
         }
         PrismaValue::Int(val) => serde_json::Value::Number(serde_json::Number::from(*val)),
         PrismaValue::BigInt(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::HugeInt(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
         PrismaValue::Null => serde_json::Value::Null,
         PrismaValue::Uuid(val) => serde_json::Value::String(val.to_string()),
@@ -339,6 +340,7 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
             serde_json::Value::Object(map)
         }
         PrismaValue::Placeholder { .. } => unreachable!(),
+        PrismaValue::Duration { .. } => unreachable!(),
     }
 }
 
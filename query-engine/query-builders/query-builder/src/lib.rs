@@ -1,4 +1,6 @@
-use query_structure::{FieldSelection, Filter, Model, PrismaValue, QueryArguments, RecordFilter, WriteArgs};
+use query_structure::{
+    FieldSelection, Filter, Model, PrismaValue, QueryArguments, RecordFilter, ScalarFieldRef, WriteArgs,
+};
 use serde::Serialize;
 mod query_arguments_ext;
 
@@ -27,6 +29,20 @@ pub trait QueryBuilder {
         selected_fields: Option<&FieldSelection>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Builds `INSERT` statements for `args`, upserting any row that conflicts on
+    /// `conflict_target` with `update` instead of erroring — a `createMany`-with-update
+    /// semantic. Only Postgres currently emits the native `INSERT ... ON CONFLICT (..) DO
+    /// UPDATE SET ..`; other connectors fall back to their plain `build_inserts` behavior and
+    /// ignore `conflict_target`/`update`.
+    fn build_inserts_upsert(
+        &self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        conflict_target: Vec<ScalarFieldRef>,
+        update: WriteArgs,
+        selected_fields: Option<&FieldSelection>,
+    ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>>;
+
     fn build_update(
         &self,
         model: &Model,
@@ -43,8 +59,48 @@ pub trait QueryBuilder {
         selected_fields: Option<&FieldSelection>,
         limit: Option<usize>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Builds a `COUNT(*)` query for the given model, scoped by `filter` and paged by `args`'
+    /// take/skip/cursor. A thin, single-purpose alternative to building a full aggregation with a
+    /// `Count` selection by hand.
+    fn build_count(
+        &self,
+        model: &Model,
+        args: QueryArguments,
+        filter: Filter,
+    ) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Builds a query that checks whether any record matches `filter`, without selecting or
+    /// ordering anything: `SELECT EXISTS(SELECT 1 FROM ... WHERE ..)`, or a plain `SELECT 1 ...
+    /// LIMIT 1` on connectors that can't project a boolean `EXISTS` value. Cheaper than running
+    /// `build_get_records` with a single selected column just to test for a match.
+    fn build_exists(&self, model: &Model, filter: Filter) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Builds the same query as [`QueryBuilder::build_get_records`], but locks the selected rows
+    /// with `FOR NO KEY UPDATE`, protecting them against concurrent updates and deletes without
+    /// blocking inserts of rows that reference them through a foreign key — cheaper than a plain
+    /// `FOR UPDATE` lock for workflows that only need to guard a row's own columns. Connectors
+    /// that can't take this lock return a clear error instead of silently ignoring it.
+    fn build_get_records_for_no_key_update(
+        &self,
+        model: &Model,
+        query_arguments: QueryArguments,
+        selected_fields: &FieldSelection,
+    ) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Predicts how many statements `build_inserts` will split `row_count` rows of `model` into,
+    /// without needing to build the insert itself. Assumes every row sets all of the model's
+    /// scalar fields, mirroring the connector's parameter-count and max-row-count limits; callers
+    /// can use this to report "inserting batch N of M" progress ahead of time.
+    fn planned_insert_chunk_count(&self, model: &Model, row_count: usize) -> usize;
 }
 
+/// A rendered SQL string with its bound parameters, in the order the connector's positional
+/// placeholder syntax (`?`, `$1`, `@p1`, ...) expects them. There is currently no
+/// `query-template` crate, no `PlaceholderFormat` type, and no named-placeholder rendering mode
+/// anywhere in this workspace: every `QueryBuilder` implementation renders positional
+/// placeholders only, chosen by the `Visitor` for the connector's `SqlFamily`, with no `Context`
+/// flag to select a different style.
 #[derive(Debug, Serialize)]
 pub struct DbQuery {
     pub query: String,
@@ -1,10 +1,64 @@
-use std::sync::{self, atomic::AtomicUsize};
+use std::sync::{
+    self,
+    atomic::{AtomicU64, AtomicUsize},
+};
 
-use quaint::prelude::ConnectionInfo;
+use quaint::{
+    ast::IndexHint,
+    prelude::{ConnectionInfo, SqlFamily},
+};
+use query_structure::RelationLoadStrategy;
 use telemetry::TraceParent;
 
 use crate::filter::alias::Alias;
 
+/// Generates cursor names for [`crate::SqlQueryBuilder::build_streaming_cursor`]. Process-wide
+/// rather than per-`Context`, since a fresh `Context` is constructed for every top-level
+/// operation and would otherwise restart at the same names, risking a collision between two
+/// cursors opened concurrently in different transactions.
+static CURSOR_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// How boolean values are represented in a legacy SQLite database, for interop with databases
+/// that were not created by Prisma. Only takes effect on SQLite; other connectors always use
+/// their native boolean type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteBoolRepresentation {
+    /// `0` / `1` integers. This is SQLite's own convention and the default.
+    ZeroOne,
+    /// `'f'` / `'t'` text values.
+    TF,
+    /// `'N'` / `'Y'` text values.
+    NY,
+}
+
+impl SqliteBoolRepresentation {
+    pub(crate) fn render(self, value: bool) -> quaint::ast::Value<'static> {
+        match (self, value) {
+            (SqliteBoolRepresentation::ZeroOne, _) => value.into(),
+            (SqliteBoolRepresentation::TF, true) => "t".into(),
+            (SqliteBoolRepresentation::TF, false) => "f".into(),
+            (SqliteBoolRepresentation::NY, true) => "Y".into(),
+            (SqliteBoolRepresentation::NY, false) => "N".into(),
+        }
+    }
+}
+
+/// How a decimal value that doesn't fit a column's native scale should be handled when binding
+/// it into a query. Only takes effect for columns whose scale is known (Postgres/MySQL `Decimal`
+/// native types with explicit `(precision, scale)` arguments).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalScaleRounding {
+    /// Drop the excess digits towards zero. This matches the implicit behavior of binding a
+    /// value as-is and letting the database truncate or reject it, and is the default.
+    #[default]
+    Truncate,
+    /// Round to the nearest representable value at the column's scale, ties rounding away from
+    /// zero.
+    HalfUp,
+    /// Refuse to bind a value that would lose precision at the column's scale.
+    ErrorOnLoss,
+}
+
 pub struct Context<'a> {
     connection_info: &'a ConnectionInfo,
     pub(crate) traceparent: Option<TraceParent>,
@@ -14,6 +68,24 @@ pub struct Context<'a> {
     /// Maximum number of bind parameters allowed for a single query.
     /// None is unlimited.
     pub(crate) max_bind_values: Option<usize>,
+    /// When set, every generated query logs its parameters' inferred types alongside their
+    /// values. A debugging aid for driver adapter authors tracking down parameter binding
+    /// mismatches.
+    pub(crate) log_arg_types: bool,
+    /// How booleans are represented in a SQLite database. Only takes effect when the
+    /// connection is SQLite. `None` means SQLite's native `0`/`1` integers.
+    pub(crate) sqlite_bool_representation: Option<SqliteBoolRepresentation>,
+    /// When set, forces every query built with this `Context` to use the `Query` relation load
+    /// strategy, regardless of what the query itself requested or what the global default is.
+    /// An escape hatch for callers that know a given query's join plan is too costly, without
+    /// having to thread the override through every query argument.
+    force_query_strategy: bool,
+    /// How to handle a decimal value that doesn't fit a column's native scale when binding it.
+    /// See [`DecimalScaleRounding`].
+    pub(crate) decimal_scale_rounding: DecimalScaleRounding,
+    /// A query planner hint (`USE INDEX`/`FORCE INDEX`) applied to every `SELECT` built with this
+    /// `Context`. See [`Context::with_index_hint`].
+    pub(crate) index_hint: Option<IndexHint<'static>>,
 
     alias_counter: AtomicUsize,
 }
@@ -28,11 +100,80 @@ impl<'a> Context<'a> {
             traceparent,
             max_insert_rows,
             max_bind_values: Some(max_bind_values),
+            log_arg_types: false,
+            sqlite_bool_representation: None,
+            force_query_strategy: false,
+            decimal_scale_rounding: DecimalScaleRounding::default(),
+            index_hint: None,
 
             alias_counter: Default::default(),
         }
     }
 
+    /// Forces every query built with this `Context` to use the `Query` relation load strategy,
+    /// overriding both the per-query request and the global default.
+    pub fn with_forced_query_strategy(mut self, force_query_strategy: bool) -> Self {
+        self.force_query_strategy = force_query_strategy;
+        self
+    }
+
+    /// Resolves the relation load strategy to actually use for a query, applying the forced
+    /// override (if any) on top of the `requested` strategy.
+    pub fn resolve_relation_load_strategy(&self, requested: RelationLoadStrategy) -> RelationLoadStrategy {
+        if self.force_query_strategy {
+            RelationLoadStrategy::Query
+        } else {
+            requested
+        }
+    }
+
+    /// Enables logging each generated query's parameter types alongside their values. See
+    /// [`Context::log_arg_types`].
+    pub fn with_log_arg_types(mut self, log_arg_types: bool) -> Self {
+        self.log_arg_types = log_arg_types;
+        self
+    }
+
+    /// Whether generated queries should log their parameters' inferred types, to help
+    /// diagnose driver adapter parameter binding mismatches.
+    pub fn log_arg_types(&self) -> bool {
+        self.log_arg_types
+    }
+
+    /// Configures how booleans are rendered for a legacy SQLite database. No-op on other
+    /// connectors.
+    pub fn with_sqlite_bool_representation(mut self, representation: SqliteBoolRepresentation) -> Self {
+        self.sqlite_bool_representation = Some(representation);
+        self
+    }
+
+    pub(crate) fn sqlite_bool_representation(&self) -> Option<SqliteBoolRepresentation> {
+        self.sqlite_bool_representation.filter(|_| self.sql_family().is_sqlite())
+    }
+
+    /// Configures how decimal values that exceed a column's native scale are rounded when
+    /// binding them. See [`DecimalScaleRounding`].
+    pub fn with_decimal_scale_rounding(mut self, decimal_scale_rounding: DecimalScaleRounding) -> Self {
+        self.decimal_scale_rounding = decimal_scale_rounding;
+        self
+    }
+
+    pub(crate) fn decimal_scale_rounding(&self) -> DecimalScaleRounding {
+        self.decimal_scale_rounding
+    }
+
+    /// Sets a per-query index planner hint (`USE INDEX`/`FORCE INDEX`), applied to every `SELECT`
+    /// built with this `Context`. Only rendered on MySQL; other connectors ignore it and log a
+    /// warning at build time, since it's a database-specific performance-tuning escape hatch.
+    pub fn with_index_hint(mut self, index_hint: IndexHint<'static>) -> Self {
+        self.index_hint = Some(index_hint);
+        self
+    }
+
+    pub(crate) fn index_hint(&self) -> Option<&IndexHint<'static>> {
+        self.index_hint.as_ref()
+    }
+
     pub fn traceparent(&self) -> Option<TraceParent> {
         self.traceparent
     }
@@ -41,6 +182,10 @@ impl<'a> Context<'a> {
         self.connection_info.schema_name()
     }
 
+    pub(crate) fn sql_family(&self) -> SqlFamily {
+        self.connection_info.sql_family()
+    }
+
     pub fn max_insert_rows(&self) -> Option<usize> {
         self.max_insert_rows
     }
@@ -56,4 +201,45 @@ impl<'a> Context<'a> {
     pub(crate) fn next_join_alias(&self) -> Alias {
         Alias::Join(self.alias_counter.fetch_add(1, sync::atomic::Ordering::SeqCst))
     }
+
+    /// Generates a cursor name that's unique process-wide, for use with
+    /// [`crate::SqlQueryBuilder::build_streaming_cursor`].
+    pub(crate) fn next_cursor_name(&self) -> String {
+        format!("prisma_cursor_{}", CURSOR_COUNTER.fetch_add(1, sync::atomic::Ordering::SeqCst))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quaint::prelude::{ExternalConnectionInfo, SqlFamily};
+
+    use super::*;
+
+    fn test_context(connection_info: &ConnectionInfo) -> Context<'_> {
+        Context::new(connection_info, None)
+    }
+
+    #[test]
+    fn forced_query_strategy_overrides_the_requested_strategy() {
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = test_context(&connection_info).with_forced_query_strategy(true);
+
+        assert_eq!(
+            RelationLoadStrategy::Query,
+            ctx.resolve_relation_load_strategy(RelationLoadStrategy::Join)
+        );
+    }
+
+    #[test]
+    fn unset_override_keeps_the_requested_strategy() {
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = test_context(&connection_info);
+
+        assert_eq!(
+            RelationLoadStrategy::Join,
+            ctx.resolve_relation_load_strategy(RelationLoadStrategy::Join)
+        );
+    }
 }
@@ -70,6 +70,51 @@ pub(crate) fn quaint_value_to_prisma_value(value: quaint::Value<'_>) -> PrismaVa
     }
 }
 
+/// Returns the name of a query parameter's inferred database type, for debug logging. A 1:1
+/// mapping of [`quaint::ValueType`]'s variants, ignoring the wrapped value.
+pub(crate) fn quaint_value_arg_type(value: &quaint::Value<'_>) -> &'static str {
+    match value.typed {
+        quaint::ValueType::Int32(_) => "Int32",
+        quaint::ValueType::Int64(_) => "Int64",
+        quaint::ValueType::Float(_) => "Float",
+        quaint::ValueType::Double(_) => "Double",
+        quaint::ValueType::Text(_) => "Text",
+        quaint::ValueType::Enum(_, _) => "Enum",
+        quaint::ValueType::EnumArray(_, _) => "EnumArray",
+        quaint::ValueType::Bytes(_) => "Bytes",
+        quaint::ValueType::Boolean(_) => "Boolean",
+        quaint::ValueType::Char(_) => "Char",
+        quaint::ValueType::Array(_) => "Array",
+        quaint::ValueType::Numeric(_) => "Numeric",
+        quaint::ValueType::Json(_) => "Json",
+        quaint::ValueType::Xml(_) => "Xml",
+        quaint::ValueType::Uuid(_) => "Uuid",
+        quaint::ValueType::DateTime(_) => "DateTime",
+        quaint::ValueType::Date(_) => "Date",
+        quaint::ValueType::Time(_) => "Time",
+        quaint::ValueType::Var(_, _) => "Var",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quaint_value_arg_type_matches_generated_arg_types_for_mixed_query() {
+        let params = vec![
+            quaint::Value::from(1_i32),
+            quaint::Value::from("hello"),
+            quaint::Value::from(true),
+            quaint::Value::null_datetime(),
+        ];
+
+        let arg_types: Vec<_> = params.iter().map(quaint_value_arg_type).collect();
+
+        assert_eq!(arg_types, vec!["Int32", "Text", "Boolean", "DateTime"]);
+    }
+}
+
 fn var_type_to_placeholder_type(vt: &VarType) -> PlaceholderType {
     match vt {
         VarType::Unknown => PlaceholderType::Any,
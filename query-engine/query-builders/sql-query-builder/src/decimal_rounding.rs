@@ -0,0 +1,127 @@
+use std::fmt;
+
+use bigdecimal::{
+    num_bigint::{BigInt, Sign},
+    BigDecimal, Zero,
+};
+
+use crate::context::DecimalScaleRounding;
+
+/// A [`PrismaValue::Float`](prisma_value::PrismaValue::Float) couldn't be represented at a
+/// column's native decimal scale without losing precision, and the [`Context`](crate::Context)
+/// was configured with [`DecimalScaleRounding::ErrorOnLoss`].
+#[derive(Debug, PartialEq)]
+pub(crate) struct DecimalScaleLoss {
+    pub(crate) value: BigDecimal,
+    pub(crate) scale: u8,
+}
+
+impl fmt::Display for DecimalScaleLoss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Value `{}` has more decimal digits than the column's scale of {} allows",
+            self.value, self.scale
+        )
+    }
+}
+
+/// Applies `policy` to fit `value` within `scale` decimal digits after the point. Returns `value`
+/// unchanged (not even renormalized) when it already fits.
+pub(crate) fn round_to_scale(
+    value: BigDecimal,
+    scale: u8,
+    policy: DecimalScaleRounding,
+) -> Result<BigDecimal, DecimalScaleLoss> {
+    let (digits, current_scale) = value.as_bigint_and_exponent();
+
+    // `current_scale` can be negative (e.g. `1E2` has exponent -2); nothing to round in that case.
+    if current_scale <= scale as i64 {
+        return Ok(value);
+    }
+
+    let excess = current_scale - scale as i64;
+    let divisor = BigInt::from(10u32).pow(excess as u32);
+
+    let quotient = &digits / &divisor;
+    let remainder = &digits - &quotient * &divisor;
+
+    if remainder.is_zero() {
+        return Ok(BigDecimal::new(quotient, scale as i64));
+    }
+
+    match policy {
+        DecimalScaleRounding::Truncate => Ok(BigDecimal::new(quotient, scale as i64)),
+        DecimalScaleRounding::HalfUp => {
+            let doubled_remainder_magnitude = remainder.magnitude() * 2u32;
+            let rounded = if doubled_remainder_magnitude >= *divisor.magnitude() {
+                let sign_adjustment = if digits.sign() == Sign::Minus { -1 } else { 1 };
+                quotient + sign_adjustment
+            } else {
+                quotient
+            };
+
+            Ok(BigDecimal::new(rounded, scale as i64))
+        }
+        DecimalScaleRounding::ErrorOnLoss => Err(DecimalScaleLoss { value, scale }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn bd(s: &str) -> BigDecimal {
+        BigDecimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn truncate_drops_excess_digits_towards_zero() {
+        assert_eq!(
+            round_to_scale(bd("1.239"), 2, DecimalScaleRounding::Truncate).unwrap(),
+            bd("1.23")
+        );
+        assert_eq!(
+            round_to_scale(bd("-1.239"), 2, DecimalScaleRounding::Truncate).unwrap(),
+            bd("-1.23")
+        );
+    }
+
+    #[test]
+    fn half_up_rounds_away_from_zero_on_ties_and_above() {
+        assert_eq!(
+            round_to_scale(bd("1.235"), 2, DecimalScaleRounding::HalfUp).unwrap(),
+            bd("1.24")
+        );
+        assert_eq!(
+            round_to_scale(bd("-1.235"), 2, DecimalScaleRounding::HalfUp).unwrap(),
+            bd("-1.24")
+        );
+        assert_eq!(
+            round_to_scale(bd("1.231"), 2, DecimalScaleRounding::HalfUp).unwrap(),
+            bd("1.23")
+        );
+    }
+
+    #[test]
+    fn error_on_loss_rejects_values_that_would_lose_precision() {
+        let err = round_to_scale(bd("1.239"), 2, DecimalScaleRounding::ErrorOnLoss).unwrap_err();
+
+        assert_eq!(err.scale, 2);
+        assert_eq!(err.value, bd("1.239"));
+    }
+
+    #[test]
+    fn error_on_loss_accepts_values_that_already_fit() {
+        assert_eq!(
+            round_to_scale(bd("1.20"), 2, DecimalScaleRounding::ErrorOnLoss).unwrap(),
+            bd("1.20")
+        );
+    }
+
+    #[test]
+    fn values_within_scale_are_returned_unchanged() {
+        assert_eq!(round_to_scale(bd("1.2"), 4, DecimalScaleRounding::Truncate).unwrap(), bd("1.2"));
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt;
+
+use crate::decimal_rounding::DecimalScaleLoss;
+
+pub type Result<T> = std::result::Result<T, ConversionError>;
+
+/// A [`PrismaValue`](prisma_value::PrismaValue) couldn't be converted into the [`quaint::ast::Value`]
+/// its column expects. Unlike a `panic!`, this is expected to happen on ordinary invalid input
+/// (a stale client sending a removed enum value, a `Decimal` with more digits than the column
+/// allows) and must surface to the caller as a query error rather than crashing the process.
+#[derive(Debug)]
+pub enum ConversionError {
+    DecimalScaleLoss(DecimalScaleLoss),
+    UnknownEnumValue {
+        value: String,
+        enum_name: String,
+        known_values: Vec<String>,
+    },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::DecimalScaleLoss(loss) => loss.fmt(f),
+            ConversionError::UnknownEnumValue {
+                value,
+                enum_name,
+                known_values,
+            } => write!(
+                f,
+                "Invalid enum value `{value}` for enum `{enum_name}`. Expected one of: {}",
+                known_values.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<DecimalScaleLoss> for ConversionError {
+    fn from(loss: DecimalScaleLoss) -> Self {
+        ConversionError::DecimalScaleLoss(loss)
+    }
+}
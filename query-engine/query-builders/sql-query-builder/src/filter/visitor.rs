@@ -1199,12 +1199,22 @@ fn convert_first_value<'a>(
     }
 }
 
+// `ScalarFieldExt::value` can fail on a bad enum variant or an over-precise `Decimal`, which is
+// expected to happen for `WriteArgs` (see its doc comment). Filter values hit this same
+// conversion, but threading `Result` through this whole recursive visitor is out of scope here;
+// keep the previous panicking behavior for the filter path until that's tackled separately.
+fn expect_value<'a>(result: crate::error::Result<Value<'a>>) -> Value<'a> {
+    result.unwrap_or_else(|err| panic!("{err}"))
+}
+
 fn convert_pv<'a>(field: &ScalarFieldRef, pv: PrismaValue, ctx: &Context<'_>) -> Expression<'a> {
-    field.value(pv, ctx).into()
+    expect_value(field.value(pv, ctx)).into()
 }
 
 fn convert_list_pv<'a>(field: &ScalarFieldRef, values: Vec<PrismaValue>, ctx: &Context<'_>) -> Expression<'a> {
-    Expression::from(Value::array(values.into_iter().map(|val| field.value(val, ctx))))
+    Expression::from(Value::array(
+        values.into_iter().map(|val| expect_value(field.value(val, ctx))),
+    ))
 }
 
 fn convert_pvs<'a>(fields: &[ScalarFieldRef], values: Vec<PrismaValue>, ctx: &Context<'_>) -> Vec<Value<'a>> {
@@ -1212,11 +1222,14 @@ fn convert_pvs<'a>(fields: &[ScalarFieldRef], values: Vec<PrismaValue>, ctx: &Co
         fields
             .iter()
             .zip(values)
-            .map(|(field, value)| field.value(value, ctx))
+            .map(|(field, value)| expect_value(field.value(value, ctx)))
             .collect()
     } else {
         let field = fields.first().unwrap();
-        values.into_iter().map(|value| field.value(value, ctx)).collect()
+        values
+            .into_iter()
+            .map(|value| expect_value(field.value(value, ctx)))
+            .collect()
     }
 }
 
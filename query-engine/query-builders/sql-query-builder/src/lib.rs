@@ -2,6 +2,8 @@ pub mod column_metadata;
 mod context;
 mod convert;
 mod cursor_condition;
+mod decimal_rounding;
+pub mod error;
 mod filter;
 mod join_utils;
 pub mod limit;
@@ -18,16 +20,19 @@ pub mod write;
 use std::marker::PhantomData;
 
 use quaint::{
-    ast::{Column, Comparable, ConditionTree, Query, Row, Values},
+    ast::{Column, Comparable, ConditionTree, Expression, Query, Row, Values},
+    prelude::SqlFamily,
     visitor::Visitor,
 };
 use query_builder::{DbQuery, QueryBuilder};
 use query_structure::{
-    FieldSelection, Filter, Model, ModelProjection, QueryArguments, RecordFilter, SelectionResult, WriteArgs,
+    AggregationSelection, FieldSelection, Filter, Model, ModelProjection, QueryArguments, RecordFilter, ScalarFieldRef,
+    SelectionResult, WriteArgs,
 };
 
 pub use column_metadata::ColumnMetadata;
-pub use context::Context;
+pub use context::{Context, DecimalScaleRounding, SqliteBoolRepresentation};
+pub use quaint::ast::IndexHint;
 pub use filter::FilterBuilder;
 pub use model_extensions::{AsColumn, AsColumns, AsTable, RelationFieldExt, SelectionResultExt};
 pub use sql_trace::SqlTraceComment;
@@ -51,7 +56,13 @@ impl<'a, V> SqlQueryBuilder<'a, V> {
     where
         V: Visitor<'a>,
     {
+        let query = query.into();
         let (sql, params) = V::build(query)?;
+        if self.context.log_arg_types() {
+            let arg_types: Vec<_> = params.iter().map(convert::quaint_value_arg_type).collect();
+            tracing::debug!(query = %sql, ?params, ?arg_types, "built query with parameter types");
+        }
+
         let params = params
             .into_iter()
             .map(convert::quaint_value_to_prisma_value)
@@ -85,7 +96,7 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         args: WriteArgs,
         selected_fields: &FieldSelection,
     ) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>> {
-        let query = write::create_record(model, args, &selected_fields.into(), &self.context);
+        let query = write::create_record(model, args, &selected_fields.into(), &self.context)?;
         self.convert_query(query)
     }
 
@@ -97,10 +108,34 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         selected_fields: Option<&FieldSelection>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>> {
         let projection = selected_fields.map(ModelProjection::from);
-        let query = write::generate_insert_statements(model, args, skip_duplicates, projection.as_ref(), &self.context);
+        let query = write::generate_insert_statements(model, args, skip_duplicates, projection.as_ref(), &self.context)?;
         query.into_iter().map(|q| self.convert_query(q)).collect()
     }
 
+    fn build_inserts_upsert(
+        &self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        conflict_target: Vec<ScalarFieldRef>,
+        update: WriteArgs,
+        selected_fields: Option<&FieldSelection>,
+    ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>> {
+        let projection = selected_fields.map(ModelProjection::from);
+        let queries = if self.context.sql_family() == SqlFamily::Postgres {
+            write::generate_insert_statements_with_upsert(
+                model,
+                args,
+                &conflict_target,
+                update,
+                projection.as_ref(),
+                &self.context,
+            )
+        } else {
+            write::generate_insert_statements(model, args, false, projection.as_ref(), &self.context)
+        }?;
+        queries.into_iter().map(|q| self.convert_query(q)).collect()
+    }
+
     fn build_update(
         &self,
         model: &Model,
@@ -112,7 +147,7 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
             Some(selected_fields) => {
                 let selected_fields = ModelProjection::from(selected_fields);
                 let query =
-                    update::update_one_with_selection(model, record_filter, args, &selected_fields, &self.context);
+                    update::update_one_with_selection(model, record_filter, args, &selected_fields, &self.context)?;
                 self.convert_query(query)
             }
             None => {
@@ -133,9 +168,123 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         limit: Option<usize>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>> {
         let projection = selected_fields.map(ModelProjection::from);
-        let query = update::update_many_from_filter(model, filter, args, projection.as_ref(), limit, &self.context);
+        let query = update::update_many_from_filter(model, filter, args, projection.as_ref(), limit, &self.context)?;
         Ok(vec![self.convert_query(query)?])
     }
+
+    fn build_count(
+        &self,
+        model: &Model,
+        mut args: QueryArguments,
+        filter: Filter,
+    ) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>> {
+        args.filter = Some(filter);
+        let selections = [AggregationSelection::Count {
+            all: true,
+            fields: vec![],
+        }];
+        let query = read::aggregate(model, &selections, args, &self.context);
+        self.convert_query(query)
+    }
+
+    fn build_exists(&self, model: &Model, filter: Filter) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>> {
+        let query = read::exists(model, filter, &self.context);
+        self.convert_query(query)
+    }
+
+    fn build_get_records_for_no_key_update(
+        &self,
+        model: &Model,
+        query_arguments: QueryArguments,
+        selected_fields: &FieldSelection,
+    ) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>> {
+        let query = read::get_records_for_no_key_update(
+            model,
+            ModelProjection::from(selected_fields)
+                .as_columns(&self.context)
+                .mark_all_selected(),
+            selected_fields.virtuals(),
+            query_arguments,
+            &self.context,
+        );
+        self.convert_query(query)
+    }
+
+    fn planned_insert_chunk_count(&self, model: &Model, row_count: usize) -> usize {
+        if row_count == 0 {
+            return 0;
+        }
+
+        let columns_per_row = model.fields().scalar().count().max(1);
+        let rows_per_chunk_by_params = self
+            .context
+            .max_bind_values()
+            .map(|max_params| (max_params / columns_per_row).max(1));
+
+        let rows_per_chunk = match (rows_per_chunk_by_params, self.context.max_insert_rows()) {
+            (Some(by_params), Some(max_rows)) => by_params.min(max_rows),
+            (Some(by_params), None) => by_params,
+            (None, Some(max_rows)) => max_rows,
+            (None, None) => return 1,
+        };
+
+        row_count.div_ceil(rows_per_chunk)
+    }
+}
+
+/// The queries needed to stream a `SELECT`'s results in batches through a server-side cursor
+/// instead of buffering the whole result set in memory, for large exports. Built by
+/// [`SqlQueryBuilder::build_streaming_cursor`].
+///
+/// `declare` must run first, then `fetch` repeatedly (it returns fewer rows than the requested
+/// batch size once the cursor is exhausted), then `close`. All three must run against the same
+/// connection inside the same transaction; a server-side cursor does not survive past it.
+pub struct StreamingCursor {
+    pub declare: DbQuery,
+    pub fetch: DbQuery,
+    pub close: DbQuery,
+}
+
+impl<'a, V: Visitor<'a>> SqlQueryBuilder<'a, V> {
+    /// Builds a server-side cursor over `query_arguments`'s results, for streaming them in
+    /// batches of `batch_size` rows instead of buffering the whole result set in memory. Returns
+    /// `Ok(None)` on connectors without server-side cursor support (currently only Postgres has
+    /// one); callers must fall back to [`QueryBuilder::build_get_records`] in that case.
+    ///
+    /// The caller is responsible for running the returned queries, in order, inside a single
+    /// transaction on the same connection -- see [`StreamingCursor`].
+    pub fn build_streaming_cursor(
+        &self,
+        model: &Model,
+        query_arguments: QueryArguments,
+        selected_fields: &FieldSelection,
+        batch_size: u32,
+    ) -> Result<Option<StreamingCursor>, Box<dyn std::error::Error + Send + Sync>> {
+        if self.context.sql_family() != SqlFamily::Postgres {
+            return Ok(None);
+        }
+
+        let query = read::get_records(
+            model,
+            ModelProjection::from(selected_fields)
+                .as_columns(&self.context)
+                .mark_all_selected(),
+            selected_fields.virtuals(),
+            query_arguments,
+            &self.context,
+        );
+        let select = self.convert_query(query)?;
+        let cursor_name = self.context.next_cursor_name();
+
+        let declare = DbQuery::new(
+            format!("DECLARE {cursor_name} CURSOR FOR {}", select.query),
+            select.params,
+        );
+        let fetch = DbQuery::new(format!("FETCH {batch_size} FROM {cursor_name}"), Vec::new());
+        let close = DbQuery::new(format!("CLOSE {cursor_name}"), Vec::new());
+
+        Ok(Some(StreamingCursor { declare, fetch, close }))
+    }
 }
 
 pub fn chunked_conditions<F, Q>(
@@ -162,12 +311,529 @@ pub fn in_conditions<'a>(
     results: impl IntoIterator<Item = &'a SelectionResult>,
     ctx: &Context<'_>,
 ) -> ConditionTree<'static> {
-    let mut values = Values::empty();
+    if supports_row_value_in(ctx) {
+        let mut values = Values::empty();
+
+        for result in results.into_iter() {
+            let vals: Vec<_> = result.db_values(ctx);
+            values.push(vals)
+        }
+
+        Row::from(columns.to_vec()).in_selection(values).into()
+    } else {
+        let or_conditions: Vec<Expression<'static>> = results
+            .into_iter()
+            .map(|result| {
+                let and_conditions: Vec<Expression<'static>> = columns
+                    .iter()
+                    .cloned()
+                    .zip(result.db_values(ctx))
+                    .map(|(column, value)| column.equals(value).into())
+                    .collect();
+
+                ConditionTree::And(and_conditions).into()
+            })
+            .collect();
+
+        ConditionTree::Or(or_conditions)
+    }
+}
+
+/// Whether the connector can compare a row of columns against a list of tuples in one go, e.g.
+/// `(a, b) IN ((1, 2), (3, 4))`. SQL Server doesn't support this, so [`in_conditions`] falls back
+/// to an OR-of-ANDs expansion (`(a = 1 AND b = 2) OR (a = 3 AND b = 4)`) for it.
+fn supports_row_value_in(ctx: &Context<'_>) -> bool {
+    !matches!(ctx.sql_family(), SqlFamily::Mssql)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use quaint::{
+        prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily},
+        visitor::Postgres,
+    };
+    use query_structure::{
+        DatasourceFieldName, InternalDataModel, PrismaValue, ScalarCompare, WriteArgs, WriteOperation,
+    };
+
+    use crate::model_extensions::{AsColumn, ScalarFieldExt};
+
+    use super::*;
 
-    for result in results.into_iter() {
-        let vals: Vec<_> = result.db_values(ctx);
-        values.push(vals)
+    fn test_model() -> Model {
+        let schema = r#"
+            datasource db {
+                provider = "postgresql"
+                url = "postgres://stub"
+            }
+
+            model TestModel {
+                id   Int    @id
+                name String
+            }
+        "#;
+
+        let internal_data_model = InternalDataModel {
+            schema: Arc::new(psl::validate(schema.into())),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    #[test]
+    fn build_count_generates_a_count_star_query_scoped_by_the_filter() {
+        let model = test_model();
+        let name_field = model.fields().find_from_scalar("name").unwrap();
+        let filter = name_field.equals(PrismaValue::String("Alice".to_owned()));
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let query = builder
+            .build_count(&model, QueryArguments::new(model.clone()), filter)
+            .unwrap();
+
+        assert!(query.query.contains("COUNT"));
+        assert!(query.query.contains("WHERE"));
+    }
+
+    #[test]
+    fn build_exists_generates_an_exists_query_with_no_selected_columns() {
+        let model = test_model();
+        let name_field = model.fields().find_from_scalar("name").unwrap();
+        let filter = name_field.equals(PrismaValue::String("Alice".to_owned()));
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let query = builder.build_exists(&model, filter).unwrap();
+
+        assert!(query.query.contains("SELECT EXISTS"));
+        assert!(query.query.contains("WHERE"));
+        assert!(!query.query.contains("\"name\""));
+    }
+
+    #[test]
+    fn build_exists_falls_back_to_a_plain_select_with_limit_on_mssql() {
+        let model = test_model();
+        let name_field = model.fields().find_from_scalar("name").unwrap();
+        let filter = name_field.equals(PrismaValue::String("Alice".to_owned()));
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mssql, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<quaint::visitor::Mssql<'_>>::new(ctx);
+
+        let query = builder.build_exists(&model, filter).unwrap();
+
+        assert!(!query.query.contains("EXISTS"));
+        assert!(query.query.contains("WHERE"));
+        assert!(!query.query.contains("[name]"));
     }
 
-    Row::from(columns.to_vec()).in_selection(values).into()
+    #[test]
+    fn build_get_records_for_no_key_update_appends_the_postgres_locking_clause() {
+        let model = test_model();
+        let selected_fields = model.primary_identifier();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let query = builder
+            .build_get_records_for_no_key_update(&model, QueryArguments::new(model.clone()), &selected_fields)
+            .unwrap();
+
+        assert!(query.query.ends_with("FOR NO KEY UPDATE"));
+    }
+
+    #[test]
+    fn build_get_records_for_no_key_update_errors_on_connectors_without_row_locking() {
+        let model = test_model();
+        let selected_fields = model.primary_identifier();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mssql, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<quaint::visitor::Mssql<'_>>::new(ctx);
+
+        let error = builder
+            .build_get_records_for_no_key_update(&model, QueryArguments::new(model.clone()), &selected_fields)
+            .unwrap_err();
+
+        assert!(error.to_string().contains("not supported"));
+    }
+
+    #[test]
+    fn build_streaming_cursor_declares_fetches_and_closes_a_uniquely_named_cursor() {
+        let model = test_model();
+        let selected_fields = model.primary_identifier();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let cursor = builder
+            .build_streaming_cursor(&model, QueryArguments::new(model.clone()), &selected_fields, 100)
+            .unwrap()
+            .expect("Postgres supports server-side cursors");
+
+        assert!(cursor.declare.query.starts_with("DECLARE prisma_cursor_"));
+        assert!(cursor.declare.query.contains(" CURSOR FOR SELECT"));
+        assert!(cursor.fetch.query.starts_with("FETCH 100 FROM prisma_cursor_"));
+        assert!(cursor.close.query.starts_with("CLOSE prisma_cursor_"));
+        assert!(cursor.fetch.params.is_empty());
+        assert!(cursor.close.params.is_empty());
+    }
+
+    #[test]
+    fn build_streaming_cursor_generates_a_distinct_name_on_every_call() {
+        let model = test_model();
+        let selected_fields = model.primary_identifier();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let first = builder
+            .build_streaming_cursor(&model, QueryArguments::new(model.clone()), &selected_fields, 100)
+            .unwrap()
+            .unwrap();
+        let second = builder
+            .build_streaming_cursor(&model, QueryArguments::new(model.clone()), &selected_fields, 100)
+            .unwrap()
+            .unwrap();
+
+        assert_ne!(first.declare.query, second.declare.query);
+    }
+
+    #[test]
+    fn build_streaming_cursor_is_unsupported_outside_postgres() {
+        let model = test_model();
+        let selected_fields = model.primary_identifier();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mssql, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<quaint::visitor::Mssql<'_>>::new(ctx);
+
+        let cursor = builder
+            .build_streaming_cursor(&model, QueryArguments::new(model.clone()), &selected_fields, 100)
+            .unwrap();
+
+        assert!(cursor.is_none());
+    }
+
+    #[test]
+    fn build_get_records_with_index_hint_emits_use_index_on_mysql() {
+        let model = test_model();
+        let selected_fields = model.primary_identifier();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mysql, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None)
+            .with_index_hint(quaint::ast::IndexHint::Use(vec!["idx_name".into()]));
+        let builder = SqlQueryBuilder::<quaint::visitor::Mysql<'_>>::new(ctx);
+
+        let query = builder
+            .build_get_records(&model, QueryArguments::new(model.clone()), &selected_fields)
+            .unwrap();
+
+        assert!(query.query.contains("USE INDEX (`idx_name`)"));
+    }
+
+    #[test]
+    fn build_get_records_with_index_hint_is_ignored_on_non_mysql_connectors() {
+        let model = test_model();
+        let selected_fields = model.primary_identifier();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None)
+            .with_index_hint(quaint::ast::IndexHint::Use(vec!["idx_name".into()]));
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let query = builder
+            .build_get_records(&model, QueryArguments::new(model.clone()), &selected_fields)
+            .unwrap();
+
+        assert!(!query.query.to_lowercase().contains("index"));
+    }
+
+    fn test_model_with_enum() -> Model {
+        let schema = r#"
+            datasource db {
+                provider = "postgresql"
+                url = "postgres://stub"
+            }
+
+            model TestModel {
+                id     Int    @id
+                status Status
+            }
+
+            enum Status {
+                ACTIVE
+                INACTIVE
+            }
+        "#;
+
+        let internal_data_model = InternalDataModel {
+            schema: Arc::new(psl::validate(schema.into())),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    #[test]
+    fn value_rejects_an_unknown_enum_variant_with_a_clear_message() {
+        let model = test_model_with_enum();
+        let status_field = model.fields().find_from_scalar("status").unwrap();
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+
+        let err = status_field
+            .value(PrismaValue::Enum("BOGUS".to_owned()), &ctx)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid enum value `BOGUS` for enum `Status`. Expected one of: ACTIVE, INACTIVE"
+        );
+    }
+
+    #[test]
+    fn planned_insert_chunk_count_straddles_two_chunks() {
+        let model = test_model();
+        // TestModel has 2 scalar columns (id, name), so a 10-parameter limit fits 5 rows per chunk.
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), Some(10)));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        assert_eq!(builder.planned_insert_chunk_count(&model, 8), 2);
+        assert_eq!(builder.planned_insert_chunk_count(&model, 5), 1);
+        assert_eq!(builder.planned_insert_chunk_count(&model, 0), 0);
+    }
+
+    fn test_model_with_columns(column_count: usize) -> Model {
+        let extra_fields: String = (0..column_count.saturating_sub(1))
+            .map(|i| format!("field{i} Int\n"))
+            .collect();
+
+        let schema = format!(
+            r#"
+                datasource db {{
+                    provider = "postgresql"
+                    url = "postgres://stub"
+                }}
+
+                model TestModel {{
+                    id Int @id
+                    {extra_fields}
+                }}
+            "#
+        );
+
+        let internal_data_model = InternalDataModel {
+            schema: Arc::new(psl::validate(schema.into())),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    #[test]
+    fn planned_insert_chunk_count_shrinks_as_column_count_grows() {
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), Some(100)));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let narrow_model = test_model_with_columns(2); // 100 params / 2 columns = 50 rows per chunk.
+        let wide_model = test_model_with_columns(20); // 100 params / 20 columns = 5 rows per chunk.
+
+        let narrow_chunks = builder.planned_insert_chunk_count(&narrow_model, 50);
+        let wide_chunks = builder.planned_insert_chunk_count(&wide_model, 50);
+
+        assert_eq!(narrow_chunks, 1);
+        assert!(
+            wide_chunks > narrow_chunks,
+            "a wider row should need more chunks for the same row count"
+        );
+    }
+
+    fn create_args(name: &str) -> WriteArgs {
+        let mut args = WriteArgs::new_empty(PrismaValue::Null);
+        args.insert(
+            DatasourceFieldName("name".to_owned()),
+            WriteOperation::scalar_set(PrismaValue::String(name.to_owned())),
+        );
+        args
+    }
+
+    #[test]
+    fn build_inserts_upsert_generates_on_conflict_do_update_for_postgres() {
+        let model = test_model();
+        let id_field = model.fields().find_from_scalar("id").unwrap();
+        let update = create_args("Bob");
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<Postgres<'_>>::new(ctx);
+
+        let queries = builder
+            .build_inserts_upsert(&model, vec![create_args("Alice")], vec![id_field], update, None)
+            .unwrap();
+
+        assert_eq!(queries.len(), 1);
+        assert!(queries[0].query.contains("ON CONFLICT"));
+        assert!(queries[0].query.contains("DO UPDATE SET"));
+    }
+
+    #[test]
+    fn build_inserts_upsert_falls_back_to_a_plain_insert_on_mysql() {
+        let model = test_model();
+        let id_field = model.fields().find_from_scalar("id").unwrap();
+        let update = create_args("Bob");
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mysql, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let builder = SqlQueryBuilder::<quaint::visitor::Mysql<'_>>::new(ctx);
+
+        let queries = builder
+            .build_inserts_upsert(&model, vec![create_args("Alice")], vec![id_field], update, None)
+            .unwrap();
+
+        assert_eq!(queries.len(), 1);
+        assert!(!queries[0].query.contains("ON CONFLICT"));
+        assert!(!queries[0].query.contains("ON DUPLICATE"));
+    }
+
+    fn compound_key_selection_result(ctx: &Context<'_>) -> (Vec<Column<'static>>, SelectionResult) {
+        let model = test_model();
+        let id_field = model.fields().find_from_scalar("id").unwrap();
+        let name_field = model.fields().find_from_scalar("name").unwrap();
+
+        let columns = vec![id_field.as_column(ctx), name_field.as_column(ctx)];
+        let result = SelectionResult::new(vec![
+            (id_field, PrismaValue::Int(1)),
+            (name_field, PrismaValue::String("Alice".to_owned())),
+        ]);
+
+        (columns, result)
+    }
+
+    #[test]
+    fn in_conditions_emits_a_row_value_tuple_for_postgres() {
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let (columns, result) = compound_key_selection_result(&ctx);
+
+        let tree = in_conditions(&columns, [&result], &ctx);
+
+        assert!(matches!(tree, ConditionTree::Single(_)));
+    }
+
+    #[test]
+    fn in_conditions_expands_to_or_of_and_for_mssql() {
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mssql, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+        let (columns, result) = compound_key_selection_result(&ctx);
+
+        let tree = in_conditions(&columns, [&result], &ctx);
+
+        match tree {
+            ConditionTree::Or(conditions) => {
+                assert_eq!(conditions.len(), 1);
+                assert!(matches!(
+                    conditions[0].kind,
+                    quaint::ast::ExpressionKind::ConditionTree(ConditionTree::And(_))
+                ));
+            }
+            other => panic!("expected an OR of ANDs, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn delete_many_from_filter_returns_deleted_rows_on_postgres() {
+        let model = test_model();
+        let selected_fields = ModelProjection::from(model.primary_identifier());
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Postgres, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+
+        let filter = model
+            .fields()
+            .find_from_scalar("name")
+            .unwrap()
+            .equals(PrismaValue::String("Alice".to_owned()));
+        let condition = FilterBuilder::without_top_level_joins().visit_filter(filter, &ctx);
+        let query = write::delete_many_from_filter(&model, condition, Some(&selected_fields), None, &ctx);
+
+        let query = SqlQueryBuilder::<Postgres<'_>>::new(ctx).convert_query(query).unwrap();
+
+        assert!(query.query.contains("RETURNING"));
+    }
+
+    #[test]
+    fn delete_many_from_filter_returns_deleted_rows_on_sqlite() {
+        let model = test_model();
+        let selected_fields = ModelProjection::from(model.primary_identifier());
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+
+        let filter = model
+            .fields()
+            .find_from_scalar("name")
+            .unwrap()
+            .equals(PrismaValue::String("Alice".to_owned()));
+        let condition = FilterBuilder::without_top_level_joins().visit_filter(filter, &ctx);
+        let query = write::delete_many_from_filter(&model, condition, Some(&selected_fields), None, &ctx);
+
+        let query = SqlQueryBuilder::<quaint::visitor::Sqlite>::new(ctx).convert_query(query).unwrap();
+
+        assert!(query.query.contains("RETURNING"));
+    }
+
+    #[test]
+    fn delete_many_from_filter_falls_back_without_returning_on_mysql() {
+        let model = test_model();
+        let selected_fields = ModelProjection::from(model.primary_identifier());
+
+        let connection_info =
+            ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mysql, "public".to_owned(), None));
+        let ctx = Context::new(&connection_info, None);
+
+        let filter = model
+            .fields()
+            .find_from_scalar("name")
+            .unwrap()
+            .equals(PrismaValue::String("Alice".to_owned()));
+        let condition = FilterBuilder::without_top_level_joins().visit_filter(filter, &ctx);
+        let query = write::delete_many_from_filter(&model, condition, Some(&selected_fields), None, &ctx);
+
+        let query = SqlQueryBuilder::<quaint::visitor::Mysql<'_>>::new(ctx)
+            .convert_query(query)
+            .unwrap();
+
+        assert!(!query.query.contains("RETURNING"));
+    }
 }
@@ -1,4 +1,6 @@
 use crate::context::Context;
+use crate::decimal_rounding::round_to_scale;
+use crate::error::{ConversionError, Result};
 use chrono::Utc;
 use prisma_value::PrismaValue;
 use quaint::{
@@ -8,19 +10,35 @@ use quaint::{
 use query_structure::{ScalarField, TypeIdentifier};
 
 pub(crate) trait ScalarFieldExt {
-    fn value<'a>(&self, pv: PrismaValue, ctx: &Context<'_>) -> Value<'a>;
+    fn value<'a>(&self, pv: PrismaValue, ctx: &Context<'_>) -> Result<Value<'a>>;
     fn type_family(&self) -> TypeFamily;
 }
 
 impl ScalarFieldExt for ScalarField {
-    fn value<'a>(&self, pv: PrismaValue, ctx: &Context<'_>) -> Value<'a> {
+    fn value<'a>(&self, pv: PrismaValue, ctx: &Context<'_>) -> Result<Value<'a>> {
         let value = match (pv, self.type_identifier()) {
             (PrismaValue::String(s), _) => s.into(),
+            (PrismaValue::Float(f), TypeIdentifier::Decimal) => match parse_scalar_scale(self) {
+                Some(scale) => round_to_scale(f, scale, ctx.decimal_scale_rounding())?.into(),
+                None => f.into(),
+            },
             (PrismaValue::Float(f), _) => f.into(),
-            (PrismaValue::Boolean(b), _) => b.into(),
+            (PrismaValue::Boolean(b), _) => match ctx.sqlite_bool_representation() {
+                Some(representation) => representation.render(b),
+                None => b.into(),
+            },
             (PrismaValue::DateTime(d), _) => d.with_timezone(&Utc).into(),
             (PrismaValue::Enum(e), TypeIdentifier::Enum(enum_id)) => {
                 let enum_walker = self.dm.clone().zip(enum_id);
+
+                if !enum_walker.value_names().any(|name| name == e) {
+                    return Err(ConversionError::UnknownEnumValue {
+                        value: e,
+                        enum_name: enum_walker.name().to_owned(),
+                        known_values: enum_walker.value_names().map(ToOwned::to_owned).collect(),
+                    });
+                }
+
                 let enum_name = enum_walker.db_name().to_owned();
                 let schema_name = enum_walker
                     .schema_name()
@@ -48,11 +66,15 @@ impl ScalarFieldExt for ScalarField {
             (PrismaValue::Enum(e), _) => e.into(),
             (PrismaValue::Int(i), _) => i.into(),
             (PrismaValue::BigInt(i), _) => i.into(),
+            (PrismaValue::HugeInt(i), _) => bigdecimal::BigDecimal::new(i, 0).into(),
             (PrismaValue::Uuid(u), _) => u.to_string().into(),
-            (PrismaValue::List(l), _) => Value::array(l.into_iter().map(|x| self.value(x, ctx))),
+            (PrismaValue::List(l), _) => {
+                Value::array(l.into_iter().map(|x| self.value(x, ctx)).collect::<Result<Vec<_>>>()?)
+            }
             (PrismaValue::Json(s), _) => Value::json(serde_json::from_str::<serde_json::Value>(&s).unwrap()),
             (PrismaValue::Bytes(b), _) => Value::bytes(b),
             (PrismaValue::Object(_), _) => unimplemented!(),
+            (PrismaValue::Duration { .. }, _) => unimplemented!(),
             (PrismaValue::Null, ident) => match ident {
                 TypeIdentifier::String => Value::null_text(),
                 TypeIdentifier::Float => Value::null_numeric(),
@@ -94,7 +116,7 @@ impl ScalarFieldExt for ScalarField {
 
         let nt_col_type = self.native_type().map(|nt| (nt.name(), parse_scalar_length(self)));
 
-        value.with_native_column_type(nt_col_type)
+        Ok(value.with_native_column_type(nt_col_type))
     }
 
     fn type_family(&self) -> TypeFamily {
@@ -103,15 +125,7 @@ impl ScalarFieldExt for ScalarField {
             TypeIdentifier::Int => TypeFamily::Int,
             TypeIdentifier::BigInt => TypeFamily::Int,
             TypeIdentifier::Float => TypeFamily::Double,
-            TypeIdentifier::Decimal => {
-                let params = self
-                    .native_type()
-                    .map(|nt| nt.args().into_iter())
-                    .and_then(|mut args| Some((args.next()?, args.next()?)))
-                    .and_then(|(p, s)| Some((p.parse::<u8>().ok()?, s.parse::<u8>().ok()?)));
-
-                TypeFamily::Decimal(params)
-            }
+            TypeIdentifier::Decimal => TypeFamily::Decimal(parse_scalar_precision_and_scale(self)),
             TypeIdentifier::Boolean => TypeFamily::Boolean,
             TypeIdentifier::Enum(_) => TypeFamily::Text(Some(TypeDataLength::Constant(8000))),
             TypeIdentifier::UUID => TypeFamily::Uuid,
@@ -131,3 +145,14 @@ fn parse_scalar_length(sf: &ScalarField) -> Option<TypeDataLength> {
             num => num.parse().map(TypeDataLength::Constant).ok(),
         })
 }
+
+fn parse_scalar_precision_and_scale(sf: &ScalarField) -> Option<(u8, u8)> {
+    sf.native_type()
+        .map(|nt| nt.args().into_iter())
+        .and_then(|mut args| Some((args.next()?, args.next()?)))
+        .and_then(|(p, s)| Some((p.parse::<u8>().ok()?, s.parse::<u8>().ok()?)))
+}
+
+fn parse_scalar_scale(sf: &ScalarField) -> Option<u8> {
+    parse_scalar_precision_and_scale(sf).map(|(_, scale)| scale)
+}
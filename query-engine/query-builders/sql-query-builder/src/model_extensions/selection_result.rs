@@ -35,7 +35,10 @@ impl SelectionResultExt for SelectionResult {
         self.pairs
             .iter()
             .filter_map(|(selection, v)| match selection {
-                SelectedField::Scalar(sf) => Some(sf.value(v.clone(), ctx)),
+                // These are identifiers the engine already resolved (primary/relation keys), never
+                // a client-supplied enum or `Decimal` literal, so a conversion error here would be
+                // an internal bug rather than ordinary bad input.
+                SelectedField::Scalar(sf) => Some(sf.value(v.clone(), ctx).unwrap_or_else(|err| panic!("{err}"))),
                 SelectedField::Composite(_) => None,
                 SelectedField::Relation(_) => None,
                 SelectedField::Virtual(_) => None,
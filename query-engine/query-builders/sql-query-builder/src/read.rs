@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 use query_structure::*;
 
 use crate::{
@@ -140,6 +140,12 @@ where
     let (select, additional_selection_set) = query.into_select(model, virtual_selections, ctx);
     let select = columns.fold(select, |acc, col| acc.column(col));
 
+    let select = match ctx.index_hint() {
+        Some(IndexHint::Use(indices)) => select.use_index(indices.clone()),
+        Some(IndexHint::Force(indices)) => select.force_index(indices.clone()),
+        None => select,
+    };
+
     let select = select.add_traceparent(ctx.traceparent);
 
     additional_selection_set
@@ -147,6 +153,44 @@ where
         .fold(select, |acc, col| acc.value(col))
 }
 
+/// Like [`get_records`], but locks the returned rows with `FOR NO KEY UPDATE`, guarding them
+/// against concurrent updates and deletes without blocking inserts that reference them through a
+/// foreign key. Connectors without row-locking support (anything but Postgres, currently) return
+/// a clear error when the query is built.
+pub fn get_records_for_no_key_update<'a, T>(
+    model: &Model,
+    columns: impl Iterator<Item = Column<'static>>,
+    virtual_selections: impl IntoIterator<Item = &'a VirtualSelection>,
+    query: T,
+    ctx: &Context<'_>,
+) -> Select<'static>
+where
+    T: SelectDefinition,
+{
+    get_records(model, columns, virtual_selections, query, ctx).lock_for_no_key_update()
+}
+
+/// Builds an existence check for `model` scoped by `filter`, short-circuiting selection and
+/// ordering entirely. Emits `SELECT EXISTS(SELECT 1 FROM ... WHERE ..)` where the connector can
+/// project a boolean value, and a plain `SELECT 1 ... LIMIT 1` where it can't (SQL Server), in
+/// which case the caller treats "any row returned" as existence.
+pub fn exists(model: &Model, filter: Filter, ctx: &Context<'_>) -> Select<'static> {
+    let conditions = FilterBuilder::without_top_level_joins().visit_filter(filter, ctx);
+
+    let base_select = Select::from_table(model.as_table(ctx))
+        .value(1)
+        .so_that(conditions)
+        .add_traceparent(ctx.traceparent);
+
+    if matches!(ctx.sql_family(), SqlFamily::Mssql) {
+        base_select.limit(1)
+    } else {
+        let exists_expr: Expression<'static> = Compare::Exists(Box::new(base_select.into())).into();
+
+        Select::default().value(exists_expr)
+    }
+}
+
 /// Generates a query of the form:
 /// ```sql
 /// SELECT
@@ -173,6 +217,27 @@ where
 /// ```
 /// Important note: Do not use the AsColumn trait here as we need to construct column references that are relative,
 /// not absolute - e.g. `SELECT "field" FROM (...)` NOT `SELECT "full"."path"."to"."field" FROM (...)`.
+/// Builds an existence check for `model` scoped by `filter`, short-circuiting selection and
+/// ordering entirely. Emits `SELECT EXISTS(SELECT 1 FROM ... WHERE ..)` where the connector can
+/// project a boolean value, and a plain `SELECT 1 ... LIMIT 1` where it can't (SQL Server), in
+/// which case the caller treats "any row returned" as existence.
+pub fn exists(model: &Model, filter: Filter, ctx: &Context<'_>) -> Select<'static> {
+    let conditions = FilterBuilder::without_top_level_joins().visit_filter(filter, ctx);
+
+    let base_select = Select::from_table(model.as_table(ctx))
+        .value(1)
+        .so_that(conditions)
+        .add_traceparent(ctx.traceparent);
+
+    if matches!(ctx.sql_family(), SqlFamily::Mssql) {
+        base_select.limit(1)
+    } else {
+        let exists_expr: Expression<'static> = Compare::Exists(Box::new(base_select.into())).into();
+
+        Select::default().value(exists_expr)
+    }
+}
+
 pub fn aggregate(
     model: &Model,
     selections: &[AggregationSelection],
@@ -1,6 +1,7 @@
 use quaint::ast::{Query, Update};
 use query_structure::{Filter, IntoFilter, Model, ModelProjection, RecordFilter, SelectionResult, WriteArgs};
 
+use crate::error::Result;
 use crate::{limit, write, AsColumns, Context, FilterBuilder};
 
 // Generates a query like this:
@@ -12,8 +13,8 @@ pub fn update_many_from_filter(
     selected_fields: Option<&ModelProjection>,
     limit: Option<usize>,
     ctx: &Context<'_>,
-) -> Query<'static> {
-    let update = write::build_update_and_set_query(model, args, None, ctx);
+) -> Result<Query<'static>> {
+    let update = write::build_update_and_set_query(model, args, None, ctx)?;
     let filter_condition = limit::wrap_with_limit_subquery_if_needed(
         model,
         FilterBuilder::without_top_level_joins().visit_filter(filter, ctx),
@@ -22,13 +23,13 @@ pub fn update_many_from_filter(
     );
 
     let update = update.so_that(filter_condition);
-    if let Some(selected_fields) = selected_fields {
+    Ok(if let Some(selected_fields) = selected_fields {
         update
             .returning(selected_fields.as_columns(ctx).map(|c| c.set_is_selected(true)))
             .into()
     } else {
         update.into()
-    }
+    })
 }
 
 // Generates a query like this:
@@ -40,15 +41,15 @@ pub fn update_many_from_ids_and_filter(
     args: WriteArgs,
     selected_fields: Option<&ModelProjection>,
     ctx: &Context<'_>,
-) -> Vec<Query<'static>> {
+) -> Result<Vec<Query<'static>>> {
     let filter_condition = FilterBuilder::without_top_level_joins().visit_filter(filter, ctx);
 
     if selections.is_empty() {
-        return vec![];
+        return Ok(vec![]);
     }
 
-    let update = write::build_update_and_set_query(model, args, selected_fields, ctx);
-    write::chunk_update_with_ids(update, model, selections, filter_condition, ctx)
+    let update = write::build_update_and_set_query(model, args, selected_fields, ctx)?;
+    Ok(write::chunk_update_with_ids(update, model, selections, filter_condition, ctx))
 }
 
 /// Creates an update with an explicit selection set.
@@ -58,9 +59,9 @@ pub fn update_one_with_selection(
     args: WriteArgs,
     selected_fields: &ModelProjection,
     ctx: &Context<'_>,
-) -> Update<'static> {
+) -> Result<Update<'static>> {
     let cond = FilterBuilder::without_top_level_joins().visit_filter(build_update_one_filter(record_filter), ctx);
-    write::build_update_and_set_query(model, args, Some(selected_fields), ctx).so_that(cond)
+    Ok(write::build_update_and_set_query(model, args, Some(selected_fields), ctx)?.so_that(cond))
 }
 
 /// Given a record filter, builds a ConditionTree composed of:
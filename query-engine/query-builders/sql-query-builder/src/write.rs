@@ -1,7 +1,8 @@
+use crate::error::Result;
 use crate::limit::wrap_with_limit_subquery_if_needed;
 use crate::{model_extensions::*, sql_trace::SqlTraceComment, Context};
 use itertools::Itertools;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 use query_structure::*;
 use std::{collections::HashSet, convert::TryInto};
 
@@ -12,28 +13,29 @@ pub fn create_record(
     mut args: WriteArgs,
     selected_fields: &ModelProjection,
     ctx: &Context<'_>,
-) -> Insert<'static> {
+) -> Result<Insert<'static>> {
     let fields: Vec<_> = model
         .fields()
         .scalar()
         .filter(|field| args.has_arg_for(field.db_name()))
         .collect();
 
-    let insert = fields
-        .into_iter()
-        .fold(Insert::single_into(model.as_table(ctx)), |insert, field| {
+    let insert = fields.into_iter().try_fold(
+        Insert::single_into(model.as_table(ctx)),
+        |insert, field| -> Result<SingleRowInsert<'static>> {
             let db_name = field.db_name();
             let value = args.take_field_value(db_name).unwrap();
             let value: PrismaValue = value
                 .try_into()
                 .expect("Create calls can only use PrismaValue write expressions (right now).");
 
-            insert.value(db_name.to_owned(), field.value(value, ctx))
-        });
+            Ok(insert.value(db_name.to_owned(), field.value(value, ctx)?))
+        },
+    )?;
 
-    Insert::from(insert)
+    Ok(Insert::from(insert)
         .returning(selected_fields.as_columns(ctx).map(|c| c.set_is_selected(true)))
-        .add_traceparent(ctx.traceparent)
+        .add_traceparent(ctx.traceparent))
 }
 
 /// `INSERT` new records into the database based on the given write arguments,
@@ -47,7 +49,7 @@ pub fn create_records_nonempty(
     affected_fields: &HashSet<ScalarFieldRef>,
     selected_fields: Option<&ModelProjection>,
     ctx: &Context<'_>,
-) -> Insert<'static> {
+) -> Result<Insert<'static>> {
     let mut fields = affected_fields.iter().cloned().collect_vec();
     fields.sort_by_key(|f| f.id);
 
@@ -68,7 +70,7 @@ pub fn create_records_nonempty(
                             .try_into()
                             .expect("Create calls can only use PrismaValue write expressions (right now).");
 
-                        row.push(field.value(value, ctx).into());
+                        row.push(field.value(value, ctx)?.into());
                     }
                     // We can't use `DEFAULT` for SQLite so we provided an explicit `NULL` instead.
                     None if !field.is_required() && field.default_value().is_none() => {
@@ -78,9 +80,9 @@ pub fn create_records_nonempty(
                 }
             }
 
-            row
+            Ok(row)
         })
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     let columns = fields.as_columns(ctx);
     let insert = Insert::multi_into(model.as_table(ctx), columns);
@@ -96,7 +98,7 @@ pub fn create_records_nonempty(
         insert = insert.on_conflict(OnConflict::DoNothing)
     }
 
-    insert
+    Ok(insert)
 }
 
 /// `INSERT` empty records statement.
@@ -125,67 +127,80 @@ pub fn build_update_and_set_query(
     args: WriteArgs,
     selected_fields: Option<&ModelProjection>,
     ctx: &Context<'_>,
-) -> Update<'static> {
-    let scalar_fields = model.fields().scalar();
+) -> Result<Update<'static>> {
     let table = model.as_table(ctx);
-    let query = args
-        .args
-        .into_iter()
-        .fold(Update::table(table.clone()), |acc, (field_name, val)| {
-            let DatasourceFieldName(name) = field_name;
-            let field = scalar_fields
-                .clone()
-                .find(|f| f.db_name() == name)
-                .expect("Expected field to be valid");
-
-            let value: Expression = match val.try_into_scalar().unwrap() {
-                ScalarWriteOperation::Field(_) => unimplemented!(),
-                ScalarWriteOperation::Set(rhs) => field.value(rhs, ctx).into(),
-                ScalarWriteOperation::Add(rhs) if field.is_list() => {
-                    let e: Expression = Column::from((table.clone(), name.clone())).into();
-                    let vals: Vec<_> = match rhs {
-                        PrismaValue::List(vals) => vals.into_iter().map(|val| field.value(val, ctx)).collect(),
-                        _ => vec![field.value(rhs, ctx)],
-                    };
+    let query = apply_write_args_as_set(Update::table(table), args, model, ctx)?;
+    let query = query.add_traceparent(ctx.traceparent);
 
-                    // Postgres only
-                    e.compare_raw("||", Value::array(vals)).into()
-                }
-                ScalarWriteOperation::Add(rhs) => {
-                    let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
-                    e + field.value(rhs, ctx).into()
-                }
+    let query = if let Some(selected_fields) = selected_fields {
+        query.returning(selected_fields.as_columns(ctx).map(|c| c.set_is_selected(true)))
+    } else {
+        query
+    };
 
-                ScalarWriteOperation::Substract(rhs) => {
-                    let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
-                    e - field.value(rhs, ctx).into()
-                }
+    Ok(query)
+}
 
-                ScalarWriteOperation::Multiply(rhs) => {
-                    let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
-                    e * field.value(rhs, ctx).into()
-                }
+/// Folds `args` into `update`'s `SET` clause, converting each write operation into the
+/// corresponding SQL expression. Shared between standalone `UPDATE` statements and the `DO
+/// UPDATE SET` clause of an upsert-on-conflict insert.
+fn apply_write_args_as_set(
+    update: Update<'static>,
+    args: WriteArgs,
+    model: &Model,
+    ctx: &Context<'_>,
+) -> Result<Update<'static>> {
+    let scalar_fields = model.fields().scalar();
+    let table = model.as_table(ctx);
 
-                ScalarWriteOperation::Divide(rhs) => {
-                    let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
-                    e / field.value(rhs, ctx).into()
-                }
+    args.args.into_iter().try_fold(update, |acc, (field_name, val)| {
+        let DatasourceFieldName(name) = field_name;
+        let field = scalar_fields
+            .clone()
+            .find(|f| f.db_name() == name)
+            .expect("Expected field to be valid");
+
+        let value: Expression = match val.try_into_scalar().unwrap() {
+            ScalarWriteOperation::Field(_) => unimplemented!(),
+            ScalarWriteOperation::Set(rhs) => field.value(rhs, ctx)?.into(),
+            ScalarWriteOperation::Add(rhs) if field.is_list() => {
+                let e: Expression = Column::from((table.clone(), name.clone())).into();
+                let vals: Vec<_> = match rhs {
+                    PrismaValue::List(vals) => vals
+                        .into_iter()
+                        .map(|val| field.value(val, ctx))
+                        .collect::<Result<Vec<_>>>()?,
+                    _ => vec![field.value(rhs, ctx)?],
+                };
+
+                // Postgres only
+                e.compare_raw("||", Value::array(vals)).into()
+            }
+            ScalarWriteOperation::Add(rhs) => {
+                let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
+                e + field.value(rhs, ctx)?.into()
+            }
 
-                ScalarWriteOperation::Unset(_) => unreachable!("Unset is not supported on SQL connectors"),
-            };
+            ScalarWriteOperation::Substract(rhs) => {
+                let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
+                e - field.value(rhs, ctx)?.into()
+            }
 
-            acc.set(name, value)
-        });
+            ScalarWriteOperation::Multiply(rhs) => {
+                let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
+                e * field.value(rhs, ctx)?.into()
+            }
 
-    let query = query.add_traceparent(ctx.traceparent);
+            ScalarWriteOperation::Divide(rhs) => {
+                let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
+                e / field.value(rhs, ctx)?.into()
+            }
 
-    let query = if let Some(selected_fields) = selected_fields {
-        query.returning(selected_fields.as_columns(ctx).map(|c| c.set_is_selected(true)))
-    } else {
-        query
-    };
+            ScalarWriteOperation::Unset(_) => unreachable!("Unset is not supported on SQL connectors"),
+        };
 
-    query
+        Ok(acc.set(name, value))
+    })
 }
 
 pub fn chunk_update_with_ids(
@@ -225,24 +240,43 @@ pub fn delete_returning(
         .into()
 }
 
+/// Whether the connector can return the deleted rows from a `DELETE` statement in the same
+/// round-trip. Mirrors `ConnectorCapability::DeleteReturning`, which is set for Postgres,
+/// CockroachDb, and SQLite, but not MySQL or SQL Server.
+fn supports_delete_returning(ctx: &Context<'_>) -> bool {
+    matches!(ctx.sql_family(), SqlFamily::Postgres | SqlFamily::Sqlite)
+}
+
+/// Deletes every row matching `filter_condition`, optionally chunked by `limit`. When
+/// `selected_fields` is given and the connector supports it (see [`supports_delete_returning`]),
+/// the statement returns the deleted rows so the caller can report which records were removed;
+/// otherwise it falls back to a plain `DELETE` and the caller only learns the affected row count.
 pub fn delete_many_from_filter(
     model: &Model,
     filter_condition: ConditionTree<'static>,
+    selected_fields: Option<&ModelProjection>,
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> Query<'static> {
     let filter_condition = wrap_with_limit_subquery_if_needed(model, filter_condition, limit, ctx);
 
-    Delete::from_table(model.as_table(ctx))
-        .so_that(filter_condition)
-        .add_traceparent(ctx.traceparent)
-        .into()
+    let delete = Delete::from_table(model.as_table(ctx)).so_that(filter_condition);
+
+    let delete = match selected_fields {
+        Some(selected_fields) if supports_delete_returning(ctx) => {
+            delete.returning(projection_into_columns(selected_fields, ctx))
+        }
+        _ => delete,
+    };
+
+    delete.add_traceparent(ctx.traceparent).into()
 }
 
 pub fn delete_many_from_ids_and_filter(
     model: &Model,
     ids: &[SelectionResult],
     filter_condition: ConditionTree<'static>,
+    selected_fields: Option<&ModelProjection>,
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> Vec<Query<'static>> {
@@ -251,7 +285,13 @@ pub fn delete_many_from_ids_and_filter(
         .collect();
 
     super::chunked_conditions(&columns, ids, ctx, |conditions| {
-        delete_many_from_filter(model, conditions.and(filter_condition.clone()), limit, ctx)
+        delete_many_from_filter(
+            model,
+            conditions.and(filter_condition.clone()),
+            selected_fields,
+            limit,
+            ctx,
+        )
     })
 }
 
@@ -313,12 +353,12 @@ pub fn generate_insert_statements(
     skip_duplicates: bool,
     selected_fields: Option<&ModelProjection>,
     ctx: &Context<'_>,
-) -> Vec<Insert<'static>> {
+) -> Result<Vec<Insert<'static>>> {
     let affected_fields = collect_affected_fields(&args, model);
 
     if affected_fields.is_empty() {
         args.into_iter()
-            .map(|_| create_records_empty(model, skip_duplicates, selected_fields, ctx))
+            .map(|_| Ok(create_records_empty(model, skip_duplicates, selected_fields, ctx)))
             .collect()
     } else {
         let partitioned_batches = partition_into_batches(args, ctx);
@@ -330,6 +370,50 @@ pub fn generate_insert_statements(
     }
 }
 
+/// Like [`generate_insert_statements`], but instead of `skip_duplicates` emits a native
+/// `INSERT ... ON CONFLICT (conflict_target) DO UPDATE SET ...` for a `createMany`-with-update
+/// semantic.
+///
+/// Only available on Postgres today: `ConnectorCapability::NativeUpsert` is also set for MySQL
+/// and SQLite, but those connectors' `ON DUPLICATE KEY UPDATE` / `ON CONFLICT DO UPDATE` dialects
+/// haven't been validated against an explicit, caller-chosen conflict target for bulk inserts, so
+/// callers on those connectors should keep calling `generate_insert_statements` instead.
+pub fn generate_insert_statements_with_upsert(
+    model: &Model,
+    args: Vec<WriteArgs>,
+    conflict_target: &[ScalarFieldRef],
+    update: WriteArgs,
+    selected_fields: Option<&ModelProjection>,
+    ctx: &Context<'_>,
+) -> Result<Vec<Insert<'static>>> {
+    assert_eq!(
+        ctx.sql_family(),
+        SqlFamily::Postgres,
+        "native upsert-on-conflict inserts are only supported on Postgres"
+    );
+
+    let affected_fields = collect_affected_fields(&args, model);
+
+    if affected_fields.is_empty() {
+        return args
+            .into_iter()
+            .map(|_| Ok(create_records_empty(model, false, selected_fields, ctx)))
+            .collect();
+    }
+
+    let conflict_columns: Vec<_> = conflict_target.as_columns(ctx).collect();
+
+    partition_into_batches(args, ctx)
+        .into_iter()
+        .map(|batch| {
+            let insert = create_records_nonempty(model, batch, false, &affected_fields, selected_fields, ctx)?;
+            let set_clause = apply_write_args_as_set(Update::table(model.as_table(ctx)), update.clone(), model, ctx)?;
+
+            Ok(insert.on_conflict(OnConflict::Update(set_clause, conflict_columns.clone())))
+        })
+        .collect()
+}
+
 /// Returns a set of fields that are used in the arguments for the create operation.
 fn collect_affected_fields(args: &[WriteArgs], model: &Model) -> HashSet<ScalarFieldRef> {
     let mut fields = HashSet::new();
@@ -16,6 +16,10 @@ impl InternalEnum {
     pub fn schema_name(&self) -> Option<&str> {
         self.dm.walk(self.id).schema().map(|tuple| tuple.0)
     }
+
+    pub fn value_names(&self) -> impl Iterator<Item = &str> {
+        self.dm.walk(self.id).values().map(|value| value.name())
+    }
 }
 
 impl std::fmt::Debug for InternalEnum {
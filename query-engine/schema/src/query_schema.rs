@@ -213,9 +213,9 @@ impl QuerySchema {
     }
 
     pub fn can_native_upsert(&self) -> bool {
-        self.connector
-            .capabilities()
-            .contains(ConnectorCapability::NativeUpsert)
+        let capabilities = self.connector.capabilities();
+
+        capabilities.contains(ConnectorCapability::NativeUpsert) || capabilities.contains(ConnectorCapability::Merge)
     }
 
     pub fn is_sql(&self) -> bool {
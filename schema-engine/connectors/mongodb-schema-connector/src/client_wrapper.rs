@@ -13,7 +13,12 @@ pub struct Client {
 
 impl Client {
     pub async fn connect(connection_str: &str, _preview_features: BitFlags<PreviewFeature>) -> ConnectorResult<Client> {
-        let MongoConnectionString { database, .. } = connection_str.parse().map_err(ConnectorError::url_parse_error)?;
+        let connection_string: MongoConnectionString =
+            connection_str.parse().map_err(ConnectorError::url_parse_error)?;
+        let database = connection_string
+            .require_database()
+            .map_err(ConnectorError::url_parse_error)?
+            .to_owned();
 
         let inner = mongodb_client::create(connection_str)
             .await
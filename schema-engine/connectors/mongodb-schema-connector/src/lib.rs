@@ -112,6 +112,12 @@ impl SchemaConnector for MongoDbSchemaConnector {
         ))))
     }
 
+    fn db_validate(&mut self, _script: String) -> BoxFuture<'_, ConnectorResult<()>> {
+        Box::pin(future::ready(Err(ConnectorError::from_msg(
+            "dbValidate is not supported on MongoDB".to_owned(),
+        ))))
+    }
+
     fn empty_database_schema(&self) -> DatabaseSchema {
         DatabaseSchema::new(MongoSchema::default())
     }
@@ -150,6 +156,7 @@ impl SchemaConnector for MongoDbSchemaConnector {
         &mut self,
         _soft: bool,
         _namespaces: Option<Namespaces>,
+        _externally_managed_tables: &ExternallyManagedTables,
     ) -> BoxFuture<'_, schema_connector::ConnectorResult<()>> {
         Box::pin(async { self.client().await?.drop_database().await })
     }
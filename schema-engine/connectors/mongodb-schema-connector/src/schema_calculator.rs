@@ -35,7 +35,11 @@ pub(crate) fn calculate(datamodel: &ValidatedSchema) -> MongoSchema {
                         _ => IndexFieldProperty::Ascending,
                     };
 
-                    IndexField { name, property }
+                    IndexField {
+                        name,
+                        property,
+                        kind: None,
+                    }
                 })
                 .collect();
 
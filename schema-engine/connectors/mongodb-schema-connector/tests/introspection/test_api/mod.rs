@@ -115,6 +115,7 @@ where
             connection_string: connection_string.clone(),
             preview_features,
             shadow_database_connection_string: None,
+            application_name: None,
         };
 
         let connector = MongoDbSchemaConnector::new(params);
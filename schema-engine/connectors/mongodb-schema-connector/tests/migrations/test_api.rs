@@ -80,6 +80,7 @@ fn new_connector(preview_features: BitFlags<PreviewFeature>) -> (String, MongoDb
         connection_string: url.to_string(),
         preview_features,
         shadow_database_connection_string: None,
+        application_name: None,
     };
     (db_name, MongoDbSchemaConnector::new(params))
 }
@@ -10,4 +10,7 @@ pub struct ConnectorParams {
     pub preview_features: BitFlags<PreviewFeature>,
     /// The shadow database connection string.
     pub shadow_database_connection_string: Option<String>,
+    /// The application name to report to the database server on connection, if the connector
+    /// supports it.
+    pub application_name: Option<String>,
 }
@@ -0,0 +1,25 @@
+//! Tables that a [`SchemaConnector::reset`] must preserve even though they are not part of the
+//! target (empty) schema.
+
+/// A set of table names that must survive a [`SchemaConnector::reset`]. Used for bookkeeping
+/// tables in shared-tenant databases (e.g. seed data) that live alongside Prisma-managed tables
+/// but are populated and maintained by something else.
+#[derive(Clone, Debug, Default)]
+pub struct ExternallyManagedTables(Vec<String>);
+
+impl ExternallyManagedTables {
+    /// Build a new set from the given table names.
+    pub fn new(table_names: Vec<String>) -> Self {
+        Self(table_names)
+    }
+
+    /// Whether the given table name must be preserved.
+    pub fn contains(&self, table_name: &str) -> bool {
+        self.0.iter().any(|name| name == table_name)
+    }
+
+    /// Whether no tables are externally managed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
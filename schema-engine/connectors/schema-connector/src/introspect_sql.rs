@@ -25,6 +25,9 @@ pub struct IntrospectSqlQueryOutput {
     pub documentation: Option<String>,
     pub parameters: Vec<IntrospectSqlQueryParameterOutput>,
     pub result_columns: Vec<IntrospectSqlQueryColumnOutput>,
+    /// Best-effort guess, based on the query's shape (e.g. a `LIMIT 1` clause, or an aggregate
+    /// with no `GROUP BY`), at whether the query can return at most one row.
+    pub returns_single_row: bool,
 }
 
 #[allow(missing_docs)]
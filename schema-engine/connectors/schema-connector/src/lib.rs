@@ -9,6 +9,7 @@ mod database_schema;
 mod destructive_change_checker;
 mod diff;
 mod error;
+mod externally_managed_tables;
 mod introspect_sql;
 mod introspection_context;
 mod introspection_result;
@@ -30,6 +31,7 @@ pub use destructive_change_checker::{
 };
 pub use diff::DiffTarget;
 pub use error::{ConnectorError, ConnectorResult};
+pub use externally_managed_tables::ExternallyManagedTables;
 pub use introspect_sql::*;
 pub use introspection_context::{CompositeTypeDepth, IntrospectionContext};
 pub use introspection_result::{IntrospectionResult, ViewDefinition};
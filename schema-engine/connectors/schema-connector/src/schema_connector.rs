@@ -5,8 +5,9 @@ use psl::ValidatedSchema;
 
 use crate::{
     migrations_directory::MigrationDirectory, BoxFuture, ConnectorHost, ConnectorParams, ConnectorResult,
-    DatabaseSchema, DestructiveChangeChecker, DestructiveChangeDiagnostics, DiffTarget, IntrospectSqlQueryInput,
-    IntrospectSqlQueryOutput, IntrospectionContext, IntrospectionResult, Migration, MigrationPersistence, Namespaces,
+    DatabaseSchema, DestructiveChangeChecker, DestructiveChangeDiagnostics, DiffTarget, ExternallyManagedTables,
+    IntrospectSqlQueryInput, IntrospectSqlQueryOutput, IntrospectionContext, IntrospectionResult, Migration,
+    MigrationPersistence, Namespaces,
 };
 
 /// The top-level trait for connectors. This is the abstraction the schema engine core relies on to
@@ -32,6 +33,22 @@ pub trait SchemaConnector: Send + Sync + 'static {
     /// Applies the migration to the database. Returns the number of executed steps.
     fn apply_migration<'a>(&'a mut self, migration: &'a Migration) -> BoxFuture<'a, ConnectorResult<u32>>;
 
+    /// Like [`Self::apply_migration`], but calls `on_progress(completed, total)` after each
+    /// statement of the migration is executed, so embedders can report progress on long
+    /// migrations. The default implementation has no per-statement granularity to report, so it
+    /// runs the whole migration as a single step and reports it as such.
+    fn apply_migration_with_progress<'a>(
+        &'a mut self,
+        migration: &'a Migration,
+        on_progress: &'a mut dyn FnMut(usize, usize),
+    ) -> BoxFuture<'a, ConnectorResult<u32>> {
+        Box::pin(async move {
+            let steps = self.apply_migration(migration).await?;
+            on_progress(1, 1);
+            Ok(steps)
+        })
+    }
+
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
     fn apply_script<'a>(&'a mut self, migration_name: &'a str, script: &'a str) -> BoxFuture<'a, ConnectorResult<()>>;
@@ -46,9 +63,22 @@ pub trait SchemaConnector: Send + Sync + 'static {
     /// Create the database referenced by Prisma schema that was used to initialize the connector.
     fn create_database(&mut self) -> BoxFuture<'_, ConnectorResult<String>>;
 
+    /// Whether DDL statements (`CREATE TABLE`, `ALTER TABLE`, ...) run transactionally, i.e. can
+    /// be rolled back if a later step of the same migration fails. Connectors that can't offer
+    /// this guarantee (e.g. MySQL, which implicitly commits DDL) should return `false`, so a
+    /// failed migration is reported as potentially leaving the database in a partial state.
+    fn ddl_is_transactional(&self) -> bool {
+        true
+    }
+
     /// Send a command to the database directly.
     fn db_execute(&mut self, script: String) -> BoxFuture<'_, ConnectorResult<()>>;
 
+    /// Validate a script against the database, without persisting any changes. Where the
+    /// connector supports it, the script is run inside a transaction that is rolled back
+    /// afterwards.
+    fn db_validate(&mut self, script: String) -> BoxFuture<'_, ConnectorResult<()>>;
+
     /// Create a migration by comparing two database schemas.
     fn diff(&self, from: DatabaseSchema, to: DatabaseSchema) -> Migration;
 
@@ -83,7 +113,17 @@ pub trait SchemaConnector: Send + Sync + 'static {
     ///
     /// Set the `soft` parameter to `true` to force a soft-reset, that is to say a reset that does
     /// not drop the database.
-    fn reset(&mut self, soft: bool, namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<()>>;
+    ///
+    /// `externally_managed_tables` lists tables that must survive the reset even though they are
+    /// not part of the target schema, e.g. bookkeeping tables in a shared-tenant database that
+    /// this connector doesn't own. Connectors that always drop and recreate the whole database
+    /// (or database user) cannot honor this and should document that limitation.
+    fn reset(
+        &mut self,
+        soft: bool,
+        namespaces: Option<Namespaces>,
+        externally_managed_tables: &ExternallyManagedTables,
+    ) -> BoxFuture<'_, ConnectorResult<()>>;
 
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
@@ -11,15 +11,36 @@ use tracing_futures::Instrument;
 pub(crate) async fn apply_migration(
     migration: &Migration,
     flavour: &mut (dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<u32> {
+    apply_migration_with_progress(migration, flavour, &mut |_, _| ()).await
+}
+
+#[tracing::instrument(skip(flavour, migration, on_progress))]
+pub(crate) async fn apply_migration_with_progress(
+    migration: &Migration,
+    flavour: &mut (dyn SqlFlavour + Send + Sync),
+    on_progress: &mut dyn FnMut(usize, usize),
 ) -> ConnectorResult<u32> {
     let migration: &SqlMigration = migration.downcast_ref();
     tracing::debug!("{} steps to execute", migration.steps.len());
 
-    for step in &migration.steps {
-        for sql_string in render_raw_sql(step, flavour, MigrationPair::new(&migration.before, &migration.after)) {
+    let schemas = MigrationPair::new(migration.before.as_ref(), migration.after.as_ref());
+
+    let rendered_steps: Vec<Vec<String>> = migration
+        .steps
+        .iter()
+        .map(|step| render_raw_sql(step, flavour, schemas))
+        .collect();
+    let total_statements: usize = rendered_steps.iter().map(Vec::len).sum();
+    let mut completed_statements = 0;
+
+    for (step, sql_strings) in migration.steps.iter().zip(&rendered_steps) {
+        for sql_string in sql_strings {
             assert!(!sql_string.is_empty());
             let span = tracing::info_span!("migration_step", ?step);
-            flavour.raw_cmd(&sql_string).instrument(span).await?;
+            flavour.raw_cmd(sql_string).instrument(span).await?;
+            completed_statements += 1;
+            on_progress(completed_statements, total_statements);
         }
     }
 
@@ -71,7 +92,7 @@ pub(crate) fn render_script(
 
     for step in &migration.steps {
         let statements: Vec<String> =
-            render_raw_sql(step, flavour, MigrationPair::new(&migration.before, &migration.after));
+            render_raw_sql(step, flavour, MigrationPair::new(migration.before.as_ref(), migration.after.as_ref()));
 
         if !statements.is_empty() {
             if is_first_step {
@@ -103,6 +124,91 @@ pub(crate) fn render_script(
     Ok(script)
 }
 
+/// Controls the letter case [`apply_keyword_case`] rewrites SQL keywords to in [`render_script`]
+/// output. Identifiers (table, column, constraint names, ...) are never affected: every flavour's
+/// renderer always quotes them, and [`apply_keyword_case`] leaves quoted spans untouched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeywordCase {
+    /// Keep the casing each [`SqlRenderer`](crate::sql_renderer::SqlRenderer) method already
+    /// produces (currently uppercase everywhere). The default.
+    #[default]
+    AsIs,
+    /// Rewrite every keyword to uppercase, e.g. `CREATE TABLE`.
+    Upper,
+    /// Rewrite every keyword to lowercase, e.g. `create table`.
+    Lower,
+}
+
+/// The keywords `apply_keyword_case` recognizes: the DDL/DML/transaction control words this
+/// crate's renderers actually emit, not the full SQL standard. Type names (`INTEGER`, `VARCHAR`,
+/// ...) are deliberately excluded, since the request this satisfies is about statement structure,
+/// not column type spelling.
+const KEYWORDS: &[&str] = &[
+    "ALTER", "ADD", "AND", "AS", "ASC", "BEGIN", "CASCADE", "CHECK", "CLUSTERED", "COLUMN",
+    "COMMIT", "CONSTRAINT", "CREATE", "DEFAULT", "DESC", "DROP", "ENUM", "EXISTS", "EXTENSION",
+    "FIRST", "FOR", "FOREIGN", "FULL", "IF", "IN", "INDEX", "INSERT", "INTO", "KEY", "LAST",
+    "NO", "NONCLUSTERED", "NOT", "NULL", "NULLS", "ON", "OR", "PRIMARY", "REFERENCES", "RENAME",
+    "RESTRICT", "SCHEMA", "SEQUENCE", "SET", "TABLE", "TO", "TRANSACTION", "TYPE", "UNIQUE",
+    "UNLOGGED", "USING", "VALUES", "VIEW", "VIRTUAL", "WITH",
+];
+
+/// Rewrites every [`KEYWORDS`] token in `script` to `case`, skipping anything inside a quoted
+/// span (`'...'` string literals, and `"..."`/`` `...` ``/`[...]` quoted identifiers, covering
+/// every flavour this crate renders for). Applied once to the fully assembled script rather than
+/// threaded through every [`SqlRenderer`](crate::sql_renderer::SqlRenderer) method, since those
+/// already spell keywords consistently and a single post-processing pass avoids touching dozens
+/// of call sites for what is a purely cosmetic setting.
+pub(crate) fn apply_keyword_case(script: &str, case: KeywordCase) -> String {
+    if case == KeywordCase::AsIs {
+        return script.to_owned();
+    }
+
+    let mut out = String::with_capacity(script.len());
+    let mut quote: Option<char> = None;
+    let mut word = String::new();
+
+    for ch in script.chars() {
+        if let Some(closing) = quote {
+            out.push(ch);
+            if ch == closing {
+                quote = None;
+            }
+            continue;
+        }
+
+        match ch {
+            '\'' | '"' | '`' | '[' => {
+                flush_keyword_word(&mut word, &mut out, case);
+                quote = Some(if ch == '[' { ']' } else { ch });
+                out.push(ch);
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => word.push(c),
+            _ => {
+                flush_keyword_word(&mut word, &mut out, case);
+                out.push(ch);
+            }
+        }
+    }
+    flush_keyword_word(&mut word, &mut out, case);
+
+    out
+}
+
+fn flush_keyword_word(word: &mut String, out: &mut String, case: KeywordCase) {
+    if !word.is_empty() {
+        if KEYWORDS.iter().any(|kw| kw.eq_ignore_ascii_case(word)) {
+            match case {
+                KeywordCase::AsIs => out.push_str(word),
+                KeywordCase::Upper => out.push_str(&word.to_ascii_uppercase()),
+                KeywordCase::Lower => out.push_str(&word.to_ascii_lowercase()),
+            }
+        } else {
+            out.push_str(word);
+        }
+        word.clear();
+    }
+}
+
 #[tracing::instrument(skip(script, connector))]
 pub(crate) async fn apply_script(
     migration_name: &str,
@@ -128,6 +234,7 @@ fn render_raw_sql(
         }
         SqlMigrationStep::AlterPrimaryKey(table_id) => renderer.render_alter_primary_key(schemas.walk(*table_id)),
         SqlMigrationStep::AlterEnum(alter_enum) => renderer.render_alter_enum(alter_enum, schemas),
+        SqlMigrationStep::AlterProcedure(alter_procedure) => renderer.render_alter_procedure(alter_procedure, schemas),
         SqlMigrationStep::RedefineTables(redefine_tables) => renderer.render_redefine_tables(redefine_tables, schemas),
         SqlMigrationStep::CreateEnum(enum_id) => renderer.render_create_enum(schemas.next.walk(*enum_id)),
         SqlMigrationStep::CreateSchema(namespace_id) => {
@@ -158,7 +265,8 @@ fn render_raw_sql(
             table_id: _,
             index_id,
             from_drop_and_recreate: _,
-        } => vec![renderer.render_create_index(schemas.next.walk(*index_id))],
+            concurrently,
+        } => vec![renderer.render_create_index(schemas.next.walk(*index_id), *concurrently)],
         SqlMigrationStep::DropIndex { index_id } => vec![renderer.render_drop_index(schemas.previous.walk(*index_id))],
         SqlMigrationStep::RenameIndex { index } => renderer.render_rename_index(schemas.walk(*index)),
         SqlMigrationStep::DropView(drop_view) => {
@@ -186,3 +294,30 @@ fn render_raw_sql(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_keyword_case_rewrites_keywords_but_not_identifiers_or_string_literals() {
+        let script = r#"CREATE TABLE "CREATE" (
+    "id" INTEGER NOT NULL,
+    CONSTRAINT "CREATE_pkey" PRIMARY KEY ("id")
+);
+-- comment mentioning create table is untouched
+INSERT INTO "CREATE" ("id") VALUES ('table');"#;
+
+        assert_eq!(apply_keyword_case(script, KeywordCase::AsIs), script);
+
+        let lower = apply_keyword_case(script, KeywordCase::Lower);
+        assert!(lower.contains(r#"create table "CREATE" ("#));
+        assert!(lower.contains(r#""id" INTEGER not null,"#));
+        assert!(lower.contains(r#"constraint "CREATE_pkey" primary key ("id")"#));
+        assert!(lower.contains(r#"insert into "CREATE" ("id") values ('table');"#));
+
+        let upper = apply_keyword_case(script, KeywordCase::Upper);
+        assert!(upper.contains(r#"CREATE TABLE "CREATE" ("#));
+        assert!(upper.contains(r#"CONSTRAINT "CREATE_pkey" PRIMARY KEY ("id")"#));
+    }
+}
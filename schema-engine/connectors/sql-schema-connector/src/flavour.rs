@@ -157,6 +157,14 @@ pub(crate) trait SqlFlavour:
     /// The datamodel connector corresponding to the flavour
     fn datamodel_connector(&self) -> &'static dyn psl::datamodel_connector::Connector;
 
+    /// Whether DDL statements (`CREATE TABLE`, `ALTER TABLE`, ...) run inside a transaction can
+    /// be rolled back like any other statement. MySQL implicitly commits DDL statements, so a
+    /// failed migration can leave the database in a partially-applied state; most other
+    /// connectors don't have that limitation.
+    fn ddl_is_transactional(&self) -> bool {
+        true
+    }
+
     fn describe_schema(&mut self, namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<SqlSchema>>;
 
     /// Drop the database.
@@ -276,6 +284,28 @@ pub(crate) trait SqlFlavour:
 
     fn raw_cmd<'a>(&'a mut self, sql: &'a str) -> BoxFuture<'a, ConnectorResult<()>>;
 
+    /// The statement that opens a transaction that `validate_script` wraps the script in.
+    /// Connectors that spell this differently (e.g. Microsoft SQL Server) should override it.
+    fn begin_statement(&self) -> &'static str {
+        "BEGIN"
+    }
+
+    /// The statement that rolls back the transaction opened by `begin_statement`.
+    fn rollback_statement(&self) -> &'static str {
+        "ROLLBACK"
+    }
+
+    /// Run a script inside a transaction that is rolled back at the end, so the script's syntax
+    /// and semantics can be validated without persisting any change. This is a best-effort check:
+    /// on connectors without transactional DDL, the script still runs, but nothing is undone by
+    /// the rollback.
+    fn validate_script<'a>(&'a mut self, script: &'a str) -> BoxFuture<'a, ConnectorResult<()>> {
+        Box::pin(async move {
+            let wrapped = format!("{}\n{}\n{}", self.begin_statement(), script, self.rollback_statement());
+            self.raw_cmd(&wrapped).await
+        })
+    }
+
     /// Drop the database and recreate it empty.
     fn reset(&mut self, namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<()>>;
 
@@ -66,6 +66,14 @@ impl MssqlFlavour {
 }
 
 impl SqlFlavour for MssqlFlavour {
+    fn begin_statement(&self) -> &'static str {
+        "BEGIN TRANSACTION"
+    }
+
+    fn rollback_statement(&self) -> &'static str {
+        "ROLLBACK TRANSACTION"
+    }
+
     fn acquire_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         // see
         // https://docs.microsoft.com/en-us/sql/relational-databases/system-stored-procedures/sp-getapplock-transact-sql?view=sql-server-ver15
@@ -127,6 +135,7 @@ impl SqlFlavour for MssqlFlavour {
                             connection_string: master_uri.clone(),
                             preview_features: Default::default(),
                             shadow_database_connection_string: None,
+                            application_name: None,
                         },
                     },
                 )
@@ -190,6 +199,7 @@ impl SqlFlavour for MssqlFlavour {
                         connection_string: master_uri.clone(),
                         preview_features: Default::default(),
                         shadow_database_connection_string: None,
+                        application_name: None,
                     },
                     url: MssqlUrl::new(&master_uri).unwrap(),
                 },
@@ -442,6 +452,7 @@ impl SqlFlavour for MssqlFlavour {
                         .map(|cp| cp.connector_params.preview_features)
                         .unwrap_or_default(),
                     shadow_database_connection_string: None,
+                    application_name: None,
                 };
                 shadow_database.set_params(shadow_db_params)?;
                 shadow_database.ensure_connection_validity().await?;
@@ -485,6 +496,7 @@ impl SqlFlavour for MssqlFlavour {
                     connection_string: jdbc_string,
                     preview_features: params.connector_params.preview_features,
                     shadow_database_connection_string: None,
+                    application_name: None,
                 };
                 shadow_database.set_params(shadow_db_params)?;
 
@@ -558,6 +570,7 @@ mod tests {
             connection_string: url.to_owned(),
             preview_features: Default::default(),
             shadow_database_connection_string: None,
+            application_name: None,
         };
 
         let mut flavour = MssqlFlavour::default();
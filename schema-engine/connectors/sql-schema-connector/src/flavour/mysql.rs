@@ -104,6 +104,12 @@ impl SqlFlavour for MysqlFlavour {
         psl::builtin_connectors::MYSQL
     }
 
+    fn ddl_is_transactional(&self) -> bool {
+        // MySQL implicitly commits the current transaction before and after most DDL
+        // statements, so DDL cannot be rolled back as part of a failed migration.
+        false
+    }
+
     fn describe_schema(&mut self, _namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<SqlSchema>> {
         with_connection(&mut self.state, |params, circumstances, connection| async move {
             connection.describe_schema(circumstances, params).await
@@ -245,6 +251,12 @@ impl SqlFlavour for MysqlFlavour {
         self.raw_cmd("DROP TABLE _prisma_migrations")
     }
 
+    fn empty_database_schema(&self) -> SqlSchema {
+        let mut schema = SqlSchema::default();
+        schema.set_connector_data(Box::<sql_schema_describer::mysql::MysqlSchemaExt>::default());
+        schema
+    }
+
     fn ensure_connection_validity(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         with_connection(&mut self.state, |_, _, _| future::ready(Ok(())))
     }
@@ -344,6 +356,7 @@ impl SqlFlavour for MysqlFlavour {
                         .map(|p| p.connector_params.preview_features)
                         .unwrap_or_default(),
                     shadow_database_connection_string: None,
+                    application_name: None,
                 };
 
                 shadow_database.set_params(shadow_db_params)?;
@@ -371,6 +384,7 @@ impl SqlFlavour for MysqlFlavour {
                         connection_string: shadow_database_url.to_string(),
                         preview_features: params.connector_params.preview_features,
                         shadow_database_connection_string: None,
+                        application_name: None,
                     };
 
                     let host = shadow_database_url.host();
@@ -578,6 +592,7 @@ mod tests {
             connection_string: url.to_owned(),
             preview_features: Default::default(),
             shadow_database_connection_string: None,
+            application_name: None,
         };
         flavour.set_params(params).unwrap();
         let debugged = format!("{flavour:?}");
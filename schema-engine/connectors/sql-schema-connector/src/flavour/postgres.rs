@@ -34,6 +34,21 @@ use url::Url;
 
 const ADVISORY_LOCK_TIMEOUT: time::Duration = time::Duration::from_secs(10);
 
+/// Connection string query parameter opting into `CREATE INDEX CONCURRENTLY` for indexes added
+/// to existing tables, at the cost of the statement not being run inside a transaction.
+const CONCURRENT_INDEXES_PARAM: &str = "createIndexConcurrently";
+
+fn concurrent_indexes_requested(connection_string: &str) -> bool {
+    Url::parse(connection_string)
+        .ok()
+        .and_then(|url| {
+            url.query_pairs()
+                .find(|(name, _)| name == CONCURRENT_INDEXES_PARAM)
+                .map(|(_, value)| value == "true")
+        })
+        .unwrap_or(false)
+}
+
 /// Connection settings applied to every new connection on CockroachDB.
 ///
 /// https://www.cockroachlabs.com/docs/stable/experimental-features.html
@@ -119,6 +134,10 @@ pub(crate) enum PostgresProvider {
 pub(crate) struct PostgresFlavour {
     state: State,
     provider: PostgresProvider,
+    /// Whether indexes added to existing tables should be created with `CREATE INDEX
+    /// CONCURRENTLY`, to avoid locking the table for writes. Off by default because it requires
+    /// the statement to run outside of a transaction.
+    concurrent_indexes: bool,
 }
 
 #[cfg(feature = "postgresql-native")]
@@ -143,6 +162,7 @@ impl PostgresFlavour {
         Ok(PostgresFlavour {
             state: State::new(adapter, provider, Default::default()).await?,
             provider,
+            concurrent_indexes: false,
         })
     }
 
@@ -151,6 +171,7 @@ impl PostgresFlavour {
         PostgresFlavour {
             state: State::Initial,
             provider: PostgresProvider::PostgreSql,
+            concurrent_indexes: false,
         }
     }
 
@@ -159,6 +180,7 @@ impl PostgresFlavour {
         PostgresFlavour {
             state: State::Initial,
             provider: PostgresProvider::CockroachDb,
+            concurrent_indexes: false,
         }
     }
 
@@ -167,9 +189,14 @@ impl PostgresFlavour {
         PostgresFlavour {
             state: State::Initial,
             provider: PostgresProvider::Unspecified,
+            concurrent_indexes: false,
         }
     }
 
+    pub(crate) fn concurrent_indexes(&self) -> bool {
+        self.concurrent_indexes
+    }
+
     fn circumstances(&self) -> Option<BitFlags<Circumstances>> {
         imp::get_circumstances(&self.state)
     }
@@ -416,6 +443,7 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     fn set_params(&mut self, connector_params: ConnectorParams) -> ConnectorResult<()> {
+        self.concurrent_indexes = concurrent_indexes_requested(&connector_params.connection_string);
         imp::set_params(&mut self.state, connector_params)
     }
 
@@ -607,6 +635,7 @@ mod tests {
             connection_string: url.to_owned(),
             preview_features: Default::default(),
             shadow_database_connection_string: None,
+            application_name: None,
         };
         flavour.set_params(params).unwrap();
         let debugged = format!("{flavour:?}");
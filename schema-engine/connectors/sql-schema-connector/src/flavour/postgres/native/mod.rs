@@ -307,6 +307,7 @@ pub(super) fn set_params(state: &mut State, mut connector_params: ConnectorParam
         .parse()
         .map_err(ConnectorError::url_parse_error)?;
     disable_postgres_statement_cache(&mut url)?;
+    apply_application_name(&mut url, connector_params.application_name.as_deref());
     let connection_string = url.to_string();
     let url = MigratePostgresUrl::new(url)?;
     connector_params.connection_string = connection_string;
@@ -414,3 +415,17 @@ fn disable_postgres_statement_cache(url: &mut Url) -> ConnectorResult<()> {
     }
     Ok(())
 }
+
+/// If an `application_name` was requested through `ConnectorParams`, set it on the connection
+/// URL, unless the URL already carries its own `application_name` query parameter.
+fn apply_application_name(url: &mut Url, application_name: Option<&str>) {
+    let Some(application_name) = application_name else {
+        return;
+    };
+
+    if url.query_pairs().any(|(k, _)| k == "application_name") {
+        return;
+    }
+
+    url.query_pairs_mut().append_pair("application_name", application_name);
+}
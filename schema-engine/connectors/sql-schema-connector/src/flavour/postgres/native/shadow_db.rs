@@ -40,6 +40,7 @@ pub async fn sql_schema_from_migration_history(
                     .map(|p| p.connector_params.preview_features)
                     .unwrap_or_default(),
                 shadow_database_connection_string: None,
+                application_name: None,
             };
 
             shadow_database.set_params(shadow_db_params)?;
@@ -82,6 +83,7 @@ pub async fn sql_schema_from_migration_history(
                 connection_string: shadow_database_url.to_string(),
                 preview_features: params.connector_params.preview_features,
                 shadow_database_connection_string: None,
+                application_name: None,
             };
             shadow_database.set_params(shadow_db_params)?;
             tracing::debug!("Connecting to shadow database `{}`", shadow_database_name);
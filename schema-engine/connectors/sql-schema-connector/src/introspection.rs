@@ -4,5 +4,5 @@ mod introspection_helpers;
 mod introspection_map;
 mod introspection_pair;
 mod rendering;
-mod sanitize_datamodel_names;
+pub mod sanitize_datamodel_names;
 mod warnings;
@@ -6,8 +6,10 @@ pub(crate) use context::DatamodelCalculatorContext;
 
 use crate::introspection::{rendering, warnings};
 use psl::PreviewFeature;
-use schema_connector::{IntrospectionContext, IntrospectionResult};
+use quaint::prelude::SqlFamily;
+use schema_connector::{CompositeTypeDepth, IntrospectionContext, IntrospectionResult};
 use sql_schema_describer as sql;
+use std::path::PathBuf;
 
 /// Calculate datamodels from a database schema.
 pub fn calculate(schema: &sql::SqlSchema, ctx: &IntrospectionContext, search_path: &str) -> IntrospectionResult {
@@ -35,3 +37,92 @@ pub fn calculate(schema: &sql::SqlSchema, ctx: &IntrospectionContext, search_pat
         views,
     }
 }
+
+/// Renders a [`sql::SqlSchema`] straight to a PSL string, without a live database connection or a
+/// pre-existing PSL file to introspect against. Mainly useful for unit-testing the calculator and
+/// for tools that want to convert a schema to PSL offline.
+pub fn sql_schema_to_psl(schema: &sql::SqlSchema, sql_family: SqlFamily, search_path: &str) -> String {
+    let (provider, stub_url) = match sql_family {
+        SqlFamily::Postgres => ("postgresql", "postgres://stub"),
+        SqlFamily::Mysql => ("mysql", "mysql://stub"),
+        SqlFamily::Sqlite => ("sqlite", "file:stub.db"),
+        SqlFamily::Mssql => ("sqlserver", "sqlserver://stub"),
+    };
+
+    let minimal_schema = format!("datasource db {{\n  provider = \"{provider}\"\n  url = \"{stub_url}\"\n}}\n");
+    let previous_schema = psl::validate(minimal_schema.into());
+    let ctx = IntrospectionContext::new(previous_schema, CompositeTypeDepth::None, None, PathBuf::new());
+
+    calculate(schema, &ctx, search_path).into_single_datamodel()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sql::{Column, ColumnArity, ColumnType, ColumnTypeFamily, ForeignKeyAction, IndexColumn};
+
+    #[test]
+    fn sql_schema_to_psl_renders_a_two_table_schema_with_a_foreign_key() {
+        let mut schema = sql::SqlSchema::default();
+        let namespace_id = schema.push_namespace("public".to_owned());
+
+        let user_table_id = schema.push_table("User".to_owned(), namespace_id, None);
+        let user_id_column = schema.push_table_column(
+            user_table_id,
+            Column {
+                name: "id".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                auto_increment: true,
+                description: None,
+            },
+        );
+        let user_pk = schema.push_primary_key(user_table_id, "User_pkey".to_owned());
+        schema.push_index_column(IndexColumn {
+            index_id: user_pk,
+            column_id: user_id_column,
+            sort_order: None,
+            length: None,
+        });
+
+        let post_table_id = schema.push_table("Post".to_owned(), namespace_id, None);
+        let post_id_column = schema.push_table_column(
+            post_table_id,
+            Column {
+                name: "id".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                auto_increment: true,
+                description: None,
+            },
+        );
+        let post_pk = schema.push_primary_key(post_table_id, "Post_pkey".to_owned());
+        schema.push_index_column(IndexColumn {
+            index_id: post_pk,
+            column_id: post_id_column,
+            sort_order: None,
+            length: None,
+        });
+        let author_id_column = schema.push_table_column(
+            post_table_id,
+            Column {
+                name: "authorId".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            },
+        );
+
+        let fk_id = schema.push_foreign_key(
+            Some("Post_authorId_fkey".to_owned()),
+            [post_table_id, user_table_id],
+            [ForeignKeyAction::Cascade, ForeignKeyAction::Cascade],
+        );
+        schema.push_foreign_key_column(fk_id, [author_id_column, user_id_column]);
+
+        let psl = sql_schema_to_psl(&schema, SqlFamily::Postgres, "public");
+
+        assert!(psl::validate(psl.clone().into()).diagnostics.errors().is_empty());
+        assert!(psl.contains("model User"));
+        assert!(psl.contains("model Post"));
+        assert!(psl.contains("@relation"));
+    }
+}
@@ -35,7 +35,7 @@ impl<'a> DefaultValuePair<'a> {
         let family = self.next.column_type_family();
 
         match (sql_kind, family) {
-            (Some(sql::DefaultKind::Sequence(name)), _) if self.context.is_cockroach() => {
+            (Some(sql::DefaultKind::Sequence { name, .. }), _) if self.context.is_cockroach() => {
                 let connector_data: &PostgresSchemaExt = self.context.sql_schema.downcast_connector_data();
 
                 let sequence_idx = connector_data
@@ -45,10 +45,32 @@ impl<'a> DefaultValuePair<'a> {
 
                 Some(DefaultKind::Sequence(&connector_data.sequences[sequence_idx]))
             }
+            // A plain Postgres serial/identity sequence renders as `autoincrement()` below, but
+            // one whose `INCREMENT`/`START`/`MINVALUE`/`MAXVALUE`/`CACHE` was customized needs to
+            // keep carrying those, or they're silently reset to Postgres' defaults on the next
+            // migration. Only a customized sequence is rendered as `sequence(...)`; a plain one
+            // looked up here still renders as `autoincrement()`.
+            (Some(sql::DefaultKind::Sequence { name, .. }), sql::ColumnTypeFamily::Int | sql::ColumnTypeFamily::BigInt)
+                if self.next.is_autoincrement() =>
+            {
+                let connector_data: &PostgresSchemaExt = self.context.sql_schema.downcast_connector_data();
+
+                let sequence_idx = connector_data
+                    .sequences
+                    .binary_search_by_key(&name, |s| &s.name)
+                    .unwrap();
+                let sequence = &connector_data.sequences[sequence_idx];
+
+                if sequence_has_custom_attributes(sequence) {
+                    Some(DefaultKind::Sequence(sequence))
+                } else {
+                    Some(DefaultKind::Autoincrement)
+                }
+            }
             (_, sql::ColumnTypeFamily::Int | sql::ColumnTypeFamily::BigInt) if self.next.is_autoincrement() => {
                 Some(DefaultKind::Autoincrement)
             }
-            (Some(sql::DefaultKind::Sequence(_)), _) => Some(DefaultKind::Autoincrement),
+            (Some(sql::DefaultKind::Sequence { .. }), _) => Some(DefaultKind::Autoincrement),
             (Some(sql::DefaultKind::UniqueRowid), _) => Some(DefaultKind::Autoincrement),
 
             (Some(sql::DefaultKind::DbGenerated(default_string)), _) => {
@@ -170,3 +192,16 @@ impl<'a> DefaultValuePair<'a> {
         ConstraintNames::default_name(container_name, self.next.name(), self.context.active_connector())
     }
 }
+
+/// Whether `sequence` was created with anything other than Postgres' own defaults for a fresh
+/// sequence, i.e. whether introspecting it as bare `autoincrement()` would lose information.
+/// The default maximum depends on the sequence's data type (`smallint`, `integer` or `bigint`).
+fn sequence_has_custom_attributes(sequence: &sql::postgres::Sequence) -> bool {
+    let default_max_values = [i16::MAX as i64, i32::MAX as i64, i64::MAX];
+
+    sequence.start_value != 1
+        || sequence.min_value != 1
+        || !default_max_values.contains(&sequence.max_value)
+        || sequence.increment_by != 1
+        || sequence.cache_size != 1
+}
@@ -73,6 +73,21 @@ impl<'a> ModelPair<'a> {
         self.previous.is_none() && self.context.flavour.uses_exclude_constraint(self.context, self.next)
     }
 
+    /// Whether the model has one or more constraints (of any kind) with a `COMMENT ON
+    /// CONSTRAINT` set on them.
+    pub(crate) fn adds_constraint_comments(self) -> bool {
+        self.previous.is_none() && self.has_constraint_comments()
+    }
+
+    fn has_constraint_comments(self) -> bool {
+        if !self.context.sql_family().is_postgres() {
+            return false;
+        }
+
+        let data: &PostgresSchemaExt = self.context.sql_schema.downcast_connector_data();
+        data.has_constraint_comments(self.next.id)
+    }
+
     pub(crate) fn expression_indexes(self) -> impl Iterator<Item = &'a str> {
         let mut indexes = None;
         if self.context.sql_family().is_postgres() {
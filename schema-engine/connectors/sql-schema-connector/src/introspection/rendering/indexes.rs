@@ -31,7 +31,8 @@ pub(super) fn render(index: IndexPair<'_>) -> renderer::IndexDefinition<'_> {
     let mut definition = match index.index_type() {
         sql::IndexType::Unique => renderer::IndexDefinition::unique(fields),
         sql::IndexType::Fulltext => renderer::IndexDefinition::fulltext(fields),
-        sql::IndexType::Normal => renderer::IndexDefinition::index(fields),
+        // PSL has no way to express a spatial index, so we render it as a plain index.
+        sql::IndexType::Normal | sql::IndexType::Spatial => renderer::IndexDefinition::index(fields),
         // we filter these out in the pair
         sql::IndexType::PrimaryKey => unreachable!(),
     };
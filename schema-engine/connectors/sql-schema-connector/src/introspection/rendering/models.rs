@@ -132,6 +132,12 @@ fn render_model(model: ModelPair<'_>, sql_family: SqlFamily) -> renderer::Model<
         rendered.documentation(docs);
     }
 
+    if model.adds_constraint_comments() {
+        let docs = "This model has comments on one or more of its constraints and requires additional setup for migrations. Visit https://pris.ly/d/constraint-comments for more info.";
+
+        rendered.documentation(docs);
+    }
+
     if model.adds_non_default_null_position() {
         let docs = "This model contains an index with non-default null sort order and requires additional setup for migrations. Visit https://pris.ly/d/default-index-null-ordering for more info.";
 
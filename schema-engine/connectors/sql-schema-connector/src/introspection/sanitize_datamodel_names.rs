@@ -31,6 +31,33 @@ pub(crate) fn sanitize_string<'a>(s: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
     }
 }
 
+/// The value of an `@map`/`@@map` attribute introspection would add to preserve the original,
+/// database-level name of a renamed field or model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapName(pub String);
+
+/// Predict the name introspection would give a field for the raw, database-level identifier
+/// `raw`, along with the `@map` name it would need to preserve the original name, if any.
+///
+/// This mirrors the renaming rules applied to columns during introspection (see
+/// [`IntrospectedName`]), so tooling like editor previews can predict names without running a
+/// full introspection pass.
+pub fn sanitize_identifier(raw: &str) -> (String, Option<MapName>) {
+    if raw.is_empty() {
+        return (raw.to_owned(), None);
+    }
+
+    if psl::is_reserved_type_name(raw) {
+        return (format!("Renamed{raw}"), Some(MapName(raw.to_owned())));
+    }
+
+    if needs_sanitation(raw) {
+        (sanitize_string(raw).into_owned(), Some(MapName(raw.to_owned())))
+    } else {
+        (raw.to_owned(), None)
+    }
+}
+
 /// Names that correspond to _types_ in the generated client.
 /// Concretely, enums, models and composite types.
 #[derive(Clone, Copy, Debug)]
@@ -192,3 +219,36 @@ impl<'a> EnumVariantName<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_identifier_with_spaces() {
+        let (name, map_name) = sanitize_identifier("first name");
+        assert_eq!(name, "first_name");
+        assert_eq!(map_name, Some(MapName("first name".to_owned())));
+    }
+
+    #[test]
+    fn sanitize_identifier_with_leading_digit() {
+        let (name, map_name) = sanitize_identifier("1_first");
+        assert_eq!(name, "first");
+        assert_eq!(map_name, Some(MapName("1_first".to_owned())));
+    }
+
+    #[test]
+    fn sanitize_identifier_with_reserved_word() {
+        let (name, map_name) = sanitize_identifier("String");
+        assert_eq!(name, "RenamedString");
+        assert_eq!(map_name, Some(MapName("String".to_owned())));
+    }
+
+    #[test]
+    fn sanitize_identifier_leaves_valid_names_untouched() {
+        let (name, map_name) = sanitize_identifier("valid_name");
+        assert_eq!(name, "valid_name");
+        assert_eq!(map_name, None);
+    }
+}
@@ -12,6 +12,7 @@ mod sql_destructive_change_checker;
 mod sql_doc_parser;
 mod sql_migration;
 mod sql_migration_persistence;
+mod sql_query_cardinality;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
@@ -23,8 +24,14 @@ use migration_pair::MigrationPair;
 use psl::{datamodel_connector::NativeTypeInstance, parser_database::ScalarType, ValidatedSchema};
 use quaint::connector::DescribedQuery;
 use schema_connector::{migrations_directory::MigrationDirectory, *};
+
+pub use apply_migration::KeywordCase;
+pub use introspection::datamodel_calculator::sql_schema_to_psl;
+pub use introspection::sanitize_datamodel_names::{sanitize_identifier, MapName};
+pub use sql_migration::SqlMigration;
 use sql_doc_parser::{parse_sql_doc, sanitize_sql};
-use sql_migration::{DropUserDefinedType, DropView, SqlMigration, SqlMigrationStep};
+use sql_query_cardinality::returns_at_most_one_row;
+use sql_migration::{DropUserDefinedType, DropView, SqlMigrationStep};
 use sql_schema_describer as sql;
 use std::{future, sync::Arc};
 
@@ -153,6 +160,47 @@ impl SqlSchemaConnector {
         self.flavour.set_params(params)
     }
 
+    /// Diff two [`sql::SqlSchema`]s directly, for callers that already have both schemas in hand
+    /// (e.g. from [`SqlSchemaConnector::describe_schema`]) and don't want to wrap them in
+    /// [`DatabaseSchema`] first. The diff applies this connector's dialect-specific behavior
+    /// (native type casts, extension and sequence support, and so on) exactly like
+    /// [`SchemaConnector::diff`] does.
+    ///
+    /// The resulting migration steps are an internal representation of this crate; inspect the
+    /// returned [`SqlMigration`] via [`Migration::downcast_ref`] and its `affected_tables`,
+    /// `drift_summary`, or `plan_tree` methods rather than the raw step list.
+    pub fn diff_sql_schemas(&self, from: sql::SqlSchema, to: sql::SqlSchema) -> Migration {
+        let previous = SqlDatabaseSchema::from(from);
+        let next = SqlDatabaseSchema::from(to);
+        let steps = sql_schema_differ::calculate_steps(
+            MigrationPair::new(&previous, &next),
+            self.flavour.as_ref(),
+            &ExternallyManagedTables::default(),
+        );
+
+        Migration::new(SqlMigration {
+            before: Arc::new(previous.describer_schema),
+            after: Arc::new(next.describer_schema),
+            steps,
+        })
+    }
+
+    /// Renders the migration script exactly like [`SchemaConnector::render_script`], but rewrites
+    /// every SQL keyword (`CREATE`, `TABLE`, `NOT NULL`, ...) to `keyword_case` instead of leaving
+    /// them in whatever case this crate's renderers happen to emit (currently uppercase
+    /// everywhere). Identifiers are never affected, since every flavour's renderer always quotes
+    /// them. For teams whose SQL style guide requires a specific keyword case in committed
+    /// migration files.
+    pub fn render_script_with_keyword_case(
+        &self,
+        migration: &Migration,
+        diagnostics: &DestructiveChangeDiagnostics,
+        keyword_case: KeywordCase,
+    ) -> ConnectorResult<String> {
+        apply_migration::render_script(migration, diagnostics, self.flavour())
+            .map(|script| apply_migration::apply_keyword_case(&script, keyword_case))
+    }
+
     async fn db_schema_from_diff_target(
         &mut self,
         target: DiffTarget<'_>,
@@ -230,6 +278,18 @@ impl SchemaConnector for SqlSchemaConnector {
         Box::pin(apply_migration::apply_migration(migration, self.flavour.as_mut()))
     }
 
+    fn apply_migration_with_progress<'a>(
+        &'a mut self,
+        migration: &'a Migration,
+        on_progress: &'a mut dyn FnMut(usize, usize),
+    ) -> BoxFuture<'a, ConnectorResult<u32>> {
+        Box::pin(apply_migration::apply_migration_with_progress(
+            migration,
+            self.flavour.as_mut(),
+            on_progress,
+        ))
+    }
+
     fn apply_script<'a>(&'a mut self, migration_name: &'a str, script: &'a str) -> BoxFuture<'a, ConnectorResult<()>> {
         Box::pin(apply_migration::apply_script(migration_name, script, self))
     }
@@ -260,6 +320,10 @@ impl SchemaConnector for SqlSchemaConnector {
         self.flavour.create_database()
     }
 
+    fn ddl_is_transactional(&self) -> bool {
+        self.flavour.ddl_is_transactional()
+    }
+
     fn database_schema_from_diff_target<'a>(
         &'a mut self,
         diff_target: DiffTarget<'a>,
@@ -277,16 +341,24 @@ impl SchemaConnector for SqlSchemaConnector {
         Box::pin(async move { self.flavour.raw_cmd(&script).await })
     }
 
+    fn db_validate(&mut self, script: String) -> BoxFuture<'_, ConnectorResult<()>> {
+        Box::pin(async move { self.flavour.validate_script(&script).await })
+    }
+
     #[tracing::instrument(skip(self, from, to))]
     fn diff(&self, from: DatabaseSchema, to: DatabaseSchema) -> Migration {
         let previous = SqlDatabaseSchema::from_erased(from);
         let next = SqlDatabaseSchema::from_erased(to);
-        let steps = sql_schema_differ::calculate_steps(MigrationPair::new(&previous, &next), self.flavour.as_ref());
+        let steps = sql_schema_differ::calculate_steps(
+            MigrationPair::new(&previous, &next),
+            self.flavour.as_ref(),
+            &ExternallyManagedTables::default(),
+        );
         tracing::debug!(?steps, "Inferred migration steps.");
 
         Migration::new(SqlMigration {
-            before: previous.describer_schema,
-            after: next.describer_schema,
+            before: Arc::new(previous.describer_schema),
+            after: Arc::new(next.describer_schema),
             steps,
         })
     }
@@ -330,10 +402,21 @@ impl SchemaConnector for SqlSchemaConnector {
         apply_migration::render_script(migration, diagnostics, self.flavour())
     }
 
-    fn reset(&mut self, soft: bool, namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<()>> {
+    fn reset(
+        &mut self,
+        soft: bool,
+        namespaces: Option<Namespaces>,
+        externally_managed_tables: &ExternallyManagedTables,
+    ) -> BoxFuture<'_, ConnectorResult<()>> {
+        let externally_managed_tables = externally_managed_tables.clone();
         Box::pin(async move {
-            if soft || self.flavour.reset(namespaces.clone()).await.is_err() {
-                best_effort_reset(self.flavour.as_mut(), namespaces).await?;
+            // The native fast path drops and recreates the whole database or schema, which would
+            // take the externally managed tables down with it, so it can only be used when there
+            // are none to preserve.
+            let can_use_native_reset = externally_managed_tables.is_empty();
+
+            if soft || !can_use_native_reset || self.flavour.reset(namespaces.clone()).await.is_err() {
+                best_effort_reset(self.flavour.as_mut(), namespaces, &externally_managed_tables).await?;
             }
 
             Ok(())
@@ -424,6 +507,7 @@ impl SchemaConnector for SqlSchemaConnector {
                 })
                 .collect();
             let columns = columns.into_iter().map(IntrospectSqlQueryColumnOutput::from).collect();
+            let returns_single_row = returns_at_most_one_row(&sanitized_sql);
 
             Ok(IntrospectSqlQueryOutput {
                 name: input.name,
@@ -431,6 +515,7 @@ impl SchemaConnector for SqlSchemaConnector {
                 documentation: parsed_doc.description().map(ToOwned::to_owned),
                 parameters,
                 result_columns: columns,
+                returns_single_row,
             })
         })
     }
@@ -446,8 +531,9 @@ fn new_shadow_database_name() -> String {
 async fn best_effort_reset(
     flavour: &mut (dyn SqlFlavour + Send + Sync),
     namespaces: Option<Namespaces>,
+    externally_managed_tables: &ExternallyManagedTables,
 ) -> ConnectorResult<()> {
-    best_effort_reset_impl(flavour, namespaces)
+    best_effort_reset_impl(flavour, namespaces, externally_managed_tables)
         .await
         .map_err(|err| err.into_soft_reset_failed_error())
 }
@@ -455,11 +541,20 @@ async fn best_effort_reset(
 async fn best_effort_reset_impl(
     flavour: &mut (dyn SqlFlavour + Send + Sync),
     namespaces: Option<Namespaces>,
+    externally_managed_tables: &ExternallyManagedTables,
 ) -> ConnectorResult<()> {
     tracing::info!("Attempting best_effort_reset");
 
     let source_schema = flavour.describe_schema(namespaces).await?;
     let target_schema = flavour.empty_database_schema();
+
+    if !externally_managed_tables.is_empty() {
+        check_no_foreign_keys_from_externally_managed_tables_into_dropped_tables(
+            &source_schema,
+            externally_managed_tables,
+        )?;
+    }
+
     let mut steps = Vec::new();
 
     // We drop views here, not in the normal migration process to not
@@ -473,7 +568,11 @@ async fn best_effort_reset_impl(
     steps.extend(drop_views);
 
     let diffables: MigrationPair<SqlDatabaseSchema> = MigrationPair::new(source_schema, target_schema).map(From::from);
-    steps.extend(sql_schema_differ::calculate_steps(diffables.as_ref(), flavour));
+    steps.extend(sql_schema_differ::calculate_steps(
+        diffables.as_ref(),
+        flavour,
+        externally_managed_tables,
+    ));
     let (source_schema, target_schema) = diffables.map(|s| s.describer_schema).into_tuple();
 
     let drop_udts = source_schema
@@ -485,8 +584,8 @@ async fn best_effort_reset_impl(
     steps.extend(drop_udts);
 
     let migration = SqlMigration {
-        before: source_schema,
-        after: target_schema,
+        before: Arc::new(source_schema),
+        after: Arc::new(target_schema),
         steps,
     };
 
@@ -508,3 +607,35 @@ async fn best_effort_reset_impl(
 
     Ok(())
 }
+
+/// An externally managed table is never dropped, but a table it references via a foreign key
+/// might be, since it is invisible to the differ ([`sql_schema_differ::calculate_steps`] skips it
+/// like any other ignored table). Dropping the referenced table would leave the externally
+/// managed table with a dangling foreign key, so bail out with a clear error instead of producing
+/// a script that fails (or silently corrupts referential integrity) when applied.
+fn check_no_foreign_keys_from_externally_managed_tables_into_dropped_tables(
+    source_schema: &sql::SqlSchema,
+    externally_managed_tables: &ExternallyManagedTables,
+) -> ConnectorResult<()> {
+    for table in source_schema
+        .table_walkers()
+        .filter(|table| externally_managed_tables.contains(table.name()))
+    {
+        for fk in table.foreign_keys() {
+            let referenced_table = fk.referenced_table();
+
+            if !externally_managed_tables.contains(referenced_table.name()) {
+                return Err(ConnectorError::from_msg(format!(
+                    "Cannot reset the database: externally managed table `{}` has a foreign key to \
+                     `{}`, which is not externally managed and would be dropped. Add `{}` to the \
+                     externally managed tables, or remove the foreign key.",
+                    table.name(),
+                    referenced_table.name(),
+                    referenced_table.name(),
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -151,6 +151,7 @@ impl SqlSchemaConnector {
                             }
                             TableChange::AddPrimaryKey { .. } => (),
                             TableChange::RenamePrimaryKey { .. } => (),
+                            TableChange::AlterReplicaIdentity => (),
                         }
                     }
                 }
@@ -251,6 +252,7 @@ impl SqlSchemaConnector {
                     table_id: (Some(_), _),
                     index_id,
                     from_drop_and_recreate: false,
+                    concurrently: _,
                 } => {
                     let index = schemas.next.walk(*index_id);
                     if index.is_unique() {
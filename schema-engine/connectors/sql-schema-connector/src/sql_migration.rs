@@ -9,19 +9,116 @@ use sql_schema_describer::{
     walkers::{TableColumnWalker, TableWalker},
     EnumId, ForeignKeyId, IndexId, SqlSchema, TableColumnId, TableId, UdtId, ViewId,
 };
-use std::{collections::BTreeSet, fmt::Write as _};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    sync::Arc,
+};
 
 /// The database migration type for SqlMigrationConnector.
 #[derive(Debug)]
 pub struct SqlMigration {
-    pub(crate) before: SqlSchema,
-    pub(crate) after: SqlSchema,
+    pub(crate) before: Arc<SqlSchema>,
+    pub(crate) after: Arc<SqlSchema>,
     pub(crate) steps: Vec<SqlMigrationStep>,
 }
 
 impl SqlMigration {
     pub(crate) fn schemas(&self) -> MigrationPair<&SqlSchema> {
-        MigrationPair::new(&self.before, &self.after)
+        MigrationPair::new(self.before.as_ref(), self.after.as_ref())
+    }
+
+    /// The distinct set of tables touched by any step in the migration, for callers that want
+    /// to invalidate caches or plan locks without walking the full `steps_json` representation.
+    pub fn affected_tables(&self) -> Vec<String> {
+        let mut tables: BTreeSet<&str> = BTreeSet::new();
+
+        for step in &self.steps {
+            tables.extend(self.tables_touched_by_step(step));
+        }
+
+        tables.into_iter().map(ToOwned::to_owned).collect()
+    }
+
+    /// Returns only the steps of this migration that touch `table_name`, in their original
+    /// order. This is what powers surgical drift repair: instead of applying the full inferred
+    /// migration, a caller can single out one drifted table (or an index on it) and get just
+    /// the steps needed to bring that one object back in line, leaving everything else alone.
+    pub(crate) fn steps_for_table(&self, table_name: &str) -> Vec<&SqlMigrationStep> {
+        self.steps
+            .iter()
+            .filter(|step| self.tables_touched_by_step(step).contains(&table_name))
+            .collect()
+    }
+
+    fn tables_touched_by_step(&self, step: &SqlMigrationStep) -> Vec<&str> {
+        let mut tables = Vec::new();
+
+        match step {
+            SqlMigrationStep::AlterSequence(_, _)
+            | SqlMigrationStep::CreateSchema(_)
+            | SqlMigrationStep::DropView(_)
+            | SqlMigrationStep::DropUserDefinedType(_)
+            | SqlMigrationStep::CreateEnum(_)
+            | SqlMigrationStep::DropEnum(_)
+            | SqlMigrationStep::CreateExtension(_)
+            | SqlMigrationStep::AlterExtension(_)
+            | SqlMigrationStep::DropExtension(_)
+            | SqlMigrationStep::AlterProcedure(_) => (),
+            SqlMigrationStep::AlterEnum(alter_enum) => {
+                for (previous_column_id, next_column_id) in &alter_enum.previous_usages_as_default {
+                    tables.push(self.schemas().previous.walk(*previous_column_id).table().name());
+
+                    if let Some(next_column_id) = next_column_id {
+                        tables.push(self.schemas().next.walk(*next_column_id).table().name());
+                    }
+                }
+            }
+            SqlMigrationStep::DropForeignKey { foreign_key_id } => {
+                tables.push(self.schemas().previous.walk(*foreign_key_id).table().name());
+            }
+            SqlMigrationStep::AlterPrimaryKey(table_id) => {
+                tables.push(self.before.walk(table_id.previous).name());
+            }
+            SqlMigrationStep::DropIndex { index_id } => {
+                tables.push(self.schemas().previous.walk(*index_id).table().name());
+            }
+            SqlMigrationStep::AlterTable(alter_table) => {
+                let table_names = self.schemas().walk(alter_table.table_ids);
+                tables.push(table_names.previous.name());
+                tables.push(table_names.next.name());
+            }
+            SqlMigrationStep::DropTable { table_id } => {
+                tables.push(self.schemas().previous.walk(*table_id).name());
+            }
+            SqlMigrationStep::CreateTable { table_id } => {
+                tables.push(self.schemas().next.walk(*table_id).name());
+            }
+            SqlMigrationStep::RedefineTables(redefines) => {
+                for redefine in redefines {
+                    let table_names = self.schemas().walk(redefine.table_ids);
+                    tables.push(table_names.previous.name());
+                    tables.push(table_names.next.name());
+                }
+            }
+            SqlMigrationStep::RenameForeignKey { foreign_key_id } => {
+                tables.push(self.schemas().walk(*foreign_key_id).next.table().name());
+            }
+            SqlMigrationStep::CreateIndex {
+                table_id: (_, table_id),
+                ..
+            } => {
+                tables.push(self.schemas().next.walk(*table_id).name());
+            }
+            SqlMigrationStep::AddForeignKey { foreign_key_id } => {
+                tables.push(self.schemas().next.walk(*foreign_key_id).table().name());
+            }
+            SqlMigrationStep::RenameIndex { index } | SqlMigrationStep::RedefineIndex { index } => {
+                tables.push(self.schemas().walk(*index).previous.table().name());
+            }
+        }
+
+        tables
     }
 
     /// Exposed for tests.
@@ -55,6 +152,7 @@ impl SqlMigration {
             RedefinedTable,
             ChangedEnum,
             ChangedTable,
+            ChangedProcedure,
         }
 
         // (sort key, item name, step index)
@@ -176,6 +274,9 @@ impl SqlMigration {
 
                     drift_items.insert((DriftType::DroppedExtension, &extension.name, idx));
                 }
+                SqlMigrationStep::AlterProcedure(alter_procedure) => {
+                    drift_items.insert((DriftType::ChangedProcedure, &alter_procedure.name, idx));
+                }
             };
         }
 
@@ -221,6 +322,11 @@ impl SqlMigration {
                     DriftType::DroppedExtension => {
                         out.push_str("\n[-] Removed extensions\n`");
                     }
+                    DriftType::ChangedProcedure => {
+                        out.push_str("\n[*] Changed the `");
+                        out.push_str(item_name);
+                        out.push_str("` procedure\n");
+                    }
                 }
             }
 
@@ -331,6 +437,11 @@ impl SqlMigration {
                                 out.push_str(")\n");
                                 out.push_str(")\n");
                             }
+                            TableChange::AlterReplicaIdentity => {
+                                out.push_str("  [*] Changed the replica identity of `");
+                                out.push_str(tables.next.name());
+                                out.push_str("`\n");
+                            }
                         }
                     }
                 }
@@ -362,6 +473,7 @@ impl SqlMigration {
                     table_id: _,
                     index_id,
                     from_drop_and_recreate: _,
+                    concurrently: _,
                 } => {
                     let index = self.schemas().next.walk(*index_id);
 
@@ -405,11 +517,277 @@ impl SqlMigration {
                 }
                 SqlMigrationStep::AlterExtension(_) => {}
                 SqlMigrationStep::DropExtension(_) => {}
+                SqlMigrationStep::AlterProcedure(alter_procedure) => {
+                    out.push_str("  - ");
+                    out.push_str(&alter_procedure.name);
+                    out.push('\n');
+                }
             }
         }
 
         out
     }
+
+    /// Group the migration's steps by the table (or other top-level object) they affect, for
+    /// CLIs that want to render an indented, reviewable migration plan instead of the flat
+    /// [`SqlMigration::drift_summary`]. Each leaf is tagged as destructive or not, and a group is
+    /// considered destructive as soon as one of its children is.
+    pub fn plan_tree(&self) -> PlanNode {
+        let mut groups: BTreeMap<String, Vec<PlanNode>> = BTreeMap::new();
+
+        for step in &self.steps {
+            let (group, label, destructive) = self.plan_entry(step);
+            groups.entry(group).or_default().push(PlanNode::leaf(label, destructive));
+        }
+
+        let children = groups
+            .into_iter()
+            .map(|(group, steps)| PlanNode::group(group, steps))
+            .collect();
+
+        PlanNode::group("migration", children)
+    }
+
+    /// Split this migration into an "expand" phase (additive, safe to apply before a rolling
+    /// deploy) and a "contract" phase (destructive, only safe once every instance is running the
+    /// new code), for zero-downtime deploys. Builds on the same step classification as
+    /// [`Self::plan_tree`]: a step goes to the contract phase as soon as it is destructive, and to
+    /// the expand phase otherwise. An [`SqlMigrationStep::AlterTable`] step that mixes additive and
+    /// destructive [`TableChange`]s (e.g. adding one column and dropping another) is itself split
+    /// column-change by column-change, so the additive part still ships in the expand phase.
+    ///
+    /// Both returned migrations keep the original `before`/`after` schemas: they are only used to
+    /// resolve names for `drift_summary`/`plan_tree`, and the step ids remain valid against them.
+    pub fn split_expand_contract(self) -> (SqlMigration, SqlMigration) {
+        let mut expand_steps = Vec::new();
+        let mut contract_steps = Vec::new();
+
+        for step in self.steps {
+            match step {
+                SqlMigrationStep::AlterTable(alter_table) => {
+                    let (destructive_changes, additive_changes): (Vec<_>, Vec<_>) = alter_table
+                        .changes
+                        .into_iter()
+                        .partition(table_change_is_destructive);
+
+                    if !additive_changes.is_empty() {
+                        expand_steps.push(SqlMigrationStep::AlterTable(AlterTable {
+                            table_ids: alter_table.table_ids,
+                            changes: additive_changes,
+                        }));
+                    }
+
+                    if !destructive_changes.is_empty() {
+                        contract_steps.push(SqlMigrationStep::AlterTable(AlterTable {
+                            table_ids: alter_table.table_ids,
+                            changes: destructive_changes,
+                        }));
+                    }
+                }
+                other if step_is_destructive(&other) => contract_steps.push(other),
+                other => expand_steps.push(other),
+            }
+        }
+
+        let expand = SqlMigration {
+            before: self.before.clone(),
+            after: self.after.clone(),
+            steps: expand_steps,
+        };
+        let contract = SqlMigration {
+            before: self.before,
+            after: self.after,
+            steps: contract_steps,
+        };
+
+        (expand, contract)
+    }
+
+    /// Compute the (group name, step label, is destructive) triple for a single step, used by
+    /// [`Self::plan_tree`].
+    fn plan_entry(&self, step: &SqlMigrationStep) -> (String, String, bool) {
+        let (group, label) = self.plan_group_and_label(step);
+        (group, label, step_is_destructive(step))
+    }
+
+    /// Compute the (group name, step label) pair for a single step, used by [`Self::plan_entry`].
+    fn plan_group_and_label(&self, step: &SqlMigrationStep) -> (String, String) {
+        match step {
+            SqlMigrationStep::AlterSequence(_, _) => ("sequences".to_owned(), "Altered a sequence".to_owned()),
+            SqlMigrationStep::CreateSchema(namespace_id) => (
+                "schemas".to_owned(),
+                format!("Created schema `{}`", self.schemas().next.walk(*namespace_id).name()),
+            ),
+            SqlMigrationStep::DropView(drop_view) => {
+                let name = self.schemas().previous.walk(drop_view.view_id).name();
+                (name.to_owned(), format!("Dropped view `{name}`"))
+            }
+            SqlMigrationStep::DropUserDefinedType(drop_udt) => {
+                let name = self.schemas().previous.walk(drop_udt.udt_id).name();
+                (name.to_owned(), format!("Dropped user defined type `{name}`"))
+            }
+            SqlMigrationStep::CreateEnum(enum_id) => {
+                let name = self.schemas().next.walk(*enum_id).name();
+                (name.to_owned(), format!("Created enum `{name}`"))
+            }
+            SqlMigrationStep::AlterEnum(alter_enum) => {
+                let name = self.schemas().walk(alter_enum.id).previous.name();
+                (name.to_owned(), format!("Altered enum `{name}`"))
+            }
+            SqlMigrationStep::DropForeignKey { foreign_key_id } => {
+                let table = self.schemas().previous.walk(*foreign_key_id).table();
+                (table.name().to_owned(), "Dropped a foreign key".to_owned())
+            }
+            SqlMigrationStep::AlterPrimaryKey(table_id) => {
+                let name = self.before.walk(table_id.previous).name();
+                (name.to_owned(), "Altered the primary key".to_owned())
+            }
+            SqlMigrationStep::DropIndex { index_id } => {
+                let table = self.schemas().previous.walk(*index_id).table();
+                (table.name().to_owned(), "Dropped an index".to_owned())
+            }
+            SqlMigrationStep::AlterTable(alter_table) => {
+                let table = self.schemas().walk(alter_table.table_ids).previous.name();
+                (table.to_owned(), "Altered the table".to_owned())
+            }
+            SqlMigrationStep::DropTable { table_id } => {
+                let name = self.schemas().previous.walk(*table_id).name();
+                (name.to_owned(), format!("Dropped table `{name}`"))
+            }
+            SqlMigrationStep::DropEnum(enum_id) => {
+                let name = self.schemas().previous.walk(*enum_id).name();
+                (name.to_owned(), format!("Dropped enum `{name}`"))
+            }
+            SqlMigrationStep::CreateTable { table_id } => {
+                let name = self.schemas().next.walk(*table_id).name();
+                (name.to_owned(), format!("Created table `{name}`"))
+            }
+            SqlMigrationStep::RedefineTables(redefines) => {
+                let names: Vec<&str> = redefines
+                    .iter()
+                    .map(|redefine| self.schemas().walk(redefine.table_ids).previous.name())
+                    .collect();
+                (names.join(", "), "Redefined the table".to_owned())
+            }
+            SqlMigrationStep::RenameForeignKey { foreign_key_id } => {
+                let table = self.schemas().walk(*foreign_key_id).next.table();
+                (table.name().to_owned(), "Renamed a foreign key".to_owned())
+            }
+            SqlMigrationStep::CreateIndex {
+                table_id: (_, table_id),
+                ..
+            } => {
+                let name = self.schemas().next.walk(*table_id).name();
+                (name.to_owned(), "Created an index".to_owned())
+            }
+            SqlMigrationStep::AddForeignKey { foreign_key_id } => {
+                let table = self.schemas().next.walk(*foreign_key_id).table();
+                (table.name().to_owned(), "Added a foreign key".to_owned())
+            }
+            SqlMigrationStep::RenameIndex { index } => {
+                let table = self.schemas().walk(*index).previous.table();
+                (table.name().to_owned(), "Renamed an index".to_owned())
+            }
+            SqlMigrationStep::RedefineIndex { index } => {
+                let table = self.schemas().walk(*index).previous.table();
+                (table.name().to_owned(), "Redefined an index".to_owned())
+            }
+            SqlMigrationStep::CreateExtension(create_extension) => {
+                let ext: &PostgresSchemaExt = self.schemas().next.downcast_connector_data();
+                let name = &ext.get_extension(create_extension.id).name;
+                ("extensions".to_owned(), format!("Created extension `{name}`"))
+            }
+            SqlMigrationStep::AlterExtension(alter_extension) => {
+                let ext: &PostgresSchemaExt = self.schemas().previous.downcast_connector_data();
+                let name = &ext.get_extension(alter_extension.ids.previous).name;
+                ("extensions".to_owned(), format!("Altered extension `{name}`"))
+            }
+            SqlMigrationStep::DropExtension(drop_extension) => {
+                let ext: &PostgresSchemaExt = self.schemas().previous.downcast_connector_data();
+                let name = &ext.get_extension(drop_extension.id).name;
+                ("extensions".to_owned(), format!("Dropped extension `{name}`"))
+            }
+            SqlMigrationStep::AlterProcedure(alter_procedure) => (
+                "procedures".to_owned(),
+                format!("Altered procedure `{}`", alter_procedure.name),
+            ),
+        }
+    }
+}
+
+/// Whether a step is destructive, i.e. can lose data or fail if applied while old code that
+/// depends on the dropped shape is still running. Shared between [`SqlMigration::plan_entry`] and
+/// [`SqlMigration::split_expand_contract`].
+fn step_is_destructive(step: &SqlMigrationStep) -> bool {
+    match step {
+        SqlMigrationStep::AlterSequence(_, _)
+        | SqlMigrationStep::CreateSchema(_)
+        | SqlMigrationStep::CreateEnum(_)
+        | SqlMigrationStep::DropIndex { .. }
+        | SqlMigrationStep::CreateTable { .. }
+        | SqlMigrationStep::RenameForeignKey { .. }
+        | SqlMigrationStep::CreateIndex { .. }
+        | SqlMigrationStep::AddForeignKey { .. }
+        | SqlMigrationStep::RenameIndex { .. }
+        | SqlMigrationStep::RedefineIndex { .. }
+        | SqlMigrationStep::CreateExtension(_)
+        | SqlMigrationStep::AlterExtension(_)
+        | SqlMigrationStep::AlterProcedure(_) => false,
+        SqlMigrationStep::DropView(_)
+        | SqlMigrationStep::DropUserDefinedType(_)
+        | SqlMigrationStep::DropForeignKey { .. }
+        | SqlMigrationStep::AlterPrimaryKey(_)
+        | SqlMigrationStep::DropTable { .. }
+        | SqlMigrationStep::DropEnum(_)
+        | SqlMigrationStep::DropExtension(_) => true,
+        SqlMigrationStep::AlterEnum(alter_enum) => !alter_enum.dropped_variants.is_empty(),
+        SqlMigrationStep::AlterTable(alter_table) => alter_table.changes.iter().any(table_change_is_destructive),
+        SqlMigrationStep::RedefineTables(redefines) => redefines
+            .iter()
+            .any(|redefine| redefine.dropped_primary_key || !redefine.dropped_columns.is_empty()),
+    }
+}
+
+/// Whether a single [`TableChange`] is destructive. See [`step_is_destructive`].
+fn table_change_is_destructive(change: &TableChange) -> bool {
+    matches!(
+        change,
+        TableChange::DropColumn { .. } | TableChange::DropAndRecreateColumn { .. } | TableChange::DropPrimaryKey
+    )
+}
+
+/// A single node in the tree returned by [`SqlMigration::plan_tree`], suitable for indented
+/// display in a CLI. A group node (e.g. a table) is marked destructive as soon as one of its
+/// children is.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PlanNode {
+    /// A human-readable label for this node: a table/enum/extension name for a group, or a
+    /// description of the change for a leaf.
+    pub label: String,
+    /// Whether this node, or one of its descendants, represents a destructive change.
+    pub destructive: bool,
+    /// The child nodes. Empty for leaves.
+    pub children: Vec<PlanNode>,
+}
+
+impl PlanNode {
+    fn leaf(label: String, destructive: bool) -> Self {
+        PlanNode {
+            label,
+            destructive,
+            children: Vec::new(),
+        }
+    }
+
+    fn group(label: String, children: Vec<PlanNode>) -> Self {
+        let destructive = children.iter().any(|child| child.destructive);
+
+        PlanNode {
+            label,
+            destructive,
+            children,
+        }
+    }
 }
 
 fn render_column_changes(columns: MigrationPair<TableColumnWalker<'_>>, changes: &ColumnChanges, sink: &mut String) {
@@ -434,6 +812,9 @@ fn render_column_changes(columns: MigrationPair<TableColumnWalker<'_>>, changes:
                     "column became autoincrementing".to_owned()
                 }
             }
+            ColumnChange::Ordering => "column order changed".to_owned(),
+            ColumnChange::Collation => "collation changed".to_owned(),
+            ColumnChange::Storage => "storage changed".to_owned(),
         })
         .join(", ");
 
@@ -488,6 +869,9 @@ pub(crate) enum SqlMigrationStep {
         table_id: (Option<TableId>, TableId),
         index_id: IndexId,
         from_drop_and_recreate: bool,
+        /// Whether the index should be created without taking a write lock on the table
+        /// (`CREATE INDEX CONCURRENTLY` on Postgres). Ignored by connectors that do not support it.
+        concurrently: bool,
     },
     RenameForeignKey {
         foreign_key_id: MigrationPair<ForeignKeyId>,
@@ -503,6 +887,9 @@ pub(crate) enum SqlMigrationStep {
     RedefineIndex {
         index: MigrationPair<IndexId>,
     },
+    // Order matters: this must come last, since procedures can reference tables, columns and
+    // types created or altered by earlier steps.
+    AlterProcedure(AlterProcedure),
 }
 
 impl SqlMigrationStep {
@@ -580,6 +967,9 @@ pub(crate) enum TableChange {
     DropPrimaryKey,
     AddPrimaryKey,
     RenamePrimaryKey,
+    /// The table's `REPLICA IDENTITY` setting changed. Only produced on Postgres; the new value
+    /// is read from `PostgresSchemaExt` at render time rather than carried on the step.
+    AlterReplicaIdentity,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -630,6 +1020,15 @@ pub(crate) struct AlterEnum {
     pub previous_usages_as_default: Vec<(TableColumnId, Option<TableColumnId>)>,
 }
 
+/// A stored procedure or function whose definition changed between the two schemas being
+/// diffed. Procedures have no stable id (see [`sql_schema_describer::Procedure`]), so unlike
+/// [`AlterEnum`] this carries the name rather than an id pair; the renderer looks the current
+/// definition back up on the next schema by that name.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct AlterProcedure {
+    pub name: String,
+}
+
 impl AlterEnum {
     pub(crate) fn is_empty(&self) -> bool {
         self.created_variants.is_empty() && self.dropped_variants.is_empty()
@@ -670,6 +1069,204 @@ pub(crate) enum SequenceChange {
     Start = 1 << 2,
     Cache = 1 << 3,
     Increment = 1 << 4,
+    OwnedBy = 1 << 5,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migration_pair::MigrationPair;
+
+    #[test]
+    fn affected_tables_for_mixed_migration() {
+        let mut before = SqlSchema::default();
+        let before_ns = before.push_namespace("default".to_owned());
+        let cats_id = before.push_table("Cat".to_owned(), before_ns, None);
+        before.push_table("Dog".to_owned(), before_ns, None);
+
+        let mut after = SqlSchema::default();
+        let after_ns = after.push_namespace("default".to_owned());
+        let cats_id_after = after.push_table("Cat".to_owned(), after_ns, None);
+        let column_id = after.push_table_column(
+            cats_id_after,
+            sql_schema_describer::Column {
+                name: "nickname".to_owned(),
+                tpe: sql_schema_describer::ColumnType::pure(
+                    sql_schema_describer::ColumnTypeFamily::String,
+                    sql_schema_describer::ColumnArity::Nullable,
+                ),
+                auto_increment: false,
+                description: None,
+            },
+        );
+        after.push_table("Bird".to_owned(), after_ns, None);
+
+        let steps = vec![
+            SqlMigrationStep::DropTable { table_id: cats_id },
+            SqlMigrationStep::CreateTable { table_id: cats_id_after },
+            SqlMigrationStep::AlterTable(AlterTable {
+                table_ids: MigrationPair::new(cats_id, cats_id_after),
+                changes: vec![TableChange::AddColumn {
+                    column_id,
+                    has_virtual_default: false,
+                }],
+            }),
+        ];
+
+        let migration = SqlMigration {
+            before: Arc::new(before),
+            after: Arc::new(after),
+            steps,
+        };
+
+        assert_eq!(migration.affected_tables(), vec!["Cat".to_owned()]);
+    }
+
+    #[test]
+    fn steps_for_table_only_returns_the_requested_tables_steps() {
+        let mut before = SqlSchema::default();
+        let before_ns = before.push_namespace("default".to_owned());
+        let cats_id = before.push_table("Cat".to_owned(), before_ns, None);
+        let dogs_id = before.push_table("Dog".to_owned(), before_ns, None);
+
+        let mut after = SqlSchema::default();
+        let after_ns = after.push_namespace("default".to_owned());
+        let cats_id_after = after.push_table("Cat".to_owned(), after_ns, None);
+
+        let drop_cat = SqlMigrationStep::DropTable { table_id: cats_id };
+        let create_cat = SqlMigrationStep::CreateTable { table_id: cats_id_after };
+        let drop_dog = SqlMigrationStep::DropTable { table_id: dogs_id };
+
+        let migration = SqlMigration {
+            before: Arc::new(before),
+            after: Arc::new(after),
+            steps: vec![drop_cat, create_cat, drop_dog],
+        };
+
+        let cat_steps = migration.steps_for_table("Cat");
+
+        assert_eq!(cat_steps.len(), 2);
+        assert!(matches!(cat_steps[0], SqlMigrationStep::DropTable { table_id } if *table_id == cats_id));
+        assert!(matches!(cat_steps[1], SqlMigrationStep::CreateTable { table_id } if *table_id == cats_id_after));
+    }
+
+    #[test]
+    fn plan_tree_groups_steps_by_table() {
+        let mut before = SqlSchema::default();
+        let before_ns = before.push_namespace("default".to_owned());
+        let dog_id = before.push_table("Dog".to_owned(), before_ns, None);
+        let cats_id = before.push_table("Cat".to_owned(), before_ns, None);
+
+        let mut after = SqlSchema::default();
+        let after_ns = after.push_namespace("default".to_owned());
+        let cats_id_after = after.push_table("Cat".to_owned(), after_ns, None);
+        let bird_id = after.push_table("Bird".to_owned(), after_ns, None);
+
+        let steps = vec![
+            SqlMigrationStep::DropTable { table_id: dog_id },
+            SqlMigrationStep::CreateTable { table_id: bird_id },
+            SqlMigrationStep::AlterTable(AlterTable {
+                table_ids: MigrationPair::new(cats_id, cats_id_after),
+                changes: vec![TableChange::AddPrimaryKey],
+            }),
+        ];
+
+        let migration = SqlMigration {
+            before: Arc::new(before),
+            after: Arc::new(after),
+            steps,
+        };
+        let plan = migration.plan_tree();
+
+        let group_labels: Vec<&str> = plan.children.iter().map(|child| child.label.as_str()).collect();
+        assert_eq!(group_labels, vec!["Bird", "Cat", "Dog"]);
+
+        let bird = &plan.children[0];
+        assert!(!bird.destructive);
+        assert_eq!(bird.children.len(), 1);
+
+        let cat = &plan.children[1];
+        assert!(!cat.destructive);
+        assert_eq!(cat.children.len(), 1);
+
+        let dog = &plan.children[2];
+        assert!(dog.destructive);
+        assert_eq!(dog.children.len(), 1);
+
+        assert!(plan.destructive);
+    }
+
+    #[test]
+    fn split_expand_contract_separates_an_added_column_from_a_dropped_column() {
+        let mut before = SqlSchema::default();
+        let before_ns = before.push_namespace("default".to_owned());
+        let cats_id = before.push_table("Cat".to_owned(), before_ns, None);
+        let nickname_id = before.push_table_column(
+            cats_id,
+            sql_schema_describer::Column {
+                name: "nickname".to_owned(),
+                tpe: sql_schema_describer::ColumnType::pure(
+                    sql_schema_describer::ColumnTypeFamily::String,
+                    sql_schema_describer::ColumnArity::Nullable,
+                ),
+                auto_increment: false,
+                description: None,
+            },
+        );
+
+        let mut after = SqlSchema::default();
+        let after_ns = after.push_namespace("default".to_owned());
+        let cats_id_after = after.push_table("Cat".to_owned(), after_ns, None);
+        let age_id = after.push_table_column(
+            cats_id_after,
+            sql_schema_describer::Column {
+                name: "age".to_owned(),
+                tpe: sql_schema_describer::ColumnType::pure(
+                    sql_schema_describer::ColumnTypeFamily::Int,
+                    sql_schema_describer::ColumnArity::Nullable,
+                ),
+                auto_increment: false,
+                description: None,
+            },
+        );
+
+        let steps = vec![SqlMigrationStep::AlterTable(AlterTable {
+            table_ids: MigrationPair::new(cats_id, cats_id_after),
+            changes: vec![
+                TableChange::AddColumn {
+                    column_id: age_id,
+                    has_virtual_default: false,
+                },
+                TableChange::DropColumn { column_id: nickname_id },
+            ],
+        })];
+
+        let migration = SqlMigration {
+            before: Arc::new(before),
+            after: Arc::new(after),
+            steps,
+        };
+
+        let (expand, contract) = migration.split_expand_contract();
+
+        let added_column = TableChange::AddColumn {
+            column_id: age_id,
+            has_virtual_default: false,
+        };
+
+        assert_eq!(expand.steps.len(), 1);
+        assert!(matches!(
+            &expand.steps[0],
+            SqlMigrationStep::AlterTable(alter_table) if alter_table.changes == vec![added_column]
+        ));
+
+        assert_eq!(contract.steps.len(), 1);
+        assert!(matches!(
+            &contract.steps[0],
+            SqlMigrationStep::AlterTable(alter_table)
+                if alter_table.changes == vec![TableChange::DropColumn { column_id: nickname_id }]
+        ));
+    }
 }
 
 fn render_primary_key_column_names(table: TableWalker<'_>, out: &mut String) {
@@ -0,0 +1,112 @@
+//! Best-effort inference of whether a user-provided `introspect_sql` query can return at most one
+//! row, so typed-SQL codegen can return a single value instead of a list.
+
+use sqlparser::ast::{Expr, Query, SelectItem, SetExpr, Statement, Value};
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+
+/// The aggregate functions that collapse a whole (unfiltered by `GROUP BY`) result set into one
+/// row.
+const AGGREGATE_FUNCTION_NAMES: &[&str] = &[
+    "count",
+    "sum",
+    "avg",
+    "min",
+    "max",
+    "array_agg",
+    "string_agg",
+    "group_concat",
+    "bool_and",
+    "bool_or",
+    "every",
+];
+
+/// Infer, on a best-effort basis, whether `sql` returns at most one row: either because it has a
+/// `LIMIT 1` clause, or because its projection consists solely of aggregate functions with no
+/// `GROUP BY` (so the whole result set collapses into a single row).
+///
+/// This is a syntactic heuristic, not a guarantee: it can miss cases (e.g. a `WHERE` clause that
+/// pins down a primary key), and it does not attempt to prove anything about queries it cannot
+/// parse, returning `false` for those instead.
+pub(crate) fn returns_at_most_one_row(sql: &str) -> bool {
+    let dialect = GenericDialect {};
+
+    let statement = match Parser::new(&dialect).try_with_sql(sql).and_then(|mut p| p.parse_statement()) {
+        Ok(statement) => statement,
+        Err(_) => return false,
+    };
+
+    let query = match statement {
+        Statement::Query(query) => query,
+        _ => return false,
+    };
+
+    has_limit_one(&query) || is_ungrouped_aggregate(&query)
+}
+
+fn has_limit_one(query: &Query) -> bool {
+    matches!(
+        &query.limit,
+        Some(Expr::Value(Value::Number(n, _))) if n == "1"
+    )
+}
+
+fn is_ungrouped_aggregate(query: &Query) -> bool {
+    let select = match query.body.as_ref() {
+        SetExpr::Select(select) => select,
+        _ => return false,
+    };
+
+    if !select.group_by.is_empty() || select.projection.is_empty() {
+        return false;
+    }
+
+    select.projection.iter().all(|item| match item {
+        SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => is_aggregate_call(expr),
+        _ => false,
+    })
+}
+
+fn is_aggregate_call(expr: &Expr) -> bool {
+    match expr {
+        Expr::Function(function) => function
+            .name
+            .0
+            .last()
+            .map(|ident| AGGREGATE_FUNCTION_NAMES.contains(&ident.value.to_ascii_lowercase().as_str()))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::returns_at_most_one_row;
+
+    #[test]
+    fn limit_1_is_a_single_row_query() {
+        assert!(returns_at_most_one_row("SELECT id FROM users LIMIT 1"));
+    }
+
+    #[test]
+    fn limit_2_is_not_a_single_row_query() {
+        assert!(!returns_at_most_one_row("SELECT id FROM users LIMIT 2"));
+    }
+
+    #[test]
+    fn bare_aggregate_is_a_single_row_query() {
+        assert!(returns_at_most_one_row("SELECT COUNT(*) FROM users"));
+    }
+
+    #[test]
+    fn aggregate_with_group_by_is_not_a_single_row_query() {
+        assert!(!returns_at_most_one_row(
+            "SELECT COUNT(*) FROM users GROUP BY organization_id"
+        ));
+    }
+
+    #[test]
+    fn plain_select_is_not_a_single_row_query() {
+        assert!(!returns_at_most_one_row("SELECT id, name FROM users"));
+    }
+}
@@ -28,7 +28,8 @@ use self::common::{Quoted, QuotedWithPrefix};
 use crate::{
     migration_pair::MigrationPair,
     sql_migration::{
-        AlterEnum, AlterExtension, AlterTable, CreateExtension, DropExtension, RedefineTable, SequenceChanges,
+        AlterEnum, AlterExtension, AlterProcedure, AlterTable, CreateExtension, DropExtension, RedefineTable,
+        SequenceChanges,
     },
 };
 use sql_schema_describer::{
@@ -40,10 +41,29 @@ use sql_schema_describer::{
 pub(crate) trait SqlRenderer {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
+    /// Quote a schema-qualified name, e.g. `"schema"."table"`, or just `"table"` if there is no
+    /// namespace. A connector-agnostic helper so callers don't have to reimplement this quoting
+    /// themselves.
+    fn quote_with_schema<'a>(&self, namespace: Option<&'a str>, name: &'a str) -> QuotedWithPrefix<&'a str> {
+        QuotedWithPrefix(namespace.map(|namespace| self.quote(namespace)), self.quote(name))
+    }
+
     fn render_add_foreign_key(&self, foreign_key: ForeignKeyWalker<'_>) -> String;
 
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: MigrationPair<&SqlSchema>) -> Vec<String>;
 
+    /// Render an `AlterProcedure` step, replacing a stored procedure/function whose body
+    /// changed. Only meaningful on connectors that describe procedures and opt into diffing
+    /// them (see `SqlSchemaDifferFlavour::push_procedure_steps`); other connectors never produce
+    /// this step, so the default is unreachable, like `render_alter_sequence`.
+    fn render_alter_procedure(
+        &self,
+        _alter_procedure: &AlterProcedure,
+        _schemas: MigrationPair<&SqlSchema>,
+    ) -> Vec<String> {
+        unreachable!("unreachable render_alter_procedure")
+    }
+
     fn render_alter_primary_key(&self, _tables: MigrationPair<TableWalker<'_>>) -> Vec<String> {
         unreachable!("unreachable render_alter_primary_key()")
     }
@@ -66,7 +86,7 @@ pub(crate) trait SqlRenderer {
     /// Render a `CreateEnum` step.
     fn render_create_enum(&self, create_enum: EnumWalker<'_>) -> Vec<String>;
 
-    fn render_create_index(&self, index: IndexWalker<'_>) -> String;
+    fn render_create_index(&self, index: IndexWalker<'_>, concurrently: bool) -> String;
 
     /// Render a table creation step.
     fn render_create_table(&self, table: TableWalker<'_>) -> String;
@@ -89,11 +109,7 @@ pub(crate) trait SqlRenderer {
 
     /// Render a `DropTable` step.
     fn render_drop_table(&self, namespace: Option<&str>, table_name: &str) -> Vec<String> {
-        let name = match namespace {
-            Some(namespace) => format!("{}.{}", self.quote(namespace), self.quote(table_name)),
-            None => format!("{}", self.quote(table_name)),
-        };
-        vec![format!("DROP TABLE {name}")]
+        vec![format!("DROP TABLE {}", self.quote_with_schema(namespace, table_name))]
     }
 
     /// Render a `RedefineTables` step.
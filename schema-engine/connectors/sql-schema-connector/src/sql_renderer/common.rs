@@ -190,3 +190,32 @@ pub(super) fn render_step(f: &mut dyn FnMut(&mut StepRenderer)) -> Vec<String> {
     f(&mut renderer);
     renderer.stmts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_with_prefix_renders_dotted_qualified_name_per_connector() {
+        let postgres = QuotedWithPrefix(
+            Some(Quoted::postgres_ident("my.schema")),
+            Quoted::postgres_ident("my.table"),
+        );
+        assert_eq!(postgres.to_string(), r#""my.schema"."my.table""#);
+
+        let mysql = QuotedWithPrefix(Some(Quoted::mysql_ident("my.schema")), Quoted::mysql_ident("my.table"));
+        assert_eq!(mysql.to_string(), "`my.schema`.`my.table`");
+
+        let sqlite = QuotedWithPrefix(Some(Quoted::sqlite_ident("main")), Quoted::sqlite_ident("my.table"));
+        assert_eq!(sqlite.to_string(), r#""main"."my.table""#);
+
+        let mssql = QuotedWithPrefix(Some(Quoted::mssql_ident("dbo")), Quoted::mssql_ident("my.table"));
+        assert_eq!(mssql.to_string(), "[dbo].[my.table]");
+    }
+
+    #[test]
+    fn quoted_with_prefix_omits_the_prefix_when_there_is_no_namespace() {
+        let unqualified = QuotedWithPrefix(None::<Quoted<&str>>, Quoted::postgres_ident("table"));
+        assert_eq!(unqualified.to_string(), r#""table""#);
+    }
+}
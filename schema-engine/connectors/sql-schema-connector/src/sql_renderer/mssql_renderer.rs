@@ -112,7 +112,7 @@ impl SqlRenderer for MssqlFlavour {
         unreachable!("render_create_enum on Microsoft SQL Server")
     }
 
-    fn render_create_index(&self, index: sql::IndexWalker<'_>) -> String {
+    fn render_create_index(&self, index: sql::IndexWalker<'_>, _concurrently: bool) -> String {
         let mssql_schema_ext: &MssqlSchemaExt = index.schema.downcast_connector_data();
         let index_name = Quoted::mssql_ident(index.name());
         let table_reference = self.table_name(index.table());
@@ -145,7 +145,7 @@ impl SqlRenderer for MssqlFlavour {
             sql::IndexType::Normal => {
                 format!("CREATE {clustering}INDEX {index_name} ON {table_reference}({columns})",)
             }
-            sql::IndexType::Fulltext | sql::IndexType::PrimaryKey => unreachable!(),
+            sql::IndexType::Fulltext | sql::IndexType::Spatial | sql::IndexType::PrimaryKey => unreachable!(),
         }
     }
 
@@ -347,7 +347,7 @@ impl SqlRenderer for MssqlFlavour {
 
             // Recreate the indexes.
             for index in tables.next.indexes().filter(|i| !i.is_unique() && !i.is_primary_key()) {
-                result.push(self.render_create_index(index));
+                result.push(self.render_create_index(index, false));
             }
         }
 
@@ -538,6 +538,6 @@ fn render_default(default: &sql::DefaultValue) -> Cow<'_, str> {
         sql::DefaultKind::Value(PrismaValue::DateTime(val)) => Quoted::mssql_string(val).to_string().into(),
         sql::DefaultKind::Value(PrismaValue::Boolean(val)) => Cow::from(if *val { "1" } else { "0" }),
         sql::DefaultKind::Value(val) => val.to_string().into(),
-        sql::DefaultKind::Sequence(_) | sql::DefaultKind::UniqueRowid => unreachable!(),
+        sql::DefaultKind::Sequence { .. } | sql::DefaultKind::UniqueRowid => unreachable!(),
     }
 }
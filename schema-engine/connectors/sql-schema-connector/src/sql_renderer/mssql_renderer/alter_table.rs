@@ -83,6 +83,7 @@ impl AlterTableConstructor<'_> {
                 }) => {
                     self.alter_column(*column_id, changes);
                 }
+                TableChange::AlterReplicaIdentity => unreachable!("AlterReplicaIdentity on SQL Server"),
             };
         }
 
@@ -10,6 +10,7 @@ use psl::builtin_connectors::MySqlType;
 use regex::Regex;
 use sql_ddl::{mysql as ddl, IndexColumn, SortOrder};
 use sql_schema_describer::{
+    mysql::MysqlSchemaExt,
     walkers::{
         EnumWalker, ForeignKeyWalker, IndexWalker, TableColumnWalker, TableWalker, UserDefinedTypeWalker, ViewWalker,
     },
@@ -22,7 +23,7 @@ impl MysqlFlavour {
         let default = col
             .default()
             .filter(|default| {
-                !matches!(default.kind(),  DefaultKind::Sequence(_) | DefaultKind::DbGenerated(None))
+                !matches!(default.kind(),  DefaultKind::Sequence { .. } | DefaultKind::DbGenerated(None))
                     // We do not want to render JSON defaults because
                     // they are not supported by MySQL.
                     && !matches!(col.column_type_family(), ColumnTypeFamily::Json)
@@ -32,10 +33,14 @@ impl MysqlFlavour {
             })
             .map(|default| render_default(col, default.inner()));
 
+        let mysql_ext = col.schema.downcast_connector_data::<MysqlSchemaExt>();
+
         ddl::Column {
             column_name: col.name().into(),
             not_null: col.arity().is_required(),
             column_type: render_column_type(col),
+            character_set: mysql_ext.column_character_set(col.id).map(Cow::Borrowed),
+            collation: mysql_ext.column_collation(col.id).map(Cow::Borrowed),
             default,
             auto_increment: col.is_autoincrement(),
             ..Default::default()
@@ -113,6 +118,7 @@ impl SqlRenderer for MysqlFlavour {
             match change {
                 TableChange::DropPrimaryKey => lines.push(sql_ddl::mysql::AlterTableClause::DropPrimaryKey.to_string()),
                 TableChange::RenamePrimaryKey => unreachable!("No Renaming Primary Keys on Mysql"),
+                TableChange::AlterReplicaIdentity => unreachable!("AlterReplicaIdentity on MySQL"),
                 TableChange::AddPrimaryKey => lines.push(format!(
                     "ADD PRIMARY KEY ({})",
                     tables
@@ -193,12 +199,13 @@ impl SqlRenderer for MysqlFlavour {
         )
     }
 
-    fn render_create_index(&self, index: IndexWalker<'_>) -> String {
+    fn render_create_index(&self, index: IndexWalker<'_>, _concurrently: bool) -> String {
         ddl::CreateIndex {
             r#type: match index.index_type() {
                 sql_schema_describer::IndexType::Unique => ddl::IndexType::Unique,
                 sql_schema_describer::IndexType::Normal => ddl::IndexType::Normal,
                 sql_schema_describer::IndexType::Fulltext => ddl::IndexType::Fulltext,
+                sql_schema_describer::IndexType::Spatial => ddl::IndexType::Spatial,
                 sql_schema_describer::IndexType::PrimaryKey => unreachable!(),
             },
             index_name: index.name().into(),
@@ -234,6 +241,7 @@ impl SqlRenderer for MysqlFlavour {
                         sql_schema_describer::IndexType::Unique => ddl::IndexType::Unique,
                         sql_schema_describer::IndexType::Normal => ddl::IndexType::Normal,
                         sql_schema_describer::IndexType::Fulltext => ddl::IndexType::Fulltext,
+                        sql_schema_describer::IndexType::Spatial => ddl::IndexType::Spatial,
                         sql_schema_describer::IndexType::PrimaryKey => unreachable!(),
                     },
                     columns: index
@@ -273,7 +281,7 @@ impl SqlRenderer for MysqlFlavour {
     fn render_drop_and_recreate_index(&self, indexes: MigrationPair<IndexWalker<'_>>) -> Vec<String> {
         // Order matters: dropping the old index first wouldn't work when foreign key constraints are still relying on it.
         vec![
-            self.render_create_index(indexes.next),
+            self.render_create_index(indexes.next, false),
             sql_ddl::mysql::DropIndex {
                 index_name: indexes.previous.name().into(),
                 table_name: indexes.previous.table().name().into(),
@@ -368,16 +376,38 @@ fn render_mysql_modify(
         .map(|expression| format!(" DEFAULT {expression}"))
         .unwrap_or_default();
 
+    let position = if changes.ordering_changed() {
+        match next_column.previous_sibling() {
+            Some(previous) => format!(" AFTER {}", Quoted::mysql_ident(previous.name())),
+            None => " FIRST".to_owned(),
+        }
+    } else {
+        String::new()
+    };
+
+    let mysql_ext = next_column.schema.downcast_connector_data::<MysqlSchemaExt>();
+    let charset = match mysql_ext.column_character_set(next_column.id) {
+        Some(character_set) => format!(" CHARACTER SET {character_set}"),
+        None => String::new(),
+    };
+    let collation = match mysql_ext.column_collation(next_column.id) {
+        Some(collation) => format!(" COLLATE {collation}"),
+        None => String::new(),
+    };
+
     format!(
-        "MODIFY {column_name} {column_type}{nullability}{default}{sequence}",
+        "MODIFY {column_name} {column_type}{charset}{collation}{nullability}{default}{sequence}{position}",
         column_name = Quoted::mysql_ident(&next_column.name()),
         column_type = column_type,
+        charset = charset,
+        collation = collation,
         nullability = if next_column.arity().is_required() {
             " NOT NULL"
         } else {
             " NULL"
         },
         default = default,
+        position = position,
         sequence = if next_column.is_autoincrement() {
             " AUTO_INCREMENT"
         } else {
@@ -515,6 +545,6 @@ fn render_default<'a>(column: TableColumnWalker<'a>, default: &'a DefaultValue)
             Quoted::mysql_string(dt.to_rfc3339()).to_string().into()
         }
         DefaultKind::Value(val) => val.to_string().into(),
-        DefaultKind::DbGenerated(None) | DefaultKind::Sequence(_) | DefaultKind::UniqueRowid => unreachable!(),
+        DefaultKind::DbGenerated(None) | DefaultKind::Sequence { .. } | DefaultKind::UniqueRowid => unreachable!(),
     }
 }
@@ -3,8 +3,8 @@ use crate::{
     flavour::PostgresFlavour,
     migration_pair::MigrationPair,
     sql_migration::{
-        AlterColumn, AlterEnum, AlterExtension, AlterTable, CreateExtension, DropExtension, ExtensionChange,
-        RedefineTable, SequenceChange, SequenceChanges, TableChange,
+        AlterColumn, AlterEnum, AlterExtension, AlterProcedure, AlterTable, CreateExtension, DropExtension,
+        ExtensionChange, RedefineTable, SequenceChange, SequenceChanges, TableChange,
     },
     sql_schema_differ::{ColumnChange, ColumnChanges},
 };
@@ -14,7 +14,7 @@ use sql_ddl::{
     IndexColumn, SortOrder,
 };
 use sql_schema_describer::{
-    postgres::{PostgresSchemaExt, SqlIndexAlgorithm},
+    postgres::{PostgresSchemaExt, ReplicaIdentity, SqlIndexAlgorithm},
     walkers::*,
     ColumnArity, ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, PrismaValue, SQLSortOrder, SqlSchema,
 };
@@ -33,8 +33,11 @@ impl PostgresFlavour {
             .unwrap_or_else(String::new);
 
         let identity_str = render_column_identity_str(column, self);
+        let storage_str = render_column_storage_str(column);
 
-        format!("{SQL_INDENTATION}{column_name} {tpe_str}{nullability_str}{default_str}{identity_str}",)
+        format!(
+            "{SQL_INDENTATION}{column_name} {tpe_str}{nullability_str}{default_str}{identity_str}{storage_str}",
+        )
     }
 }
 
@@ -80,6 +83,20 @@ impl SqlRenderer for PostgresFlavour {
                     stmt.push_str(" CACHE ");
                     stmt.push_display(&next_seq.cache_size);
                 }
+
+                if changes.0.contains(SequenceChange::OwnedBy) {
+                    stmt.push_str(" OWNED BY ");
+
+                    match next_seq.owned_by {
+                        Some(column_id) => {
+                            let column = schemas.next.walk(column_id);
+                            stmt.push_display(&QuotedWithPrefix::pg_from_table_walker(column.table()));
+                            stmt.push_str(".");
+                            stmt.push_display(&Quoted::postgres_ident(column.name()));
+                        }
+                        None => stmt.push_str("NONE"),
+                    }
+                }
             })
         })
     }
@@ -179,6 +196,25 @@ impl SqlRenderer for PostgresFlavour {
         .to_string()
     }
 
+    fn render_alter_procedure(
+        &self,
+        alter_procedure: &AlterProcedure,
+        schemas: MigrationPair<&SqlSchema>,
+    ) -> Vec<String> {
+        // `pg_get_functiondef` (see the postgres describer) already returns a full
+        // `CREATE OR REPLACE FUNCTION`/`PROCEDURE` statement, so there is nothing left to
+        // assemble here.
+        let procedure = schemas
+            .next
+            .get_procedure(&alter_procedure.name)
+            .expect("AlterProcedure step for a procedure missing from the next schema");
+
+        vec![procedure
+            .definition
+            .clone()
+            .expect("AlterProcedure step for a procedure without a definition")]
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: MigrationPair<&SqlSchema>) -> Vec<String> {
         // ALTER TYPE is much more limited on postgres than on cockroachdb.
         //
@@ -301,6 +337,13 @@ impl SqlRenderer for PostgresFlavour {
                     let col_sql = self.render_column(columns.next);
                     lines.push(format!("ADD COLUMN {col_sql}"));
                 }
+                TableChange::AlterReplicaIdentity => {
+                    let next_ext: &PostgresSchemaExt = schemas.next.downcast_connector_data();
+                    lines.push(format!(
+                        "REPLICA IDENTITY {}",
+                        render_replica_identity(next_ext.replica_identity(tables.next.id))
+                    ));
+                }
             };
         }
 
@@ -353,12 +396,13 @@ impl SqlRenderer for PostgresFlavour {
         })
     }
 
-    fn render_create_index(&self, index: IndexWalker<'_>) -> String {
+    fn render_create_index(&self, index: IndexWalker<'_>, concurrently: bool) -> String {
         let pg_ext: &PostgresSchemaExt = index.schema.downcast_connector_data();
 
         ddl::CreateIndex {
             index_name: index.name().into(),
             is_unique: index.is_unique(),
+            concurrently,
             table_reference: &QuotedWithPrefix::pg_from_table_walker(index.table()),
             using: Some(match pg_ext.index_algorithm(index.id) {
                 SqlIndexAlgorithm::BTree => ddl::IndexAlgorithm::BTree,
@@ -380,6 +424,7 @@ impl SqlRenderer for PostgresFlavour {
                     operator_class: pg_ext.get_opclass(c.id).map(|c| c.kind.as_ref().into()),
                 })
                 .collect(),
+            fillfactor: pg_ext.index_fillfactor(index.id),
         }
         .to_string()
     }
@@ -408,7 +453,9 @@ impl SqlRenderer for PostgresFlavour {
             String::new()
         };
 
-        format!("CREATE TABLE {table_name} (\n{columns}{pk}\n)")
+        let unlogged = if table.is_unlogged() { "UNLOGGED " } else { "" };
+
+        format!("CREATE {unlogged}TABLE {table_name} (\n{columns}{pk}\n)")
     }
 
     fn render_drop_enum(&self, dropped_enum: EnumWalker<'_>) -> Vec<String> {
@@ -497,7 +544,7 @@ impl SqlRenderer for PostgresFlavour {
             result.push(self.render_rename_table(tables.next.namespace(), &temporary_table_name, tables.next.name()));
 
             for index in tables.next.indexes().filter(|idx| !idx.is_primary_key()) {
-                result.push(self.render_create_index(index));
+                result.push(self.render_create_index(index, false));
             }
 
             for fk in tables.next.foreign_keys() {
@@ -587,6 +634,12 @@ fn render_column_type_postgres(col: TableColumnWalker<'_>) -> Cow<'static, str>
         PostgresType::Xml => "XML".into(),
         PostgresType::Json => "JSON".into(),
         PostgresType::JsonB => "JSONB".into(),
+        PostgresType::Int4Range => "INT4RANGE".into(),
+        PostgresType::Int8Range => "INT8RANGE".into(),
+        PostgresType::NumRange => "NUMRANGE".into(),
+        PostgresType::TsRange => "TSRANGE".into(),
+        PostgresType::TstzRange => "TSTZRANGE".into(),
+        PostgresType::DateRange => "DATERANGE".into(),
     };
 
     if t.arity.is_list() {
@@ -698,8 +751,15 @@ fn render_alter_column(
                 clauses.push(format!("{} DROP DEFAULT", &alter_column_prefix));
 
                 // We also need to drop the sequence, in case it isn't used by any other column.
-                if let Some(DefaultKind::Sequence(sequence_name)) = columns.previous.default().map(|d| d.kind()) {
-                    let sequence_is_still_used = columns.next.schema.walk_table_columns().any(|column| matches!(column.default().map(|d| d.kind()), Some(DefaultKind::Sequence(other_sequence)) if other_sequence == sequence_name) && !column.is_same_column(columns.next));
+                if let Some(DefaultKind::Sequence { name: sequence_name, .. }) =
+                    columns.previous.default().map(|d| d.kind())
+                {
+                    let sequence_is_still_used = columns.next.schema.walk_table_columns().any(|column| {
+                        matches!(
+                            column.default().map(|d| d.kind()),
+                            Some(DefaultKind::Sequence { name: other_sequence, .. }) if other_sequence == sequence_name
+                        ) && !column.is_same_column(columns.next)
+                    });
 
                     if !sequence_is_still_used {
                         after_statements.push(format!("DROP SEQUENCE {}", Quoted::postgres_ident(sequence_name)));
@@ -746,6 +806,11 @@ fn render_alter_column(
                     "ALTER SEQUENCE {sequence_name} OWNED BY {table_name}.{column_name}",
                 ));
             }
+            PostgresAlterColumn::SetStorage(storage) => clauses.push(format!(
+                "{} SET STORAGE {}",
+                &alter_column_prefix,
+                storage.to_ddl()
+            )),
         }
     }
 }
@@ -791,6 +856,21 @@ fn expand_alter_column(
                     changes.push(PostgresAlterColumn::AddSequence)
                 }
             }
+            ColumnChange::Storage => {
+                let next_ext: &PostgresSchemaExt = columns.next.schema.downcast_connector_data();
+
+                // We can only render `SET STORAGE` towards an explicit, known strategy. Reverting
+                // to a type's implicit default storage strategy would require looking that
+                // default up per-type, which the describer does not expose, so we leave it alone
+                // in that direction rather than guess.
+                if let Some(storage) = next_ext.column_storage(columns.next.id) {
+                    changes.push(PostgresAlterColumn::SetStorage(storage));
+                }
+            }
+            // Postgres does not track column ordering or per-column collation as migratable
+            // properties (`should_track_column_order` and `column_collation_changed` both default
+            // to `false` here), so these are never actually produced for this connector.
+            ColumnChange::Ordering | ColumnChange::Collation => (),
         }
     }
 
@@ -812,6 +892,8 @@ enum PostgresAlterColumn {
     SetNotNull,
     /// Add an auto-incrementing sequence as a default on the column.
     AddSequence,
+    /// Change the column's TOAST storage strategy.
+    SetStorage(sql_schema_describer::postgres::PostgresColumnStorage),
 }
 
 fn render_default<'a>(default: &'a DefaultValue, full_data_type: &str) -> Cow<'a, str> {
@@ -866,7 +948,7 @@ fn render_default<'a>(default: &'a DefaultValue, full_data_type: &str) -> Cow<'a
         DefaultKind::Now => "CURRENT_TIMESTAMP".into(),
         DefaultKind::Value(value) => render_constant_default(value, full_data_type),
         DefaultKind::UniqueRowid => "unique_rowid()".into(),
-        DefaultKind::Sequence(_) | DefaultKind::DbGenerated(None) => Default::default(),
+        DefaultKind::Sequence { .. } | DefaultKind::DbGenerated(None) => Default::default(),
     }
 }
 
@@ -1082,6 +1164,24 @@ fn render_cockroach_alter_enum(
     }
 }
 
+fn render_column_storage_str(column: TableColumnWalker<'_>) -> String {
+    let ext: &PostgresSchemaExt = column.schema.downcast_connector_data();
+
+    match ext.column_storage(column.id) {
+        Some(storage) => format!(" STORAGE {}", storage.to_ddl()),
+        None => String::new(),
+    }
+}
+
+fn render_replica_identity(replica_identity: Option<&ReplicaIdentity>) -> String {
+    match replica_identity {
+        None => "DEFAULT".to_owned(),
+        Some(ReplicaIdentity::Full) => "FULL".to_owned(),
+        Some(ReplicaIdentity::Nothing) => "NOTHING".to_owned(),
+        Some(ReplicaIdentity::Index(name)) => format!("USING INDEX {}", Quoted::postgres_ident(name)),
+    }
+}
+
 fn render_column_identity_str(column: TableColumnWalker<'_>, flavour: &PostgresFlavour) -> String {
     if !flavour.is_cockroachdb() {
         return String::new();
@@ -20,7 +20,7 @@ impl SqlRenderer for SqliteFlavour {
         unreachable!("render_alter_enum on sqlite")
     }
 
-    fn render_create_index(&self, index: IndexWalker<'_>) -> String {
+    fn render_create_index(&self, index: IndexWalker<'_>, _concurrently: bool) -> String {
         let index_type = if index.is_unique() { "UNIQUE " } else { "" };
         let index_name = Quoted::sqlite_ident(index.name());
         let table_reference = Quoted::sqlite_ident(index.table().name());
@@ -89,6 +89,7 @@ impl SqlRenderer for SqliteFlavour {
                 TableChange::DropColumn { .. } => unreachable!("DropColumn on SQLite"),
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
                 TableChange::RenamePrimaryKey { .. } => unreachable!("AddPrimaryKey on SQLite"),
+                TableChange::AlterReplicaIdentity => unreachable!("AlterReplicaIdentity on SQLite"),
             };
         }
 
@@ -133,6 +134,7 @@ impl SqlRenderer for SqliteFlavour {
                     }),
                 })
                 .collect(),
+            without_rowid: table.is_without_rowid(),
         };
 
         if !table.columns().any(|col| col.is_single_primary_key()) {
@@ -159,7 +161,7 @@ impl SqlRenderer for SqliteFlavour {
     fn render_drop_and_recreate_index(&self, indexes: MigrationPair<IndexWalker<'_>>) -> Vec<String> {
         vec![
             self.render_drop_index(indexes.previous),
-            self.render_create_index(indexes.next),
+            self.render_create_index(indexes.next, false),
         ]
     }
 
@@ -214,7 +216,7 @@ impl SqlRenderer for SqliteFlavour {
             ));
 
             for index in tables.next.indexes().filter(|idx| !idx.is_primary_key()) {
-                result.push(self.render_create_index(index));
+                result.push(self.render_create_index(index, false));
             }
 
             // Collect foreign key checks for any renamed tables.
@@ -338,7 +340,7 @@ fn render_column<'a>(column: &TableColumnWalker<'a>) -> ddl::Column<'a> {
             .filter(|default| {
                 !matches!(
                     default.kind(),
-                    DefaultKind::Sequence(_) | DefaultKind::DbGenerated(None)
+                    DefaultKind::Sequence { .. } | DefaultKind::DbGenerated(None)
                 )
             })
             .map(|d| d.inner())
@@ -364,6 +366,6 @@ fn render_default(default: &DefaultValue) -> Cow<'_, str> {
         DefaultKind::Now => "CURRENT_TIMESTAMP".into(),
         DefaultKind::Value(PrismaValue::DateTime(val)) => Quoted::sqlite_string(val).to_string().into(),
         DefaultKind::Value(val) => val.to_string().into(),
-        DefaultKind::DbGenerated(None) | DefaultKind::Sequence(_) | DefaultKind::UniqueRowid => unreachable!(),
+        DefaultKind::DbGenerated(None) | DefaultKind::Sequence { .. } | DefaultKind::UniqueRowid => unreachable!(),
     }
 }
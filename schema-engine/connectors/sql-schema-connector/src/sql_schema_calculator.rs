@@ -495,11 +495,11 @@ fn push_column_for_builtin_scalar_type(
                     .map(ColumnDefault::Available)
                     .unwrap_or(ColumnDefault::NA)
             } else if v.is_sequence() {
-                ColumnDefault::Available(sql::DefaultValue::new(sql::DefaultKind::Sequence(format!(
+                ColumnDefault::Available(sql::DefaultValue::sequence(format!(
                     "prisma_sequence_{}_{}",
                     field.model().database_name(),
                     field.database_name()
-                ))))
+                )))
             } else {
                 match v.value() {
                     ast::Expression::Function(_, _, _) => ColumnDefault::PrismaGenerated,
@@ -30,4 +30,15 @@ impl SqlSchemaCalculatorFlavour for MysqlFlavour {
     fn column_type_for_enum(&self, enm: EnumWalker<'_>, ctx: &Context<'_>) -> Option<sql::ColumnTypeFamily> {
         ctx.enum_ids.get(&enm.id).map(|id| sql::ColumnTypeFamily::Enum(*id))
     }
+
+    fn push_connector_data(&self, context: &mut Context<'_>) {
+        // The Prisma schema has no way to express a column or table character set/collation, so
+        // the desired schema never sets a non-default one. The empty `MysqlSchemaExt` is still
+        // required so that diffing and rendering, which always downcast the connector data on
+        // both sides, don't panic.
+        context
+            .schema
+            .describer_schema
+            .set_connector_data(Box::<sql::mysql::MysqlSchemaExt>::default());
+    }
 }
@@ -15,6 +15,7 @@ use crate::{
     sql_migration::{self, AlterColumn, AlterTable, RedefineTable, SqlMigrationStep, TableChange},
     SqlFlavour,
 };
+use schema_connector::ExternallyManagedTables;
 use column::ColumnTypeChange;
 use sql_schema_describer::{walkers::ForeignKeyWalker, IndexId, TableColumnId, Walker};
 use std::{borrow::Cow, collections::HashSet};
@@ -23,8 +24,9 @@ use table::TableDiffer;
 pub(crate) fn calculate_steps(
     schemas: MigrationPair<&SqlDatabaseSchema>,
     flavour: &dyn SqlFlavour,
+    externally_managed_tables: &ExternallyManagedTables,
 ) -> Vec<SqlMigrationStep> {
-    let db = DifferDatabase::new(schemas, flavour);
+    let db = DifferDatabase::new(schemas, flavour, externally_managed_tables);
     let mut steps: Vec<SqlMigrationStep> = Vec::new();
 
     flavour.push_extension_steps(&mut steps, &db);
@@ -39,6 +41,7 @@ pub(crate) fn calculate_steps(
 
     flavour.push_enum_steps(&mut steps, &db);
     flavour.push_alter_sequence_steps(&mut steps, &db);
+    flavour.push_procedure_steps(&mut steps, &db);
 
     sort_migration_steps(&mut steps, &db);
 
@@ -69,6 +72,7 @@ fn push_created_table_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDataba
                     table_id: (None, index.table().id),
                     index_id: index.id,
                     from_drop_and_recreate: false,
+                    concurrently: false,
                 });
 
             steps.extend(create_indexes_from_created_tables);
@@ -140,6 +144,10 @@ fn push_altered_table_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDataba
             changes.push(change);
         }
 
+        if let Some(change) = replica_identity_changed(&table) {
+            changes.push(change);
+        }
+
         dropped_columns(&table, &mut changes);
         added_columns(&table, &mut changes);
 
@@ -305,6 +313,14 @@ fn renamed_primary_key(differ: &TableDiffer<'_, '_>) -> Option<TableChange> {
         .map(|_| TableChange::RenamePrimaryKey)
 }
 
+fn replica_identity_changed(differ: &TableDiffer<'_, '_>) -> Option<TableChange> {
+    differ
+        .db
+        .flavour
+        .replica_identity_changed(differ.tables)
+        .then_some(TableChange::AlterReplicaIdentity)
+}
+
 fn push_alter_primary_key(differ: &TableDiffer<'_, '_>, steps: &mut Vec<SqlMigrationStep>) {
     if !differ.db.flavour.can_alter_primary_keys() {
         return;
@@ -329,6 +345,7 @@ fn push_created_index_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDataba
                 table_id: (Some(tables.previous().id), tables.next().id),
                 index_id: index.id,
                 from_drop_and_recreate: false,
+                concurrently: db.flavour.should_create_indexes_concurrently(),
             })
         }
 
@@ -354,6 +371,7 @@ fn push_created_index_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDataba
                     table_id: (Some(tables.previous().id), tables.next().id),
                     index_id: index.next.id,
                     from_drop_and_recreate: true,
+                    concurrently: false,
                 })
             }
         }
@@ -23,6 +23,18 @@ pub(crate) fn all_changes(cols: MigrationPair<TableColumnWalker<'_>>, flavour: &
         changes |= ColumnChange::Autoincrement;
     }
 
+    if flavour.should_track_column_order() && cols.previous.position() != cols.next.position() {
+        changes |= ColumnChange::Ordering;
+    }
+
+    if flavour.column_collation_changed(cols) {
+        changes |= ColumnChange::Collation;
+    }
+
+    if flavour.column_storage_changed(cols) {
+        changes |= ColumnChange::Storage;
+    }
+
     ColumnChanges { type_change, changes }
 }
 
@@ -104,9 +116,9 @@ fn defaults_match(cols: MigrationPair<TableColumnWalker<'_>>, flavour: &dyn SqlF
         (Some(DefaultKind::DbGenerated(_)), None) => false,
         (_, Some(DefaultKind::DbGenerated(None))) => true,
 
-        (Some(DefaultKind::Sequence(_)), None) => true, // sequences are dropped separately
-        (Some(DefaultKind::Sequence(_)), Some(DefaultKind::Value(_))) => false,
-        (Some(DefaultKind::Sequence(_)), Some(DefaultKind::Now)) => false,
+        (Some(DefaultKind::Sequence { .. }), None) => true, // sequences are dropped separately
+        (Some(DefaultKind::Sequence { .. }), Some(DefaultKind::Value(_))) => false,
+        (Some(DefaultKind::Sequence { .. }), Some(DefaultKind::Now)) => false,
 
         (Some(DefaultKind::UniqueRowid), Some(DefaultKind::UniqueRowid)) => true,
         (Some(DefaultKind::UniqueRowid), _) | (_, Some(DefaultKind::UniqueRowid)) => false,
@@ -116,10 +128,117 @@ fn defaults_match(cols: MigrationPair<TableColumnWalker<'_>>, flavour: &dyn SqlF
         (None, Some(DefaultKind::Now)) => false,
 
         (Some(DefaultKind::DbGenerated(Some(prev))), Some(DefaultKind::DbGenerated(Some(next)))) => {
-            (prev.eq_ignore_ascii_case(next)) && names_match
+            normalize_dbgenerated_expression(prev).eq_ignore_ascii_case(&normalize_dbgenerated_expression(next))
+                && names_match
         }
         (_, Some(DefaultKind::DbGenerated(_))) => false,
-        (_, Some(DefaultKind::Sequence(_))) => true,
+        (_, Some(DefaultKind::Sequence { .. })) => true,
+    }
+}
+
+/// Normalizes a raw `DbGenerated` SQL expression before comparing it, so a `dbgenerated(...)`
+/// value carried over from the Prisma schema doesn't produce a phantom diff against what the
+/// database's expression deparser reports back on introspection (e.g. Postgres re-serializing a
+/// bare string literal argument as `'-'::text`). This is not a full SQL parser: it only collapses
+/// whitespace runs and drops explicit `::type` casts, which covers the common cases without
+/// having to understand the expression itself.
+fn normalize_dbgenerated_expression(expr: &str) -> String {
+    let mut out = String::with_capacity(expr.len());
+    let mut chars = expr.chars().peekable();
+    let mut last_was_space = false;
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next(); // consume the second `:`
+            skip_cast_target(&mut chars);
+            last_was_space = false;
+            continue;
+        }
+
+        if c.is_whitespace() {
+            if !last_was_space {
+                out.push(' ');
+                last_was_space = true;
+            }
+        } else {
+            out.push(c);
+            last_was_space = false;
+        }
+    }
+
+    out.trim().to_owned()
+}
+
+/// Consumes the type name following a `::` cast operator, e.g. `text`, `"public"."color"` or
+/// `numeric(65,30)[]`, leaving the cursor right after it.
+fn skip_cast_target(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    while let Some(&c) = chars.peek() {
+        if c.is_alphanumeric() || c == '_' || c == '.' {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            for c in chars.by_ref() {
+                if c == '"' {
+                    break;
+                }
+            }
+        } else {
+            break;
+        }
+    }
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else {
+            break;
+        }
+    }
+
+    if let Some(&'(') = chars.peek() {
+        let mut depth = 0;
+        for c in chars.by_ref() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    while chars.peek() == Some(&'[') {
+        chars.next();
+        for c in chars.by_ref() {
+            if c == ']' {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_dbgenerated_expression;
+
+    #[test]
+    fn normalize_dbgenerated_expression_drops_casts_and_collapses_whitespace() {
+        assert_eq!(
+            normalize_dbgenerated_expression("concat('foo'::text,   '-'::text, 'bar'::text)"),
+            normalize_dbgenerated_expression("concat('foo', '-', 'bar')"),
+        );
+    }
+
+    #[test]
+    fn normalize_dbgenerated_expression_handles_precision_and_array_casts() {
+        assert_eq!(
+            normalize_dbgenerated_expression("'{1,2}'::numeric(65,30)[]"),
+            normalize_dbgenerated_expression("'{1,2}'"),
+        );
     }
 }
 
@@ -162,6 +281,15 @@ pub(crate) enum ColumnChange {
     Default,
     TypeChanged,
     Autoincrement,
+    /// The column moved to a different position among its table's columns. Only tracked on
+    /// connectors where column order is significant.
+    Ordering,
+    /// The column's character set or collation changed. Only tracked on connectors that support
+    /// per-column character sets and collations.
+    Collation,
+    /// The column's `STORAGE`/TOAST strategy changed. Only tracked on connectors that support
+    /// per-column storage settings.
+    Storage,
 }
 
 // This should be pub(crate), but SqlMigration is exported, so it has to be
@@ -209,6 +337,18 @@ impl ColumnChanges {
         self.changes.contains(ColumnChange::Default)
     }
 
+    pub(crate) fn ordering_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Ordering)
+    }
+
+    pub(crate) fn collation_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Collation)
+    }
+
+    pub(crate) fn storage_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Storage)
+    }
+
     pub(crate) fn only_default_changed(&self) -> bool {
         self.changes == ColumnChange::Default
     }
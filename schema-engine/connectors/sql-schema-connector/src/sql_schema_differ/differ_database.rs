@@ -1,10 +1,11 @@
 use super::{column, enums::EnumDiffer, table::TableDiffer};
 use crate::{flavour::SqlFlavour, migration_pair::MigrationPair, SqlDatabaseSchema};
 use indexmap::IndexMap;
+use schema_connector::ExternallyManagedTables;
 use sql_schema_describer::{
     postgres::{ExtensionId, ExtensionWalker, PostgresSchemaExt},
     walkers::{EnumWalker, TableColumnWalker, TableWalker},
-    NamespaceId, NamespaceWalker, TableColumnId, TableId,
+    NamespaceId, NamespaceWalker, Procedure, TableColumnId, TableId,
 };
 use std::{
     borrow::Cow,
@@ -35,7 +36,11 @@ pub(crate) struct DifferDatabase<'a> {
 }
 
 impl<'a> DifferDatabase<'a> {
-    pub(crate) fn new(schemas: MigrationPair<&'a SqlDatabaseSchema>, flavour: &'a dyn SqlFlavour) -> Self {
+    pub(crate) fn new(
+        schemas: MigrationPair<&'a SqlDatabaseSchema>,
+        flavour: &'a dyn SqlFlavour,
+        externally_managed_tables: &'a ExternallyManagedTables,
+    ) -> Self {
         let namespace_count_lb = std::cmp::max(
             schemas.previous.describer_schema.namespaces_count(),
             schemas.next.describer_schema.namespaces_count(),
@@ -58,8 +63,13 @@ impl<'a> DifferDatabase<'a> {
 
         let mut columns_cache = HashMap::new();
         let table_is_ignored = |table_name: &str| {
-            table_name == crate::MIGRATIONS_TABLE_NAME || flavour.table_should_be_ignored(table_name)
+            table_name == crate::MIGRATIONS_TABLE_NAME
+                || flavour.table_should_be_ignored(table_name)
+                || externally_managed_tables.contains(table_name)
         };
+        // Foreign tables (postgres_fdw) are backed by an external data source: we do not manage
+        // their schema, so they must never be diffed, created or dropped.
+        let table_is_diffable = |t: &TableWalker<'_>| !table_is_ignored(t.name()) && !t.is_foreign_table();
 
         // First insert all namespaces from the previous schema.
         for namespace in schemas.previous.describer_schema.walk_namespaces() {
@@ -88,7 +98,7 @@ impl<'a> DifferDatabase<'a> {
             .previous
             .describer_schema
             .table_walkers()
-            .filter(|t| !table_is_ignored(t.name()))
+            .filter(table_is_diffable)
         {
             let table_name = if flavour.lower_cases_table_names() {
                 table.name().to_ascii_lowercase().into()
@@ -107,7 +117,7 @@ impl<'a> DifferDatabase<'a> {
             .next
             .describer_schema
             .table_walkers()
-            .filter(|t| !table_is_ignored(t.name()))
+            .filter(table_is_diffable)
         {
             let table_name = if flavour.lower_cases_table_names() {
                 table.name().to_ascii_lowercase().into()
@@ -264,6 +274,21 @@ impl<'a> DifferDatabase<'a> {
         })
     }
 
+    /// Procedures present (by name) in both schemas. Procedures have no id in the describer
+    /// schema (see [`sql_schema_describer::Procedure`]), so unlike [`Self::enum_pairs`] this
+    /// matches on name alone, ignoring namespace.
+    pub(crate) fn procedure_pairs(&self) -> impl Iterator<Item = MigrationPair<&'a Procedure>> + '_ {
+        let previous_procedures = self.schemas.previous.describer_schema.procedures();
+        let next_procedures = self.schemas.next.describer_schema.procedures();
+
+        previous_procedures.iter().filter_map(move |previous| {
+            next_procedures
+                .iter()
+                .find(|next| next.name == previous.name)
+                .map(|next| MigrationPair::new(previous, next))
+        })
+    }
+
     pub(crate) fn created_enums<'db>(&'db self) -> impl Iterator<Item = EnumWalker<'a>> + 'db {
         self.next_enums()
             .filter(move |next| !self.previous_enums().any(|previous| enums_match(&previous, next)))
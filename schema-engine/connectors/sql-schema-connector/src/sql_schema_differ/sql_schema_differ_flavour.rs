@@ -60,6 +60,11 @@ pub(crate) trait SqlSchemaDifferFlavour {
     /// Push AlterExtension steps.
     fn push_extension_steps(&self, _steps: &mut Vec<SqlMigrationStep>, _db: &DifferDatabase<'_>) {}
 
+    /// Push `AlterProcedure` steps for stored procedures/functions whose body changed. Off by
+    /// default: most connectors either don't describe procedures at all, or the migration
+    /// wouldn't know how to safely replace one, so this needs an explicit opt-in per flavour.
+    fn push_procedure_steps(&self, _steps: &mut Vec<SqlMigrationStep>, _db: &DifferDatabase<'_>) {}
+
     /// Define database-specific extension modules.
     fn define_extensions(&self, _db: &mut DifferDatabase<'_>) {}
 
@@ -103,6 +108,13 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// Whether indexes added to existing tables should be created without taking a write lock
+    /// on the table (`CREATE INDEX CONCURRENTLY` on Postgres). Ignored by connectors that do not
+    /// support it.
+    fn should_create_indexes_concurrently(&self) -> bool {
+        false
+    }
+
     /// Whether the indexes of dropped tables should be dropped before the table
     /// is dropped.
     fn should_drop_indexes_from_dropped_tables(&self) -> bool {
@@ -150,6 +162,35 @@ pub(crate) trait SqlSchemaDifferFlavour {
         names.previous == names.next
     }
 
+    /// Whether a column changing its position among the other columns of its table should be
+    /// treated as a change requiring a migration step. On connectors where column order is
+    /// purely cosmetic (e.g. Postgres), this should stay `false` so that reordering fields in
+    /// the datamodel does not produce phantom migrations.
+    fn should_track_column_order(&self) -> bool {
+        false
+    }
+
+    /// Whether the column's character set or collation changed in a way that requires a
+    /// migration step. Only meaningful on connectors that track per-column character sets and
+    /// collations (currently MySQL); other connectors keep the default `false`.
+    fn column_collation_changed(&self, _columns: MigrationPair<TableColumnWalker<'_>>) -> bool {
+        false
+    }
+
+    /// Whether the column's `STORAGE` setting changed in a way that requires a migration step.
+    /// Only meaningful on connectors that track per-column TOAST storage strategies (currently
+    /// Postgres); other connectors keep the default `false`.
+    fn column_storage_changed(&self, _columns: MigrationPair<TableColumnWalker<'_>>) -> bool {
+        false
+    }
+
+    /// Whether the table's `REPLICA IDENTITY` setting changed in a way that requires a migration
+    /// step. Only meaningful on connectors that support logical replication identities
+    /// (currently Postgres); other connectors keep the default `false`.
+    fn replica_identity_changed(&self, _tables: MigrationPair<TableWalker<'_>>) -> bool {
+        false
+    }
+
     /// Return the tables that cannot be migrated without being redefined. This
     /// is currently useful only on SQLite.
     fn set_tables_to_redefine(&self, _db: &mut DifferDatabase<'_>) {}
@@ -102,6 +102,7 @@ impl SqlSchemaDifferFlavour for MssqlFlavour {
                 table_id: (None, table.next().id),
                 index_id: created_index.next.id,
                 from_drop_and_recreate: false,
+                concurrently: false,
             })
         }
     }
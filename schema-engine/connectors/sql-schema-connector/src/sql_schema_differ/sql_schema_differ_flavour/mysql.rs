@@ -6,6 +6,7 @@ use crate::{
 };
 use psl::builtin_connectors::MySqlType;
 use sql_schema_describer::{
+    mysql::MysqlSchemaExt,
     walkers::{IndexWalker, TableColumnWalker},
     ColumnTypeFamily,
 };
@@ -23,6 +24,13 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
         false
     }
 
+    fn column_collation_changed(&self, columns: MigrationPair<TableColumnWalker<'_>>) -> bool {
+        let previous_ext = columns.previous.schema.downcast_connector_data::<MysqlSchemaExt>();
+        let next_ext = columns.next.schema.downcast_connector_data::<MysqlSchemaExt>();
+
+        previous_ext.column_collation(columns.previous.id) != next_ext.column_collation(columns.next.id)
+    }
+
     fn column_type_change(&self, differ: MigrationPair<TableColumnWalker<'_>>) -> Option<ColumnTypeChange> {
         // On MariaDB, JSON is an alias for LONGTEXT. https://mariadb.com/kb/en/json-data-type/
         if self.is_mariadb() {
@@ -4,8 +4,8 @@ use crate::{
     flavour::PostgresFlavour,
     migration_pair::MigrationPair,
     sql_migration::{
-        AlterEnum, AlterExtension, CreateExtension, DropExtension, ExtensionChange, SequenceChange, SequenceChanges,
-        SqlMigrationStep,
+        AlterEnum, AlterExtension, AlterProcedure, CreateExtension, DropExtension, ExtensionChange, SequenceChange,
+        SequenceChanges, SqlMigrationStep,
     },
     sql_schema_differ::{column::ColumnTypeChange, differ_database::DifferDatabase},
 };
@@ -14,8 +14,8 @@ use once_cell::sync::Lazy;
 use psl::builtin_connectors::{CockroachType, PostgresType};
 use regex::RegexSet;
 use sql_schema_describer::{
-    postgres::PostgresSchemaExt,
-    walkers::{IndexWalker, TableColumnWalker},
+    postgres::{PostgresSchemaExt, Sequence},
+    walkers::{IndexWalker, TableColumnWalker, TableWalker},
 };
 
 /// These can be tables or views, depending on the PostGIS version. In both cases, they should be ignored.
@@ -52,6 +52,30 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         columns.previous.is_autoincrement() != columns.next.is_autoincrement()
     }
 
+    fn column_storage_changed(&self, columns: MigrationPair<TableColumnWalker<'_>>) -> bool {
+        // CockroachDB doesn't implement TOAST, so it has no notion of per-column storage.
+        if self.is_cockroachdb() {
+            return false;
+        }
+
+        let previous_ext = columns.previous.schema.downcast_connector_data::<PostgresSchemaExt>();
+        let next_ext = columns.next.schema.downcast_connector_data::<PostgresSchemaExt>();
+
+        previous_ext.column_storage(columns.previous.id) != next_ext.column_storage(columns.next.id)
+    }
+
+    fn replica_identity_changed(&self, tables: MigrationPair<TableWalker<'_>>) -> bool {
+        // CockroachDB doesn't support logical replication identities.
+        if self.is_cockroachdb() {
+            return false;
+        }
+
+        let previous_ext = tables.previous.schema.downcast_connector_data::<PostgresSchemaExt>();
+        let next_ext = tables.next.schema.downcast_connector_data::<PostgresSchemaExt>();
+
+        previous_ext.replica_identity(tables.previous.id) != next_ext.replica_identity(tables.next.id)
+    }
+
     fn column_type_change(&self, columns: MigrationPair<TableColumnWalker<'_>>) -> Option<ColumnTypeChange> {
         // Handle the enum cases first.
         match columns
@@ -97,6 +121,16 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         }
     }
 
+    fn push_procedure_steps(&self, steps: &mut Vec<SqlMigrationStep>, db: &DifferDatabase<'_>) {
+        for procedures in db.procedure_pairs() {
+            if procedures.previous.definition != procedures.next.definition {
+                steps.push(SqlMigrationStep::AlterProcedure(AlterProcedure {
+                    name: procedures.next.name.clone(),
+                }));
+            }
+        }
+    }
+
     fn push_alter_sequence_steps(&self, steps: &mut Vec<SqlMigrationStep>, db: &DifferDatabase<'_>) {
         if !self.is_cockroachdb() {
             return;
@@ -123,6 +157,16 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
                 .transpose()
             });
 
+        // `owned_by` is a `TableColumnId`, which is only meaningful within the schema it was
+        // read from: the same id can point at unrelated columns in the previous and next schema.
+        // Resolve it to a (table, column) name pair before comparing across the two schemas.
+        let owner_name = |schema: &SqlDatabaseSchema, sequence: &Sequence| {
+            sequence
+                .owned_by
+                .map(|id| schema.walk(id))
+                .map(|column| (column.table().name().to_owned(), column.name().to_owned()))
+        };
+
         for pair in sequence_pairs {
             let prev = pair.previous.1;
             let next = pair.next.1;
@@ -148,6 +192,10 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
                 changes |= SequenceChange::Increment;
             }
 
+            if owner_name(schemas.previous.0, prev) != owner_name(schemas.next.0, next) {
+                changes |= SequenceChange::OwnedBy;
+            }
+
             if !changes.is_empty() {
                 steps.push(SqlMigrationStep::AlterSequence(
                     pair.map(|p| p.0 as u32),
@@ -167,8 +215,12 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         let previous_algo = pg_ext_previous.index_algorithm(a.id);
         let next_algo = pg_ext_next.index_algorithm(b.id);
 
+        let previous_fillfactor = pg_ext_previous.index_fillfactor(a.id);
+        let next_fillfactor = pg_ext_next.index_fillfactor(b.id);
+
         columns_previous.len() == columns_next.len()
             && previous_algo == next_algo
+            && previous_fillfactor == next_fillfactor
             && columns_previous.zip(columns_next).all(|(col_a, col_b)| {
                 let a_class = pg_ext_previous.get_opclass(col_a.id);
                 let b_class = pg_ext_next.get_opclass(col_b.id);
@@ -187,6 +239,16 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         true
     }
 
+    fn should_create_indexes_concurrently(&self) -> bool {
+        // CockroachDB's shadow database migration replay wraps applied scripts in an explicit
+        // transaction (see shadow_db.rs), and CREATE INDEX CONCURRENTLY cannot run inside one.
+        if self.is_cockroachdb() {
+            return false;
+        }
+
+        self.concurrent_indexes()
+    }
+
     fn index_should_be_renamed(&self, pair: MigrationPair<IndexWalker<'_>>) -> bool {
         // Implements correct comparison for truncated index names.
         let (previous_name, next_name) = pair.map(|idx| idx.name()).into_tuple();
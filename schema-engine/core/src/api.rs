@@ -20,6 +20,9 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Send a raw command to the database.
     async fn db_execute(&self, params: DbExecuteParams) -> CoreResult<()>;
 
+    /// Validate a raw script against the database, without persisting any changes.
+    async fn db_validate(&self, params: DbValidateParams) -> CoreResult<()>;
+
     /// Debugging method that only panics, for CLI tests.
     async fn debug_panic(&self) -> CoreResult<()>;
 
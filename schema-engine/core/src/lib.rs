@@ -46,6 +46,7 @@ fn connector_for_connection_string(
                 connection_string,
                 preview_features,
                 shadow_database_connection_string,
+                application_name: None,
             };
             let mut connector = SqlSchemaConnector::new_postgres_like();
             connector.set_params(params)?;
@@ -56,6 +57,7 @@ fn connector_for_connection_string(
                 connection_string,
                 preview_features,
                 shadow_database_connection_string,
+                application_name: None,
             };
             let mut connector = SqlSchemaConnector::new_sqlite();
             connector.set_params(params)?;
@@ -66,6 +68,7 @@ fn connector_for_connection_string(
                 connection_string,
                 preview_features,
                 shadow_database_connection_string,
+                application_name: None,
             };
             let mut connector = SqlSchemaConnector::new_mysql();
             connector.set_params(params)?;
@@ -76,6 +79,7 @@ fn connector_for_connection_string(
                 connection_string,
                 preview_features,
                 shadow_database_connection_string,
+                application_name: None,
             };
             let mut connector = SqlSchemaConnector::new_mssql();
             connector.set_params(params)?;
@@ -86,6 +90,7 @@ fn connector_for_connection_string(
                 connection_string,
                 preview_features,
                 shadow_database_connection_string,
+                application_name: None,
             };
             let connector = MongoDbSchemaConnector::new(params);
             Ok(Box::new(connector))
@@ -118,6 +123,7 @@ fn schema_to_connector_unchecked(
             connection_string,
             preview_features,
             shadow_database_connection_string: source.load_shadow_database_url().ok().flatten(),
+            application_name: None,
         })?;
     }
 
@@ -139,6 +145,7 @@ fn schema_to_connector(
         connection_string: url,
         preview_features,
         shadow_database_connection_string: shadow_database_url,
+        application_name: None,
     };
 
     let mut connector = connector_for_provider(source.active_provider)?;
@@ -155,6 +162,7 @@ fn connector_for_provider(provider: &str) -> CoreResult<Box<dyn schema_connector
                 connection_string: String::new(),
                 preview_features: Default::default(),
                 shadow_database_connection_string: None,
+                application_name: None,
             }))),
             Flavour::Sqlserver => Ok(Box::new(SqlSchemaConnector::new_mssql())),
             Flavour::Mysql => Ok(Box::new(SqlSchemaConnector::new_mysql())),
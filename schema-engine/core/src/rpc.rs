@@ -39,6 +39,7 @@ async fn run_command(
         CREATE_DATABASE => render(executor.create_database(params.parse()?).await),
         CREATE_MIGRATION => render(executor.create_migration(params.parse()?).await),
         DB_EXECUTE => render(executor.db_execute(params.parse()?).await),
+        DB_VALIDATE => render(executor.db_validate(params.parse()?).await),
         DEV_DIAGNOSTIC => render(executor.dev_diagnostic(params.parse()?).await),
         DIFF => render(executor.diff(params.parse()?).await),
         DEBUG_PANIC => render(executor.debug_panic().await),
@@ -8,7 +8,9 @@ use crate::{
 };
 use enumflags2::BitFlags;
 use psl::{parser_database::SourceFile, PreviewFeature};
-use schema_connector::{ConnectorError, ConnectorHost, IntrospectionResult, Namespaces, SchemaConnector};
+use schema_connector::{
+    ConnectorError, ConnectorHost, ExternallyManagedTables, IntrospectionResult, Namespaces, SchemaConnector,
+};
 use std::{
     collections::HashMap,
     future::Future,
@@ -264,6 +266,16 @@ impl GenericApi for EngineState {
             .await
     }
 
+    async fn db_validate(&self, params: DbValidateParams) -> CoreResult<()> {
+        let url: String = match &params.datasource_type {
+            DbExecuteDatasourceType::Url(UrlContainer { url }) => url.clone(),
+            DbExecuteDatasourceType::Schema(schemas) => self.get_url_from_schemas(schemas)?,
+        };
+
+        self.with_connector_for_url(url, Box::new(move |connector| connector.db_validate(params.script)))
+            .await
+    }
+
     async fn debug_panic(&self) -> CoreResult<()> {
         panic!("This is the debugPanic artificial panic")
     }
@@ -493,7 +505,15 @@ impl GenericApi for EngineState {
         tracing::debug!("Resetting the database.");
         let namespaces = self.namespaces();
         self.with_default_connector(Box::new(move |connector| {
-            Box::pin(SchemaConnector::reset(connector, false, namespaces).instrument(tracing::info_span!("Reset")))
+            Box::pin(
+                async move {
+                    // TODO: expose `externally_managed_tables` on the JSON-RPC `reset` input so
+                    // embedders can populate this; nothing surfaces it through this call path yet.
+                    let externally_managed_tables = ExternallyManagedTables::default();
+                    SchemaConnector::reset(connector, false, namespaces, &externally_managed_tables).await
+                }
+                .instrument(tracing::info_span!("Reset")),
+            )
         }))
         .await?;
         Ok(())
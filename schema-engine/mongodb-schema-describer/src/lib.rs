@@ -30,6 +30,7 @@ pub async fn describe(client: &mongodb::Client, db_name: &str) -> mongodb::error
         let collection_type = collection.collection_type;
         let has_schema = options.validator.is_some();
         let is_capped = options.capped.is_some();
+        let validator = options.validator.clone().map(JsonSchemaValidator::new);
 
         // We need to skip views, we do not support introspecting them yet.
         if collection_type == mongodb::results::CollectionType::View {
@@ -43,7 +44,7 @@ pub async fn describe(client: &mongodb::Client, db_name: &str) -> mongodb::error
         }
 
         let collection = database.collection::<Document>(&collection_name);
-        let collection_id = schema.push_collection(collection_name, has_schema, is_capped);
+        let collection_id = schema.push_collection_with_validator(collection_name, has_schema, is_capped, validator);
 
         let mut indexes_cursor = collection.list_indexes().await?;
 
@@ -65,19 +66,24 @@ pub async fn describe(client: &mongodb::Client, db_name: &str) -> mongodb::error
                 continue; // do not introspect or diff these
             }
 
-            if options.partial_filter_expression.is_some() {
-                continue;
-            }
+            let partial_filter = options
+                .partial_filter_expression
+                .as_ref()
+                .map(|doc| serde_json::to_string(doc).expect("partial filter expression should serialize to JSON"));
 
             let as_field = |(k, v): (&String, &Bson)| {
-                let property = match v.as_i32() {
-                    Some(-1) => IndexFieldProperty::Descending,
-                    _ => IndexFieldProperty::Ascending,
+                let (property, kind) = match v.as_str() {
+                    Some(s) => (IndexFieldProperty::Ascending, IndexFieldKind::from_bson_str(s)),
+                    None => match v.as_i32() {
+                        Some(-1) => (IndexFieldProperty::Descending, None),
+                        _ => (IndexFieldProperty::Ascending, None),
+                    },
                 };
 
                 IndexField {
                     name: k.to_string(),
                     property,
+                    kind,
                 }
             };
 
@@ -97,6 +103,7 @@ pub async fn describe(client: &mongodb::Client, db_name: &str) -> mongodb::error
                     .map(|k| IndexField {
                         name: k.to_string(),
                         property: IndexFieldProperty::Text,
+                        kind: None,
                     });
 
                 // And in the end add whatever fields were left in the index keys that are not
@@ -113,7 +120,7 @@ pub async fn describe(client: &mongodb::Client, db_name: &str) -> mongodb::error
                 index.keys.iter().map(as_field).collect()
             };
 
-            schema.push_index(collection_id, name, r#type, fields);
+            schema.push_index_with_filter(collection_id, name, r#type, fields, partial_filter);
         }
     }
 
@@ -22,6 +22,24 @@ pub struct CollectionData {
     pub(crate) name: String,
     pub(crate) has_schema: bool,
     pub(crate) is_capped: bool,
+    pub(crate) validator: Option<JsonSchemaValidator>,
+}
+
+/// The `$jsonSchema` validator document attached to a collection through `collMod` or
+/// `createCollection`, wrapped so we don't leak `bson`'s document representation through the
+/// public API beyond this one accessor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonSchemaValidator(bson::Document);
+
+impl JsonSchemaValidator {
+    pub(crate) fn new(document: bson::Document) -> Self {
+        Self(document)
+    }
+
+    /// The raw `$jsonSchema` validator document, as stored by MongoDB.
+    pub fn as_document(&self) -> &bson::Document {
+        &self.0
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -60,6 +78,8 @@ pub struct IndexData {
     pub fields: Vec<IndexField>,
     /// The id of a collection this index is part of.
     pub collection_id: CollectionId,
+    /// The index's `partialFilterExpression`, serialized as JSON, if it is a partial index.
+    pub partial_filter: Option<String>,
 }
 
 /// All the possible information we should scrape out from a MongoDB database.
@@ -73,10 +93,23 @@ pub struct MongoSchema {
 impl MongoSchema {
     /// Add a collection to the schema.
     pub fn push_collection(&mut self, name: String, has_schema: bool, is_capped: bool) -> CollectionId {
+        self.push_collection_with_validator(name, has_schema, is_capped, None)
+    }
+
+    /// Add a collection to the schema, along with its `$jsonSchema` validator document, if it has
+    /// one.
+    pub fn push_collection_with_validator(
+        &mut self,
+        name: String,
+        has_schema: bool,
+        is_capped: bool,
+        validator: Option<JsonSchemaValidator>,
+    ) -> CollectionId {
         self.collections.push(CollectionData {
             name,
             has_schema,
             is_capped,
+            validator,
         });
         CollectionId(self.collections.len() - 1)
     }
@@ -88,12 +121,26 @@ impl MongoSchema {
         name: String,
         r#type: IndexType,
         fields: Vec<IndexField>,
+    ) -> IndexId {
+        self.push_index_with_filter(collection_id, name, r#type, fields, None)
+    }
+
+    /// Adds an index to the schema, along with the `partialFilterExpression` it was created
+    /// with, if it is a partial index.
+    pub fn push_index_with_filter(
+        &mut self,
+        collection_id: CollectionId,
+        name: String,
+        r#type: IndexType,
+        fields: Vec<IndexField>,
+        partial_filter: Option<String>,
     ) -> IndexId {
         self.indexes.push(IndexData {
             name,
             r#type,
             fields,
             collection_id,
+            partial_filter,
         });
 
         let index_id = IndexId(self.indexes.len() - 1);
@@ -145,10 +192,11 @@ impl MongoSchema {
                 r#type,
                 fields,
                 collection_id,
+                partial_filter,
             } = index;
 
             // because this here is a mutable reference, so we must collect...
-            self.push_index(collection_id, name, r#type, fields);
+            self.push_index_with_filter(collection_id, name, r#type, fields, partial_filter);
         }
     }
 }
@@ -176,6 +224,8 @@ pub struct IndexField {
     pub name: String,
     /// Defines the property of the field.
     pub property: IndexFieldProperty,
+    /// The Mongo-specific key sub-type, if the field isn't a plain ascending/descending key.
+    pub kind: Option<IndexFieldKind>,
 }
 
 impl IndexField {
@@ -188,6 +238,11 @@ impl IndexField {
     pub fn is_text(&self) -> bool {
         matches!(self.property, IndexFieldProperty::Text)
     }
+
+    /// The Mongo-specific key sub-type, if the field isn't a plain ascending/descending key.
+    pub fn kind(&self) -> Option<IndexFieldKind> {
+        self.kind
+    }
 }
 
 impl fmt::Display for IndexField {
@@ -214,6 +269,31 @@ impl IndexFieldProperty {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// A Mongo-specific index key sub-type, for keys whose value in the index spec is a string
+/// rather than a plain `1`/`-1` sort direction.
+pub enum IndexFieldKind {
+    /// A 2d geospatial index (`"2d"`).
+    TwoD,
+    /// A 2dsphere geospatial index (`"2dsphere"`).
+    TwoDSphere,
+    /// A hashed index (`"hashed"`).
+    Hashed,
+}
+
+impl IndexFieldKind {
+    /// Parse the key sub-type from the string value of an index key in the index spec, e.g.
+    /// `"2dsphere"` or `"hashed"`. Returns `None` for anything we don't recognize.
+    pub(crate) fn from_bson_str(s: &str) -> Option<Self> {
+        match s {
+            "2d" => Some(Self::TwoD),
+            "2dsphere" => Some(Self::TwoDSphere),
+            "hashed" => Some(Self::Hashed),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for IndexFieldProperty {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
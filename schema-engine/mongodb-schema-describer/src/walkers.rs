@@ -1,4 +1,4 @@
-use crate::{CollectionData, CollectionId, IndexData, IndexField, IndexId, IndexType, MongoSchema};
+use crate::{CollectionData, CollectionId, IndexData, IndexField, IndexId, IndexType, JsonSchemaValidator, MongoSchema};
 
 #[derive(Clone, Copy)]
 /// A collection/table in the database.
@@ -44,6 +44,11 @@ impl<'schema> CollectionWalker<'schema> {
     pub fn is_capped(self) -> bool {
         self.get().is_capped
     }
+
+    /// The collection's `$jsonSchema` validator document, if it has one.
+    pub fn validator(self) -> Option<&'schema JsonSchemaValidator> {
+        self.get().validator.as_ref()
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -95,4 +100,71 @@ impl<'schema> IndexWalker<'schema> {
     pub fn fields(self) -> impl ExactSizeIterator<Item = &'schema IndexField> + 'schema {
         self.get().fields.iter()
     }
+
+    /// True if the index is a partial index, only indexing documents matching a filter.
+    pub fn is_partial(self) -> bool {
+        self.get().partial_filter.is_some()
+    }
+
+    /// The index's `partialFilterExpression`, parsed back into a document, if it is a partial
+    /// index.
+    pub fn partial_filter(self) -> Option<bson::Document> {
+        self.get()
+            .partial_filter
+            .as_deref()
+            .map(|json| serde_json::from_str(json).expect("stored partial filter is valid JSON"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bson::doc;
+
+    #[test]
+    fn partial_filter_round_trips_nested_and_or_without_reordering_keys() {
+        let filter = doc! {
+            "$and": [
+                { "status": "active" },
+                { "$or": [{ "age": { "$gte": 21 } }, { "verified": true }] },
+            ],
+        };
+
+        let mut schema = MongoSchema::default();
+        let collection_id = schema.push_collection("users".to_owned(), false, false);
+        let index_id = schema.push_index_with_filter(
+            collection_id,
+            "active_adults".to_owned(),
+            IndexType::Normal,
+            vec![],
+            Some(serde_json::to_string(&filter).unwrap()),
+        );
+
+        let index = schema.walk_index(index_id);
+
+        assert!(index.is_partial());
+        assert_eq!(index.partial_filter(), Some(filter));
+    }
+
+    #[test]
+    fn collection_validator_is_exposed_via_walker() {
+        let schema_doc = doc! {
+            "$jsonSchema": {
+                "bsonType": "object",
+                "required": ["name"],
+            },
+        };
+
+        let mut schema = MongoSchema::default();
+        let collection_id = schema.push_collection_with_validator(
+            "users".to_owned(),
+            true,
+            false,
+            Some(JsonSchemaValidator::new(schema_doc.clone())),
+        );
+
+        let collection = schema.walk_collection(collection_id);
+
+        assert_eq!(collection.validator().map(|v| v.as_document()), Some(&schema_doc));
+    }
 }
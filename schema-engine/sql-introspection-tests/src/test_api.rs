@@ -51,12 +51,17 @@ impl TestApi {
                 connection_string: connection_string.to_owned(),
                 preview_features,
                 shadow_database_connection_string: None,
+                application_name: None,
             };
             me.set_params(params).unwrap();
 
-            me.reset(true, schema_connector::Namespaces::from_vec(&mut namespaces.clone()))
-                .await
-                .unwrap();
+            me.reset(
+                true,
+                schema_connector::Namespaces::from_vec(&mut namespaces.clone()),
+                &Default::default(),
+            )
+            .await
+            .unwrap();
 
             (
                 Quaint::new(connection_string).await.unwrap(),
@@ -71,6 +76,7 @@ impl TestApi {
                 connection_string: cs.to_owned(),
                 preview_features,
                 shadow_database_connection_string: None,
+                application_name: None,
             };
             me.set_params(params).unwrap();
 
@@ -83,6 +89,7 @@ impl TestApi {
                 connection_string: cs.to_owned(),
                 preview_features,
                 shadow_database_connection_string: None,
+                application_name: None,
             };
             me.set_params(params).unwrap();
 
@@ -104,6 +111,7 @@ impl TestApi {
                 connection_string: cs.to_owned(),
                 preview_features,
                 shadow_database_connection_string: None,
+                application_name: None,
             };
             me.set_params(params).unwrap();
 
@@ -117,6 +125,7 @@ impl TestApi {
                 connection_string: cs.to_owned(),
                 preview_features,
                 shadow_database_connection_string: None,
+                application_name: None,
             };
             me.set_params(params).unwrap();
 
@@ -130,6 +139,7 @@ impl TestApi {
                 connection_string: url.to_owned(),
                 preview_features,
                 shadow_database_connection_string: None,
+                application_name: None,
             };
             me.set_params(params).unwrap();
 
@@ -41,6 +41,7 @@ async fn introspecting_cockroach_db_with_postgres_provider_fails(api: TestApi) {
         connection_string: api.connection_string().to_owned(),
         preview_features: api.preview_features(),
         shadow_database_connection_string: None,
+        application_name: None,
     };
     engine.set_params(params).unwrap();
 
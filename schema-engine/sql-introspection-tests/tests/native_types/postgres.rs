@@ -181,3 +181,31 @@ async fn cdb_char_is_a_char(api: &mut TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn native_type_range_columns(api: &mut TestApi) -> TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Blog", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("validity Tstzrange Not Null");
+            });
+        })
+        .await?;
+
+    let types = indoc! {r#"
+        model Blog {
+          id       Int    @id
+          validity String @db.TstzRange
+        }
+    "#};
+
+    let result = api.introspect().await?;
+
+    println!("EXPECTATION: \n {types:#}");
+    println!("RESULT: \n {result:#}");
+
+    api.assert_eq_datamodels(types, &result);
+
+    Ok(())
+}
@@ -453,6 +453,66 @@ async fn exclusion_constraints_without_where_and_expressions_stopgap(api: &mut T
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn multiple_exclusion_constraints_stopgap(api: &mut TestApi) -> TestResult {
+    let raw_sql = indoc! {r#"
+        CREATE EXTENSION btree_gist;
+
+        CREATE TABLE room_reservation (
+            room_reservation_id serial PRIMARY KEY,
+            room_id integer NOT NULL,
+            desk_id integer NOT NULL,
+            reserved_at timestamptz NOT NULL,
+            reserved_until timestamptz NOT NULL,
+            EXCLUDE USING gist (
+                room_id WITH =, tstzrange(reserved_at, reserved_until) WITH &&
+            ),
+            EXCLUDE USING gist (
+                desk_id WITH =, tstzrange(reserved_at, reserved_until) WITH &&
+            )
+        );
+    "#};
+
+    api.raw_cmd(raw_sql).await;
+
+    let schema = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        /// This table contains exclusion constraints and requires additional setup for migrations. Visit https://pris.ly/d/exclusion-constraints for more info.
+        model room_reservation {
+          room_reservation_id Int      @id @default(autoincrement())
+          room_id             Int
+          desk_id             Int
+          reserved_at         DateTime @db.Timestamptz(6)
+          reserved_until      DateTime @db.Timestamptz(6)
+        }
+    "#]];
+
+    api.expect_datamodel(&schema).await;
+
+    // ensure the introspected schema is valid
+    psl::parse_schema(schema.data()).unwrap();
+
+    let expectation = expect![[r#"
+        *** WARNING ***
+
+        These constraints are not supported by Prisma Client, because Prisma currently does not fully support exclusion constraints. Read more: https://pris.ly/d/exclusion-constraints
+          - Model: "room_reservation", constraint: "room_reservation_room_id_tstzrange_excl"
+          - Model: "room_reservation", constraint: "room_reservation_desk_id_tstzrange_excl"
+    "#]];
+
+    api.expect_warnings(&expectation).await;
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn check_constraints_stopgap(api: &mut TestApi) -> TestResult {
     // https://www.notion.so/prismaio/Indexes-Constraints-Check-constraints-PostgreSQL-cde0bee25f6343d8bbd0f7e84932e808
@@ -520,3 +580,67 @@ async fn check_constraints_stopgap(api: &mut TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn constraint_comments_stopgap(api: &mut TestApi) -> TestResult {
+    let raw_sql = indoc! {r#"
+        CREATE TABLE products (
+            product_id serial PRIMARY KEY,
+            name text,
+            price numeric CHECK (price > 0)
+        );
+
+        COMMENT ON CONSTRAINT products_price_check ON products IS 'Prices must be positive.';
+    "#};
+
+    api.raw_cmd(raw_sql).await;
+
+    let schema = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        /// This table contains check constraints and requires additional setup for migrations. Visit https://pris.ly/d/check-constraints for more info.
+        /// This model has comments on one or more of its constraints and requires additional setup for migrations. Visit https://pris.ly/d/constraint-comments for more info.
+        model products {
+          product_id Int      @id @default(autoincrement())
+          name       String?
+          price      Decimal? @db.Decimal
+        }
+    "#]];
+
+    api.expect_datamodel(&schema).await;
+
+    // ensure the introspected schema is valid
+    psl::parse_schema(schema.data()).unwrap();
+
+    let input = indoc! { r#"
+        /// This table contains check constraints and requires additional setup for migrations. Visit https://pris.ly/d/check-constraints for more info.
+        /// This model has comments on one or more of its constraints and requires additional setup for migrations. Visit https://pris.ly/d/constraint-comments for more info.
+        model products {
+          product_id Int      @id @default(autoincrement())
+          name       String?
+          price      Decimal? @db.Decimal
+        }
+    "#
+    };
+
+    let expectation = expect![[r#"
+        /// This table contains check constraints and requires additional setup for migrations. Visit https://pris.ly/d/check-constraints for more info.
+        /// This model has comments on one or more of its constraints and requires additional setup for migrations. Visit https://pris.ly/d/constraint-comments for more info.
+        model products {
+          product_id Int      @id @default(autoincrement())
+          name       String?
+          price      Decimal? @db.Decimal
+        }
+    "#]];
+
+    api.expect_re_introspected_datamodel(input, expectation).await;
+
+    Ok(())
+}
@@ -52,6 +52,42 @@ async fn sequences_should_work(api: &mut TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+async fn sequence_with_custom_attributes_introspects_to_sequence_not_autoincrement(api: &mut TestApi) -> TestResult {
+    let setup = r#"
+        CREATE SEQUENCE "custom_Sequence" INCREMENT BY 10 MINVALUE 100 START 100;
+
+        CREATE TABLE "Test" (
+            id INTEGER PRIMARY KEY,
+            val BigInt NOT NULL DEFAULT nextval('"custom_Sequence"'::regclass)
+        );
+
+        ALTER SEQUENCE "custom_Sequence" OWNED BY "Test"."val";
+    "#;
+
+    api.raw_cmd(setup).await;
+
+    let expectation = expect![[r#"
+        generator client {
+          provider = "prisma-client-js"
+        }
+
+        datasource db {
+          provider = "postgresql"
+          url      = "env(TEST_DATABASE_URL)"
+        }
+
+        model Test {
+          id  Int    @id
+          val BigInt @default(sequence(minValue: 100, increment: 10, start: 100))
+        }
+    "#]];
+
+    api.expect_datamodel(&expectation).await;
+
+    Ok(())
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 async fn dbgenerated_type_casts_should_work(api: &mut TestApi) -> TestResult {
     api.barrel()
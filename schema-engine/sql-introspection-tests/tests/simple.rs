@@ -143,6 +143,7 @@ source .test_database_urls/mysql_5_6
         connection_string: database_url,
         preview_features,
         shadow_database_connection_string: None,
+        application_name: None,
     };
 
     let mut api = match provider {
@@ -205,4 +205,16 @@ impl MigrationAssertion<'_> {
         assert_eq!(expected_contents, contents);
         self
     }
+
+    /// Run custom assertions against the migration's SQL script.
+    #[track_caller]
+    pub fn assert_contents_matches(self, assertions: impl FnOnce(&str)) -> Self {
+        let migration_file_path = self.path.join("migration.sql");
+        let contents: String = std::fs::read_to_string(&migration_file_path)
+            .map_err(|_| format!("Trying to read migration file at {migration_file_path:?}"))
+            .unwrap();
+
+        assertions(&contents);
+        self
+    }
 }
@@ -1,5 +1,5 @@
 use schema_core::{
-    schema_connector::{Namespaces, SchemaConnector},
+    schema_connector::{ExternallyManagedTables, Namespaces, SchemaConnector},
     CoreResult,
 };
 
@@ -7,11 +7,16 @@ use schema_core::{
 pub struct Reset<'a> {
     api: &'a mut dyn SchemaConnector,
     soft: bool,
+    externally_managed_tables: ExternallyManagedTables,
 }
 
 impl<'a> Reset<'a> {
     pub fn new(api: &'a mut dyn SchemaConnector) -> Self {
-        Reset { api, soft: false }
+        Reset {
+            api,
+            soft: false,
+            externally_managed_tables: ExternallyManagedTables::default(),
+        }
     }
 
     pub fn soft(mut self, value: bool) -> Self {
@@ -19,8 +24,15 @@ impl<'a> Reset<'a> {
         self
     }
 
+    pub fn externally_managed_tables(mut self, table_names: Vec<String>) -> Self {
+        self.externally_managed_tables = ExternallyManagedTables::new(table_names);
+        self
+    }
+
     pub async fn send(self, namespaces: Option<Namespaces>) -> CoreResult<ResetAssertion> {
-        self.api.reset(self.soft, namespaces).await?;
+        self.api
+            .reset(self.soft, namespaces, &self.externally_managed_tables)
+            .await?;
 
         Ok(ResetAssertion {})
     }
@@ -49,10 +49,11 @@ impl TestApi {
                 connection_string: args.database_url().to_owned(),
                 preview_features,
                 shadow_database_connection_string: args.shadow_database_url().map(String::from),
+                application_name: None,
             };
             let mut conn = SqlSchemaConnector::new_mysql();
             conn.set_params(params).unwrap();
-            tok(conn.reset(false, None)).unwrap();
+            tok(conn.reset(false, None, &Default::default())).unwrap();
 
             (
                 tok(Quaint::new(args.database_url())).unwrap(),
@@ -193,6 +194,7 @@ impl TestApi {
             connection_string,
             preview_features: self.preview_features,
             shadow_database_connection_string,
+            application_name: None,
         };
 
         let mut connector = match &connection_info {
@@ -0,0 +1,36 @@
+use psl::parser_database::SourceFile;
+use schema_core::schema_connector::{DiffTarget, SchemaConnector};
+use sql_migration_tests::test_api::*;
+
+#[test_connector(tags(Postgres))]
+fn apply_migration_with_progress_reports_one_call_per_statement(mut api: TestApi) {
+    let dm = api.datamodel_with_provider(
+        r#"
+            model A {
+                id Int @id
+            }
+
+            model B {
+                id Int @id
+            }
+        "#,
+    );
+
+    let from = api.connector.empty_database_schema();
+    let to = tok(api.connector.database_schema_from_diff_target(
+        DiffTarget::Datamodel(vec![("schema.prisma".to_owned(), SourceFile::from(&dm))]),
+        None,
+        None,
+    ))
+    .unwrap();
+    let migration = api.connector.diff(from, to);
+
+    let mut progress = Vec::new();
+    let executed_steps = tok(api
+        .connector
+        .apply_migration_with_progress(&migration, &mut |completed, total| progress.push((completed, total))))
+    .unwrap();
+
+    assert_eq!(executed_steps, 2);
+    assert_eq!(progress, vec![(1, 2), (2, 2)]);
+}
@@ -17,7 +17,10 @@ fn adding_an_id_field_of_type_int_with_autoincrement_works(api: TestApi) {
     api.assert_schema().assert_table("Test", |t| {
         t.assert_column("myId", |c| {
             if api.is_postgres() {
-                c.assert_default_kind(Some(DefaultKind::Sequence("Test_myId_seq".into())))
+                c.assert_default_kind(Some(DefaultKind::Sequence {
+                    name: "Test_myId_seq".into(),
+                    r#virtual: false,
+                }))
             } else {
                 c.assert_auto_increments()
             }
@@ -355,6 +358,31 @@ fn reordering_and_altering_models_at_the_same_time_works(api: TestApi) {
     api.schema_push_w_datasource(dm2).send().assert_green();
 }
 
+#[test_connector(tags(Postgres))]
+fn reordering_scalar_fields_produces_no_migration_steps(api: TestApi) {
+    let dm1 = r#"
+        model Test {
+            id   Int    @id
+            a    String
+            b    String
+            c    String
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm1).send().assert_green();
+
+    let dm2 = r#"
+        model Test {
+            id   Int    @id
+            c    String
+            a    String
+            b    String
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm2).send().assert_green().assert_no_steps();
+}
+
 #[test_connector(tags(Sqlite))]
 fn switching_databases_must_work(api: TestApi) {
     let dm1 = r#"
@@ -1083,6 +1083,29 @@ fn autoincrement_is_idempotent(api: TestApi) {
     api.schema_push_w_datasource(dm1).send().assert_no_steps();
 }
 
+#[test_connector(tags(CockroachDb))]
+fn sequence_ownership_is_stable_across_pushes(api: TestApi) {
+    // The sequence behind an identity column is `OWNED BY` that column from the moment it's
+    // created. Re-describing the schema and diffing against itself should see that ownership is
+    // unchanged and emit no migration steps — a regression test for the class of bug where
+    // ownership wasn't captured by the describer at all, making every subsequent diff think it
+    // needed to be re-established.
+    let dm = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(minValue: 10, maxValue: 39, cache: 4, increment: 3, start: 12))
+        }
+    "#;
+
+    api.schema_push(dm).send().assert_green().assert_has_executed_steps();
+    api.schema_push(dm).send().assert_green().assert_no_steps();
+    api.schema_push(dm).send().assert_green().assert_no_steps();
+}
+
 #[test_connector(tags(CockroachDb))]
 fn alter_sequence_to_default(api: TestApi) {
     let schema1 = r#"
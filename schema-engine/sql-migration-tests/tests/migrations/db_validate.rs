@@ -0,0 +1,53 @@
+use quaint::{prelude::Queryable, single::Quaint};
+use sql_migration_tests::test_api::*;
+use sql_migration_tests::*;
+
+#[test]
+fn db_validate_valid_script_succeeds_without_persisting() {
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let url = format!("file:{}/db1.sqlite", tmpdir.path().to_string_lossy());
+    let script = r#"CREATE TABLE "dogs" ( id INTEGER PRIMARY KEY, name TEXT );"#;
+
+    let generic_api = schema_core::schema_api(None, None).unwrap();
+    tok(generic_api.db_validate(DbValidateParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer { url: url.clone() }),
+        script: script.to_owned(),
+    }))
+    .unwrap();
+
+    let q = tok(Quaint::new(&url)).unwrap();
+    let result = tok(q.query_raw(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'dogs';",
+        &[],
+    ))
+    .unwrap();
+
+    assert!(result.into_iter().next().is_none());
+}
+
+#[test_connector(tags(Mysql))]
+fn db_validate_invalid_script_errors_without_persisting(api: TestApi) {
+    let script = r#"
+        -- wrong quotes
+        CREATE TABLE "dogs" ( id INTEGER AUTO_INCREMENT PRIMARY KEY, name TEXT );
+    "#;
+
+    let generic_api = schema_core::schema_api(None, None).unwrap();
+    let result = tok(generic_api.db_validate(DbValidateParams {
+        datasource_type: DbExecuteDatasourceType::Url(UrlContainer {
+            url: api.connection_string().to_owned(),
+        }),
+        script: script.to_owned(),
+    }));
+
+    assert!(result.is_err());
+
+    let q = tok(Quaint::new(api.connection_string())).unwrap();
+    let result = tok(q.query_raw(
+        "SELECT table_name FROM information_schema.tables WHERE table_name = 'dogs';",
+        &[],
+    ))
+    .unwrap();
+
+    assert!(result.into_iter().next().is_none());
+}
@@ -202,6 +202,23 @@ fn default_dbgenerated_should_work_cockroach(api: TestApi) {
     });
 }
 
+// Postgres re-serializes the literals inside a function call with an explicit cast
+// (`concat('foo'::text, '-'::text, 'bar'::text)`), which must not be seen as a diff against the
+// `dbgenerated(...)` value carried over from the Prisma schema on the next push.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn dbgenerated_function_call_with_arguments_round_trips(api: TestApi) {
+    let dm = r#"
+        model A {
+            id   String @id
+            name String? @default(dbgenerated("concat('foo', '-', 'bar')"))
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.schema_push_w_datasource(dm).send().assert_green().assert_no_steps();
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn uuid_default(api: TestApi) {
     let dm = r#"
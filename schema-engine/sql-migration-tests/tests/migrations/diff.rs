@@ -702,6 +702,337 @@ fn from_url_to_url(mut api: TestApi) {
     expected_printed_messages.assert_debug_eq(&host.printed_messages.lock().unwrap());
 }
 
+#[test_connector(tags(Sqlite))]
+fn without_rowid_tables_produce_no_drift(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    let setup = "CREATE TABLE a ( id INTEGER PRIMARY KEY, val INTEGER NOT NULL ) WITHOUT ROWID;";
+
+    tok(async {
+        let q = Quaint::new(api.connection_string()).await.unwrap();
+        q.raw_cmd(setup).await.unwrap();
+    });
+
+    let url = api.connection_string().to_owned();
+
+    let input = DiffParams {
+        exit_code: None,
+        from: DiffTarget::Url(UrlContainer { url: url.clone() }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::Url(UrlContainer { url }),
+    };
+
+    api.diff(input).unwrap();
+
+    let expected_printed_messages = expect![[r#"
+        [
+            "-- This is an empty migration.",
+        ]
+    "#]];
+    expected_printed_messages.assert_debug_eq(&host.printed_messages.lock().unwrap());
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn index_fillfactor_produces_no_drift(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    let setup = "CREATE TABLE a ( id INTEGER PRIMARY KEY, val INTEGER NOT NULL ); \
+        CREATE INDEX a_val_idx ON a (val) WITH (fillfactor = 70);";
+
+    tok(async {
+        let q = Quaint::new(api.connection_string()).await.unwrap();
+        q.raw_cmd(setup).await.unwrap();
+    });
+
+    let url = api.connection_string().to_owned();
+
+    let input = DiffParams {
+        exit_code: None,
+        from: DiffTarget::Url(UrlContainer { url: url.clone() }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::Url(UrlContainer { url }),
+    };
+
+    api.diff(input).unwrap();
+
+    let expected_printed_messages = expect![[r#"
+        [
+            "-- This is an empty migration.",
+        ]
+    "#]];
+    expected_printed_messages.assert_debug_eq(&host.printed_messages.lock().unwrap());
+}
+
+// Procedures cannot be expressed in a Prisma schema, so this diffs two live databases directly
+// rather than a schema and a database, unlike most other tests in this file.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn changed_function_body_produces_a_replace_step(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    api.raw_cmd("DROP DATABASE IF EXISTS diffproceduretestdb");
+    api.raw_cmd("CREATE DATABASE diffproceduretestdb");
+
+    let mut other_db_url: url::Url = api.connection_string().parse().unwrap();
+    other_db_url.set_path("diffproceduretestdb");
+    let other_db_url = other_db_url.to_string();
+
+    tok(async {
+        let from_db = Quaint::new(api.connection_string()).await.unwrap();
+        from_db
+            .raw_cmd("CREATE FUNCTION get_answer() RETURNS INTEGER AS $$ BEGIN RETURN 41; END; $$ LANGUAGE plpgsql;")
+            .await
+            .unwrap();
+
+        let to_db = Quaint::new(&other_db_url).await.unwrap();
+        to_db
+            .raw_cmd("CREATE FUNCTION get_answer() RETURNS INTEGER AS $$ BEGIN RETURN 42; END; $$ LANGUAGE plpgsql;")
+            .await
+            .unwrap();
+    });
+
+    let input = DiffParams {
+        exit_code: None,
+        from: DiffTarget::Url(UrlContainer {
+            url: api.connection_string().to_owned(),
+        }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::Url(UrlContainer { url: other_db_url }),
+    };
+
+    api.diff(input).unwrap();
+
+    let printed_messages = host.printed_messages.lock().unwrap();
+    assert_eq!(printed_messages.len(), 1);
+    assert!(printed_messages[0].contains("CREATE OR REPLACE FUNCTION"));
+    assert!(printed_messages[0].contains("RETURN 42"));
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn unlogged_tables_produce_no_drift(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    let setup = "CREATE UNLOGGED TABLE a ( id INTEGER PRIMARY KEY, val INTEGER NOT NULL );";
+
+    tok(async {
+        let q = Quaint::new(api.connection_string()).await.unwrap();
+        q.raw_cmd(setup).await.unwrap();
+    });
+
+    let url = api.connection_string().to_owned();
+
+    let input = DiffParams {
+        exit_code: None,
+        from: DiffTarget::Url(UrlContainer { url: url.clone() }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::Url(UrlContainer { url }),
+    };
+
+    api.diff(input).unwrap();
+
+    let expected_printed_messages = expect![[r#"
+        [
+            "-- This is an empty migration.",
+        ]
+    "#]];
+    expected_printed_messages.assert_debug_eq(&host.printed_messages.lock().unwrap());
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn create_index_concurrently_is_emitted_when_requested(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let from_schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://example.com/test?createIndexConcurrently=true"
+        }
+
+        model TestModel {
+            id   Int @id @default(autoincrement())
+            name String
+        }
+    "#;
+
+    let to_schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://example.com/test?createIndexConcurrently=true"
+        }
+
+        model TestModel {
+            id   Int    @id @default(autoincrement())
+            name String @unique
+        }
+    "#;
+
+    let from_file = write_file_to_tmp(from_schema, &tempdir, "from");
+    let to_file = write_file_to_tmp(to_schema, &tempdir, "to");
+
+    api.diff(DiffParams {
+        exit_code: None,
+        from: DiffTarget::SchemaDatamodel(SchemasContainer {
+            files: vec![SchemaContainer {
+                path: from_file.to_string_lossy().into_owned(),
+                content: from_schema.to_string(),
+            }],
+        }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::SchemaDatamodel(SchemasContainer {
+            files: vec![SchemaContainer {
+                path: to_file.to_string_lossy().into_owned(),
+                content: to_schema.to_string(),
+            }],
+        }),
+    })
+    .unwrap();
+
+    let printed_messages = host.printed_messages.lock().unwrap();
+    let script = &printed_messages[0];
+
+    assert!(script.contains("CREATE UNIQUE INDEX CONCURRENTLY"), "{script}");
+    assert!(!script.contains("BEGIN"), "{script}");
+    assert!(!script.contains("COMMIT"), "{script}");
+}
+
+// CockroachDB's shadow database migration replay wraps applied scripts in `BEGIN;...COMMIT;`
+// (see flavour/postgres/native/shadow_db.rs), and `CREATE INDEX CONCURRENTLY` cannot run inside
+// a transaction block, so `createIndexConcurrently` must be ignored on this connector.
+#[test_connector(tags(CockroachDb))]
+fn create_index_concurrently_is_ignored_on_cockroachdb(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let from_schema = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = "postgresql://example.com/test?createIndexConcurrently=true"
+        }
+
+        model TestModel {
+            id   Int @id @default(autoincrement())
+            name String
+        }
+    "#;
+
+    let to_schema = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = "postgresql://example.com/test?createIndexConcurrently=true"
+        }
+
+        model TestModel {
+            id   Int    @id @default(autoincrement())
+            name String @unique
+        }
+    "#;
+
+    let from_file = write_file_to_tmp(from_schema, &tempdir, "from");
+    let to_file = write_file_to_tmp(to_schema, &tempdir, "to");
+
+    api.diff(DiffParams {
+        exit_code: None,
+        from: DiffTarget::SchemaDatamodel(SchemasContainer {
+            files: vec![SchemaContainer {
+                path: from_file.to_string_lossy().into_owned(),
+                content: from_schema.to_string(),
+            }],
+        }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::SchemaDatamodel(SchemasContainer {
+            files: vec![SchemaContainer {
+                path: to_file.to_string_lossy().into_owned(),
+                content: to_schema.to_string(),
+            }],
+        }),
+    })
+    .unwrap();
+
+    let printed_messages = host.printed_messages.lock().unwrap();
+    let script = &printed_messages[0];
+
+    assert!(!script.contains("CONCURRENTLY"), "{script}");
+}
+
+#[test_connector(tags(Mysql))]
+fn column_charset_and_collation_produce_no_drift(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    let setup = "CREATE TABLE a ( id INTEGER PRIMARY KEY, val VARCHAR(191) CHARACTER SET latin1 COLLATE latin1_bin NOT NULL );";
+
+    tok(async {
+        let q = Quaint::new(api.connection_string()).await.unwrap();
+        q.raw_cmd(setup).await.unwrap();
+    });
+
+    let url = api.connection_string().to_owned();
+
+    let input = DiffParams {
+        exit_code: None,
+        from: DiffTarget::Url(UrlContainer { url: url.clone() }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::Url(UrlContainer { url }),
+    };
+
+    api.diff(input).unwrap();
+
+    let expected_printed_messages = expect![[r#"
+        [
+            "-- This is an empty migration.",
+        ]
+    "#]];
+    expected_printed_messages.assert_debug_eq(&host.printed_messages.lock().unwrap());
+}
+
+#[test_connector(tags(Mysql))]
+fn spatial_index_produces_no_drift(api: TestApi) {
+    let host = Arc::new(TestConnectorHost::default());
+    api.connector.set_host(host.clone());
+
+    let setup =
+        "CREATE TABLE a ( id INTEGER PRIMARY KEY, location POINT NOT NULL, SPATIAL INDEX location_idx (location) );";
+
+    tok(async {
+        let q = Quaint::new(api.connection_string()).await.unwrap();
+        q.raw_cmd(setup).await.unwrap();
+    });
+
+    let url = api.connection_string().to_owned();
+
+    let input = DiffParams {
+        exit_code: None,
+        from: DiffTarget::Url(UrlContainer { url: url.clone() }),
+        script: true,
+        shadow_database_url: None,
+        to: DiffTarget::Url(UrlContainer { url }),
+    };
+
+    api.diff(input).unwrap();
+
+    let expected_printed_messages = expect![[r#"
+        [
+            "-- This is an empty migration.",
+        ]
+    "#]];
+    expected_printed_messages.assert_debug_eq(&host.printed_messages.lock().unwrap());
+}
+
 #[test]
 fn diffing_mongo_schemas_to_script_returns_a_nice_error() {
     let tempdir = tempfile::tempdir().unwrap();
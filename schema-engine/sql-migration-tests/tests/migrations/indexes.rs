@@ -331,6 +331,57 @@ fn index_renaming_must_work_when_renaming_to_custom(api: TestApi) {
     });
 }
 
+#[test_connector(exclude(Vitess))]
+fn name_only_index_change_renames_on_capable_connectors(api: TestApi) {
+    let dm1 = api.datamodel_with_provider(
+        r#"
+        model A {
+            id Int @id
+            a  Int
+
+            @@index([a], map: "before")
+        }
+    "#,
+    );
+
+    let dir = api.create_migrations_directory();
+    api.create_migration("init", &dm1, &dir).send_sync();
+    api.apply_migrations(&dir).send_sync();
+
+    let dm2 = api.datamodel_with_provider(
+        r#"
+        model A {
+            id Int @id
+            a  Int
+
+            @@index([a], map: "after")
+        }
+    "#,
+    );
+
+    let can_rename = !api.is_sqlite() && !api.is_mysql_5_6() && !api.is_mariadb();
+
+    api.create_migration("rename", &dm2, &dir)
+        .send_sync()
+        .assert_migration("rename", move |migration| {
+            migration.assert_contents_matches(|script| {
+                let script = script.to_uppercase();
+
+                if can_rename {
+                    assert!(
+                        !script.contains("CREATE INDEX") && !script.contains("CREATE UNIQUE INDEX"),
+                        "expected a rename-only migration, got:\n{script}"
+                    );
+                } else {
+                    assert!(
+                        script.contains("CREATE INDEX") || script.contains("CREATE TABLE"),
+                        "expected a drop+create (or table redefine) migration, got:\n{script}"
+                    );
+                }
+            })
+        });
+}
+
 #[test_connector]
 fn index_updates_with_rename_must_work(api: TestApi) {
     let dm1 = r#"
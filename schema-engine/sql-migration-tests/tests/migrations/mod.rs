@@ -1,4 +1,5 @@
 mod advisory_locking;
+mod apply_migration_progress;
 mod basic;
 mod cockroachdb;
 mod db_execute;
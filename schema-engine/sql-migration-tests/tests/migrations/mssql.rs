@@ -1,9 +1,14 @@
 use psl::parser_database::SourceFile;
-use schema_core::schema_connector::DiffTarget;
+use schema_core::schema_connector::{DiffTarget, SchemaConnector};
 use sql_migration_tests::test_api::*;
 
 mod multi_schema;
 
+#[test_connector(tags(Mssql))]
+fn ddl_is_transactional(api: TestApi) {
+    assert!(api.connector.ddl_is_transactional());
+}
+
 #[test_connector(tags(Mssql))]
 fn reset_clears_udts(api: TestApi) {
     let schema = api.schema_name();
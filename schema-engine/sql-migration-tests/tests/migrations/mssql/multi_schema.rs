@@ -1169,6 +1169,7 @@ async fn migration_with_shadow_database() {
             connection_string: conn_str,
             preview_features: PreviewFeature::MultiSchema.into(),
             shadow_database_connection_string: Some(shadow_str),
+            application_name: None,
         };
 
         (params, datasource)
@@ -1183,7 +1184,7 @@ async fn migration_with_shadow_database() {
         let _ = conn.raw_cmd("DROP DATABASE shadow").await;
 
         conn.raw_cmd("CREATE DATABASE shadow").await.unwrap();
-        conn.reset(true, namespaces.clone()).await.unwrap();
+        conn.reset(true, namespaces.clone(), &Default::default()).await.unwrap();
 
         let _ = conn.raw_cmd("DROP SCHEMA one").await;
         let _ = conn.raw_cmd("DROP SCHEMA two").await;
@@ -2,10 +2,18 @@
 
 use indoc::indoc;
 use psl::SourceFile;
-use schema_core::{json_rpc::types::*, schema_connector};
+use schema_core::{
+    json_rpc::types::*,
+    schema_connector::{self, SchemaConnector},
+};
 use sql_migration_tests::test_api::*;
 use std::fmt::Write as _;
 
+#[test_connector(tags(Mysql))]
+fn ddl_is_not_transactional(api: TestApi) {
+    assert!(!api.connector.ddl_is_transactional());
+}
+
 // We need to test this specifically for mysql, because foreign keys are indexes, and they are
 // inferred as both foreign key and index by the sql-schema-describer. We do not want to
 // create/delete a second index.
@@ -4,8 +4,12 @@ mod multi_schema;
 
 use psl::parser_database::SourceFile;
 use quaint::Value;
-use schema_core::{json_rpc::types::SchemasContainer, schema_connector::DiffTarget};
+use schema_core::{
+    json_rpc::types::SchemasContainer,
+    schema_connector::{ConnectorParams, DiffTarget, SchemaConnector},
+};
 use sql_migration_tests::test_api::*;
+use sql_schema_connector::SqlSchemaConnector;
 use std::fmt::Write;
 
 #[test_connector(tags(Postgres))]
@@ -502,6 +506,29 @@ fn connecting_to_a_postgres_database_with_the_cockroach_connector_fails(_api: Te
     expected_error.assert_eq(&err);
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn application_name_is_set_on_connect(api: TestApi) {
+    let params = ConnectorParams {
+        connection_string: api.connection_string().to_owned(),
+        preview_features: Default::default(),
+        shadow_database_connection_string: None,
+        application_name: Some("schema-engine-test".to_owned()),
+    };
+
+    let mut connector = SqlSchemaConnector::new_postgres();
+    connector.set_params(params).unwrap();
+    tok(connector.ensure_connection_validity()).unwrap();
+
+    let result = tok(connector.query_raw(
+        "SELECT application_name FROM pg_stat_activity WHERE pid = pg_backend_pid()",
+        &[],
+    ))
+    .unwrap();
+
+    let row = result.into_iter().next().unwrap();
+    assert_eq!(row[0].to_string().unwrap(), "schema-engine-test");
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn scalar_list_defaults_work(api: TestApi) {
     let schema = r#"
@@ -773,3 +800,8 @@ fn dbgenerated_on_generated_unsupported_columns_is_idempotent(api: TestApi) {
 
     api.schema_push(schema).send().assert_green().assert_no_steps();
 }
+
+#[test_connector(tags(Postgres))]
+fn ddl_is_transactional(api: TestApi) {
+    assert!(api.connector.ddl_is_transactional());
+}
@@ -1402,6 +1402,7 @@ async fn migration_with_shadow_database() {
             connection_string: dbg!(conn_str),
             preview_features: PreviewFeature::MultiSchema.into(),
             shadow_database_connection_string: dbg!(Some(shadow_str)),
+            application_name: None,
         };
 
         (params, datasource)
@@ -1416,7 +1417,7 @@ async fn migration_with_shadow_database() {
         let _ = conn.raw_cmd("DROP DATABASE shadow").await;
 
         conn.raw_cmd("CREATE DATABASE shadow").await.unwrap();
-        conn.reset(false, namespaces.clone()).await.unwrap();
+        conn.reset(false, namespaces.clone(), &Default::default()).await.unwrap();
 
         let _ = conn.raw_cmd("DROP SCHEMA one CASCADE").await;
         let _ = conn.raw_cmd("DROP SCHEMA two CASCADE").await;
@@ -37,6 +37,7 @@ fn parses_doc_complex_pg(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -86,6 +87,7 @@ fn parses_doc_complex_mysql(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -138,6 +140,7 @@ fn parses_doc_no_position(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -181,6 +184,7 @@ fn parses_doc_no_alias(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -221,6 +225,7 @@ fn parses_doc_enum_name(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
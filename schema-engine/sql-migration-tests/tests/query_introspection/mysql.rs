@@ -62,6 +62,7 @@ fn insert_mysql(api: TestApi) {
                 },
             ],
             result_columns: [],
+            returns_single_row: false,
         }
     "#]];
 
@@ -122,6 +123,7 @@ fn select_mysql(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -182,6 +184,7 @@ fn select_nullable_mysql(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -212,6 +215,7 @@ fn empty_result(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -237,6 +241,7 @@ fn unnamed_expr(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -262,6 +267,7 @@ fn named_expr(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -287,6 +293,7 @@ fn mixed_named_expr(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -312,6 +319,7 @@ fn mixed_unnamed_expr(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -337,6 +345,7 @@ fn mixed_expr_cast(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
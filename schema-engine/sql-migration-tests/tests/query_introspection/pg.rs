@@ -100,6 +100,7 @@ mod common {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -199,6 +200,7 @@ mod common {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -229,6 +231,7 @@ mod common {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -237,6 +240,58 @@ mod common {
             .expect_result(expected)
     }
 
+    #[test_connector(tags(Postgres))]
+    fn limit_1_is_a_single_row_query(api: TestApi) {
+        api.schema_push(SIMPLE_SCHEMA).send().assert_green();
+
+        let expected = expect![[r#"
+            IntrospectSqlQueryOutput {
+                name: "test_1",
+                source: "SELECT int FROM model LIMIT 1;",
+                documentation: None,
+                parameters: [],
+                result_columns: [
+                    IntrospectSqlQueryColumnOutput {
+                        name: "int",
+                        typ: "int",
+                        nullable: false,
+                    },
+                ],
+                returns_single_row: true,
+            }
+        "#]];
+
+        api.introspect_sql("test_1", "SELECT int FROM model LIMIT 1;")
+            .send_sync()
+            .expect_result(expected)
+    }
+
+    #[test_connector(tags(Postgres))]
+    fn ungrouped_aggregate_is_a_single_row_query(api: TestApi) {
+        api.schema_push(SIMPLE_SCHEMA).send().assert_green();
+
+        let expected = expect![[r#"
+            IntrospectSqlQueryOutput {
+                name: "test_1",
+                source: "SELECT COUNT(*) FROM model;",
+                documentation: None,
+                parameters: [],
+                result_columns: [
+                    IntrospectSqlQueryColumnOutput {
+                        name: "count",
+                        typ: "bigint",
+                        nullable: false,
+                    },
+                ],
+                returns_single_row: true,
+            }
+        "#]];
+
+        api.introspect_sql("test_1", "SELECT COUNT(*) FROM model;")
+            .send_sync()
+            .expect_result(expected)
+    }
+
     #[test_connector(tags(Postgres, CockroachDb))]
     fn custom_enum(api: TestApi) {
         api.schema_push(ENUM_SCHEMA).send().assert_green();
@@ -272,6 +327,7 @@ mod common {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -311,6 +367,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -336,6 +393,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -384,6 +442,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -419,6 +478,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -462,6 +522,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -500,6 +561,7 @@ mod postgres {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -524,6 +586,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -554,6 +617,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -583,6 +647,7 @@ mod postgres {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -612,6 +677,7 @@ mod postgres {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -702,6 +768,7 @@ mod crdb {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -727,6 +794,7 @@ mod crdb {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -775,6 +843,7 @@ mod crdb {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -810,6 +879,7 @@ mod crdb {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -853,6 +923,7 @@ mod crdb {
                         nullable: true,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -891,6 +962,7 @@ mod crdb {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -915,6 +987,7 @@ mod crdb {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -945,6 +1018,7 @@ mod crdb {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -974,6 +1048,7 @@ mod crdb {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
@@ -1003,6 +1078,7 @@ mod crdb {
                         nullable: false,
                     },
                 ],
+                returns_single_row: false,
             }
         "#]];
 
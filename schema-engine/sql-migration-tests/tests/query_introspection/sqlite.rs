@@ -61,6 +61,7 @@ fn insert_sqlite(api: TestApi) {
                 },
             ],
             result_columns: [],
+            returns_single_row: false,
         }
     "#]];
 
@@ -121,6 +122,7 @@ fn select_sqlite(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -181,6 +183,7 @@ fn select_nullable_sqlite(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -211,6 +214,7 @@ fn empty_result(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -236,6 +240,7 @@ fn unnamed_expr_int(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -264,6 +269,7 @@ fn named_expr_int(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -292,6 +298,7 @@ fn named_expr_int_optional(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -320,6 +327,7 @@ fn mixed_named_expr_int(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -345,6 +353,7 @@ fn mixed_unnamed_expr_int(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -370,6 +379,7 @@ fn mixed_expr_cast_int(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -395,6 +405,7 @@ fn unnamed_expr_string(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -428,6 +439,7 @@ fn unnamed_expr_bool(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -466,6 +478,7 @@ fn unnamed_expr_real(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -498,6 +511,7 @@ fn unnamed_expr_blob(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -526,6 +540,7 @@ fn unnamed_expr_date(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -554,6 +569,7 @@ fn unnamed_expr_time(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -582,6 +598,7 @@ fn unnamed_expr_datetime(api: TestApi) {
                     nullable: true,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -610,6 +627,7 @@ fn subquery(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
@@ -640,6 +658,7 @@ fn left_join(api: TestApi) {
                     nullable: false,
                 },
             ],
+            returns_single_row: false,
         }
     "#]];
 
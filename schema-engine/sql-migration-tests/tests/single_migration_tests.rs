@@ -82,10 +82,11 @@ fn run_single_migration_test(test_file_path: &str, test_function_name: &'static
             connection_string: test_api_args.database_url().to_owned(),
             preview_features: Default::default(),
             shadow_database_connection_string: None,
+            application_name: None,
         };
         let mut conn = SqlSchemaConnector::new_mysql();
         conn.set_params(params).unwrap();
-        tok(conn.reset(false, None)).unwrap();
+        tok(conn.reset(false, None, &Default::default())).unwrap();
         test_api_args.database_url().to_owned()
     } else if tags.contains(Tags::Mysql) {
         let (_, connection_string) = tok(test_api_args.create_mysql_database());
@@ -14,6 +14,7 @@ pub trait Getter {
     fn get_bool(&self, name: &str) -> Option<bool>;
     fn get_u32(&self, name: &str) -> Option<u32>;
     fn get_i64(&self, name: &str) -> Option<i64>;
+    fn get_f64(&self, name: &str) -> Option<f64>;
 }
 
 impl Getter for ResultRow {
@@ -91,4 +92,8 @@ impl Getter for ResultRow {
     fn get_i64(&self, name: &str) -> Option<i64> {
         self.get(name).and_then(|x| x.as_integer())
     }
+
+    fn get_f64(&self, name: &str) -> Option<f64> {
+        self.get(name).and_then(|x| x.as_f64())
+    }
 }
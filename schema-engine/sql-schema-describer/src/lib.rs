@@ -78,8 +78,11 @@ pub struct SqlSchema {
     indexes: Vec<Index>,
     /// All columns of indexes.
     index_columns: Vec<IndexColumn>,
-    /// Check constraints for every table.
-    check_constraints: Vec<(TableId, String)>,
+    /// Check constraints for every table, attributed to the table as a whole rather than to any
+    /// single column, together with the columns referenced by each constraint's expression (may
+    /// be empty, e.g. for a constraint on a constant expression, and may list more than one
+    /// column for a constraint like `CHECK (start < end)`).
+    check_constraints: Vec<(TableId, String, Vec<TableColumnId>)>,
     /// The schema's views,
     views: Vec<View>,
     /// The schema's columns that are in views.
@@ -139,11 +142,17 @@ impl SqlSchema {
 
     /// Try to find an enum by name.
     pub fn find_enum(&self, name: &str, namespace: Option<&str>) -> Option<EnumId> {
+        self.find_enum_by(|n| n == name, namespace)
+    }
+
+    /// Try to find an enum whose name matches the given predicate. This is useful for
+    /// case-insensitive matching, e.g. with `|n| n.eq_ignore_ascii_case(name)`.
+    pub fn find_enum_by(&self, predicate: impl Fn(&str) -> bool, namespace: Option<&str>) -> Option<EnumId> {
         let ns_id = namespace.and_then(|ns| self.get_namespace(ns));
 
         self.enums
             .iter()
-            .position(|e| e.name == name && ns_id.map(|id| id == e.namespace_id).unwrap_or(true))
+            .position(|e| predicate(&e.name) && ns_id.map(|id| id == e.namespace_id).unwrap_or(true))
             .map(|i| EnumId(i as u32))
     }
 
@@ -156,21 +165,33 @@ impl SqlSchema {
 
     /// Try to find a table by name.
     pub fn find_table(&self, name: &str, namespace: Option<&str>) -> Option<TableId> {
+        self.find_table_by(|n| n == name, namespace)
+    }
+
+    /// Try to find a table whose name matches the given predicate. This is useful for
+    /// case-insensitive matching, e.g. with `|n| n.eq_ignore_ascii_case(name)`.
+    pub fn find_table_by(&self, predicate: impl Fn(&str) -> bool, namespace: Option<&str>) -> Option<TableId> {
         let ns_id = namespace.and_then(|ns| self.get_namespace(ns));
 
         self.tables
             .iter()
-            .position(|t| t.name == name && ns_id.map(|id| id == t.namespace_id).unwrap_or(true))
+            .position(|t| predicate(&t.name) && ns_id.map(|id| id == t.namespace_id).unwrap_or(true))
             .map(|i| TableId(i as u32))
     }
 
     /// Try to find a table by name.
     pub fn find_view(&self, name: &str, namespace: Option<&str>) -> Option<ViewId> {
+        self.find_view_by(|n| n == name, namespace)
+    }
+
+    /// Try to find a view whose name matches the given predicate. This is useful for
+    /// case-insensitive matching, e.g. with `|n| n.eq_ignore_ascii_case(name)`.
+    pub fn find_view_by(&self, predicate: impl Fn(&str) -> bool, namespace: Option<&str>) -> Option<ViewId> {
         let ns_id = namespace.and_then(|ns| self.get_namespace(ns));
 
         self.views
             .iter()
-            .position(|t| t.name == name && ns_id.map(|id| id == t.namespace_id).unwrap_or(true))
+            .position(|t| predicate(&t.name) && ns_id.map(|id| id == t.namespace_id).unwrap_or(true))
             .map(|i| ViewId(i as u32))
     }
 
@@ -179,6 +200,11 @@ impl SqlSchema {
         self.procedures.iter().find(|x| x.name == name)
     }
 
+    /// All the stored procedures in the schema.
+    pub fn procedures(&self) -> &[Procedure] {
+        &self.procedures
+    }
+
     /// Get a user defined type by name.
     pub fn get_user_defined_type(&self, name: &str) -> Option<&UserDefinedType> {
         self.user_defined_types.iter().find(|x| x.name == name)
@@ -251,6 +277,17 @@ impl SqlSchema {
         id
     }
 
+    /// Add a spatial index to the schema.
+    pub fn push_spatial_index(&mut self, table_id: TableId, index_name: String) -> IndexId {
+        let id = IndexId(self.indexes.len() as u32);
+        self.indexes.push(Index {
+            table_id,
+            index_name,
+            tpe: IndexType::Spatial,
+        });
+        id
+    }
+
     /// Add an index to the schema.
     pub fn push_index(&mut self, table_id: TableId, index_name: String) -> IndexId {
         let id = IndexId(self.indexes.len() as u32);
@@ -482,10 +519,275 @@ impl SqlSchema {
         (0..self.namespaces.len()).map(|idx| self.walk(NamespaceId(idx as u32)))
     }
 
+    /// Traverse all indexes in the schema, across every table.
+    pub fn walk_indexes(&self) -> impl ExactSizeIterator<Item = IndexWalker<'_>> {
+        (0..self.indexes.len()).map(|idx| self.walk(IndexId(idx as u32)))
+    }
+
+    /// Traverse all indexes of the given type in the schema, across every table.
+    pub fn indexes_of_type(&self, r#type: IndexType) -> impl Iterator<Item = IndexWalker<'_>> {
+        self.walk_indexes().filter(move |index| index.index_type() == r#type)
+    }
+
+    /// Groups indexes that are exact duplicates of one another: same table, same [`IndexType`],
+    /// and the same columns in the same order, with the same sort order and length. Only groups
+    /// with more than one member are returned, so tooling can suggest dropping the extras.
+    pub fn duplicate_indexes(&self) -> Vec<Vec<IndexId>> {
+        #[derive(PartialEq)]
+        struct Signature {
+            table_id: TableId,
+            index_type: IndexType,
+            columns: Vec<(TableColumnId, Option<SQLSortOrder>, Option<u32>)>,
+        }
+
+        let mut groups: Vec<(Signature, Vec<IndexId>)> = Vec::new();
+
+        for index in self.walk_indexes() {
+            let signature = Signature {
+                table_id: index.table().id,
+                index_type: index.index_type(),
+                columns: index
+                    .columns()
+                    .map(|column| (column.as_column().id, column.sort_order(), column.length()))
+                    .collect(),
+            };
+
+            match groups.iter_mut().find(|(sig, _)| *sig == signature) {
+                Some((_, ids)) => ids.push(index.id),
+                None => groups.push((signature, vec![index.id])),
+            }
+        }
+
+        groups.into_iter().map(|(_, ids)| ids).filter(|ids| ids.len() > 1).collect()
+    }
+
+    /// Traverse all check constraints in the schema, across every table.
+    pub fn walk_check_constraints(&self) -> impl ExactSizeIterator<Item = CheckConstraintWalker<'_>> {
+        (0..self.check_constraints.len()).map(|idx| self.walk(CheckConstraintId(idx as u32)))
+    }
+
     /// No tables or enums in the catalog.
     pub fn is_empty(&self) -> bool {
         self.tables.is_empty() && self.enums.is_empty()
     }
+
+    /// Append `other`'s namespaces, tables, enums, views and everything else into `self`,
+    /// remapping all of `other`'s ids so they keep pointing at the right items in the merged
+    /// schema. This is meant for assembling a full schema out of per-namespace introspection
+    /// results that were fetched independently (e.g. concurrently).
+    ///
+    /// `self` and `other` are expected to describe disjoint sets of namespaces: this method does
+    /// not deduplicate namespaces, tables or any other item by name.
+    ///
+    /// Connector-specific data (see [`SqlSchema::downcast_connector_data`]) is not merged; the
+    /// data already present on `self`, if any, is left untouched.
+    pub fn merge(&mut self, other: SqlSchema) {
+        let namespace_offset = self.namespaces.len() as u32;
+        let table_offset = self.tables.len() as u32;
+        let enum_offset = self.enums.len() as u32;
+        let view_offset = self.views.len() as u32;
+        let table_column_offset = self.table_columns.len() as u32;
+        let view_column_offset = self.view_columns.len() as u32;
+        let foreign_key_offset = self.foreign_keys.len() as u32;
+        let index_offset = self.indexes.len() as u32;
+
+        self.namespaces.extend(other.namespaces);
+
+        self.tables.extend(other.tables.into_iter().map(|mut table| {
+            table.namespace_id = NamespaceId(table.namespace_id.0 + namespace_offset);
+            table
+        }));
+
+        self.enums.extend(other.enums.into_iter().map(|mut enm| {
+            enm.namespace_id = NamespaceId(enm.namespace_id.0 + namespace_offset);
+            enm
+        }));
+
+        self.enum_variants.extend(other.enum_variants.into_iter().map(|mut variant| {
+            variant.enum_id = EnumId(variant.enum_id.0 + enum_offset);
+            variant
+        }));
+
+        self.table_columns.extend(
+            other
+                .table_columns
+                .into_iter()
+                .map(|(table_id, column)| (TableId(table_id.0 + table_offset), column)),
+        );
+
+        self.foreign_keys.extend(other.foreign_keys.into_iter().map(|mut fk| {
+            fk.constrained_table = TableId(fk.constrained_table.0 + table_offset);
+            fk.referenced_table = TableId(fk.referenced_table.0 + table_offset);
+            fk
+        }));
+
+        self.table_default_values.extend(
+            other
+                .table_default_values
+                .into_iter()
+                .map(|(column_id, value)| (TableColumnId(column_id.0 + table_column_offset), value)),
+        );
+
+        self.view_default_values.extend(
+            other
+                .view_default_values
+                .into_iter()
+                .map(|(column_id, value)| (ViewColumnId(column_id.0 + view_column_offset), value)),
+        );
+
+        self.foreign_key_columns.extend(other.foreign_key_columns.into_iter().map(|mut fkc| {
+            fkc.foreign_key_id = ForeignKeyId(fkc.foreign_key_id.0 + foreign_key_offset);
+            fkc.constrained_column = TableColumnId(fkc.constrained_column.0 + table_column_offset);
+            fkc.referenced_column = TableColumnId(fkc.referenced_column.0 + table_column_offset);
+            fkc
+        }));
+
+        self.indexes.extend(other.indexes.into_iter().map(|mut index| {
+            index.table_id = TableId(index.table_id.0 + table_offset);
+            index
+        }));
+
+        self.index_columns.extend(other.index_columns.into_iter().map(|mut column| {
+            column.index_id = IndexId(column.index_id.0 + index_offset);
+            column.column_id = TableColumnId(column.column_id.0 + table_column_offset);
+            column
+        }));
+
+        self.check_constraints.extend(other.check_constraints.into_iter().map(|(table_id, expr, columns)| {
+            (
+                TableId(table_id.0 + table_offset),
+                expr,
+                columns
+                    .into_iter()
+                    .map(|column_id| TableColumnId(column_id.0 + table_column_offset))
+                    .collect(),
+            )
+        }));
+
+        self.views.extend(other.views.into_iter().map(|mut view| {
+            view.namespace_id = NamespaceId(view.namespace_id.0 + namespace_offset);
+            view
+        }));
+
+        self.view_columns.extend(
+            other
+                .view_columns
+                .into_iter()
+                .map(|(view_id, column)| (ViewId(view_id.0 + view_offset), column)),
+        );
+
+        self.procedures.extend(other.procedures.into_iter().map(|mut procedure| {
+            procedure.namespace_id = NamespaceId(procedure.namespace_id.0 + namespace_offset);
+            procedure
+        }));
+
+        self.user_defined_types.extend(other.user_defined_types.into_iter().map(|mut udt| {
+            udt.namespace_id = NamespaceId(udt.namespace_id.0 + namespace_offset);
+            udt
+        }));
+    }
+
+    /// Produce a deterministic JSON representation of the schema, with tables, columns, indexes,
+    /// foreign keys and enums emitted in canonical name order, and id-based cross references
+    /// resolved to names. Two schemas that are equivalent but were built up in different
+    /// insertion order produce identical output. This is meant for snapshot tests, not for
+    /// round-tripping a schema.
+    pub fn to_sorted_json(&self) -> serde_json::Value {
+        let mut namespaces: Vec<&str> = self.namespaces.iter().map(|ns| ns.as_str()).collect();
+        namespaces.sort_unstable();
+
+        let mut enums: Vec<EnumWalker<'_>> = self.enum_walkers().collect();
+        enums.sort_unstable_by_key(|e| (e.namespace().unwrap_or(""), e.name()));
+
+        let enums: Vec<serde_json::Value> = enums
+            .into_iter()
+            .map(|e| {
+                let mut variants: Vec<&str> = e.values().collect();
+                variants.sort_unstable();
+
+                serde_json::json!({
+                    "namespace": e.namespace(),
+                    "name": e.name(),
+                    "variants": variants,
+                })
+            })
+            .collect();
+
+        let mut tables: Vec<TableWalker<'_>> = self.table_walkers().collect();
+        tables.sort_unstable_by_key(|t| (t.namespace().unwrap_or(""), t.name()));
+
+        let tables: Vec<serde_json::Value> = tables
+            .into_iter()
+            .map(|table| {
+                let mut columns: Vec<TableColumnWalker<'_>> = table.columns().collect();
+                columns.sort_unstable_by_key(|c| c.name());
+
+                let columns: Vec<serde_json::Value> = columns
+                    .into_iter()
+                    .map(|c| {
+                        serde_json::json!({
+                            "name": c.name(),
+                            "type": format!("{:?}", c.column_type_family()),
+                            "arity": format!("{:?}", c.arity()),
+                        })
+                    })
+                    .collect();
+
+                let mut indexes: Vec<IndexWalker<'_>> = table.indexes().collect();
+                indexes.sort_unstable_by_key(|i| i.name().to_owned());
+
+                let indexes: Vec<serde_json::Value> = indexes
+                    .into_iter()
+                    .map(|idx| {
+                        let mut column_names: Vec<&str> = idx.column_names().collect();
+                        column_names.sort_unstable();
+
+                        serde_json::json!({
+                            "name": idx.name(),
+                            "type": format!("{:?}", idx.index_type()),
+                            "columns": column_names,
+                        })
+                    })
+                    .collect();
+
+                let mut foreign_keys: Vec<ForeignKeyWalker<'_>> = table.foreign_keys().collect();
+                foreign_keys.sort_unstable_by_key(|fk| {
+                    (
+                        fk.constrained_columns().map(|c| c.name().to_owned()).collect::<Vec<_>>(),
+                        fk.referenced_table_name().to_owned(),
+                    )
+                });
+
+                let foreign_keys: Vec<serde_json::Value> = foreign_keys
+                    .into_iter()
+                    .map(|fk| {
+                        let constrained_columns: Vec<&str> = fk.constrained_columns().map(|c| c.name()).collect();
+                        let referenced_columns: Vec<&str> = fk.referenced_columns().map(|c| c.name()).collect();
+
+                        serde_json::json!({
+                            "constrained_columns": constrained_columns,
+                            "referenced_table": fk.referenced_table_name(),
+                            "referenced_columns": referenced_columns,
+                        })
+                    })
+                    .collect();
+
+                serde_json::json!({
+                    "namespace": table.namespace(),
+                    "name": table.name(),
+                    "columns": columns,
+                    "indexes": indexes,
+                    "foreign_keys": foreign_keys,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "namespaces": namespaces,
+            "enums": enums,
+            "tables": tables,
+        })
+    }
 }
 
 #[enumflags2::bitflags]
@@ -495,6 +797,20 @@ pub enum TableProperties {
     IsPartition,
     HasSubclass,
     HasRowLevelSecurity,
+    /// The table was created with `WITHOUT ROWID` (SQLite only).
+    WithoutRowid,
+    /// The table is a foreign table (`CREATE FOREIGN TABLE`, Postgres only). Foreign tables are
+    /// backed by an external data source through a foreign data wrapper, so they should be
+    /// excluded from migrations.
+    IsForeignTable,
+    /// The table's single integer primary key column was declared with the explicit
+    /// `AUTOINCREMENT` keyword, as opposed to implicitly aliasing the rowid through a plain
+    /// `INTEGER PRIMARY KEY` column (SQLite only).
+    HasExplicitAutoincrement,
+    /// The table was created with `CREATE UNLOGGED TABLE` (Postgres only). Unlogged tables skip
+    /// the write-ahead log, which makes them faster but not crash-safe, and their contents are
+    /// truncated on crash recovery.
+    Unlogged,
 }
 
 /// A table found in a schema.
@@ -515,6 +831,8 @@ pub enum IndexType {
     Normal,
     /// Fulltext type.
     Fulltext,
+    /// Spatial type (MySQL's `SPATIAL INDEX`).
+    Spatial,
     /// The table's primary key
     PrimaryKey,
 }
@@ -632,7 +950,7 @@ impl ColumnType {
 }
 
 /// Enumeration of column type families.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
@@ -807,6 +1125,12 @@ pub struct View {
 pub struct DefaultValue {
     kind: DefaultKind,
     constraint_name: Option<String>,
+    /// Whether the default only applies when the inserted value is explicitly `NULL`, as opposed
+    /// to omitted entirely (Oracle's `DEFAULT ... ON NULL`). No connector this crate describes
+    /// supports the distinction today, so this is always `false` in practice; the field exists so
+    /// it isn't silently dropped once one does.
+    #[serde(default)]
+    on_null: bool,
 }
 
 /// A DefaultValue
@@ -817,7 +1141,14 @@ pub enum DefaultKind {
     /// An expression generating a current timestamp.
     Now,
     /// An expression generating a sequence.
-    Sequence(String),
+    Sequence {
+        /// The name of the sequence.
+        name: String,
+        /// Whether the sequence is a CockroachDB virtual sequence. Defaults to `false` for
+        /// backwards compatibility with schemas serialized before this field was added.
+        #[serde(default)]
+        r#virtual: bool,
+    },
     /// A unique row ID,
     UniqueRowid,
     /// An unrecognized Default Value
@@ -842,7 +1173,10 @@ impl DefaultValue {
     }
 
     pub fn sequence(val: impl ToString) -> Self {
-        Self::new(DefaultKind::Sequence(val.to_string()))
+        Self::new(DefaultKind::Sequence {
+            name: val.to_string(),
+            r#virtual: false,
+        })
     }
 
     pub fn kind(&self) -> &DefaultKind {
@@ -853,6 +1187,7 @@ impl DefaultValue {
         Self {
             kind,
             constraint_name: None,
+            on_null: false,
         }
     }
 
@@ -860,6 +1195,21 @@ impl DefaultValue {
         self.constraint_name = Some(name.to_string())
     }
 
+    /// Whether this default only applies to explicit `NULL` inserts (Oracle's `DEFAULT ... ON
+    /// NULL`). Always `false` on connectors that don't distinguish it.
+    pub fn on_null(&self) -> bool {
+        self.on_null
+    }
+
+    pub fn set_on_null(&mut self, on_null: bool) {
+        self.on_null = on_null
+    }
+
+    pub fn with_on_null(mut self, on_null: bool) -> Self {
+        self.on_null = on_null;
+        self
+    }
+
     pub(crate) fn as_value(&self) -> Option<&PrismaValue> {
         match self.kind {
             DefaultKind::Value(ref v) => Some(v),
@@ -870,7 +1220,7 @@ impl DefaultValue {
     #[cfg(test)]
     pub(crate) fn as_sequence(&self) -> Option<&str> {
         match self.kind {
-            DefaultKind::Sequence(ref name) => Some(name),
+            DefaultKind::Sequence { ref name, .. } => Some(name),
             _ => None,
         }
     }
@@ -918,6 +1268,57 @@ struct Precision {
 mod tests {
     use super::*;
 
+    #[test]
+    fn default_value_on_null_round_trips_and_defaults_to_ignored() {
+        let plain = DefaultValue::value(PrismaValue::Int(1));
+        assert!(!plain.on_null());
+
+        let on_null = DefaultValue::value(PrismaValue::Int(1)).with_on_null(true);
+        assert!(on_null.on_null());
+
+        let mut mutated = DefaultValue::value(PrismaValue::Int(1));
+        mutated.set_on_null(true);
+        assert!(mutated.on_null());
+        assert_eq!(mutated, on_null);
+
+        // Deserializing an older, pre-`on_null` serialized default (connectors that don't
+        // support the distinction never wrote the field) still works and reports `false`.
+        let mut legacy_json = serde_json::to_value(&plain).unwrap();
+        legacy_json.as_object_mut().unwrap().remove("on_null");
+        let legacy: DefaultValue = serde_json::from_value(legacy_json).unwrap();
+        assert!(!legacy.on_null());
+        assert_eq!(legacy, plain);
+    }
+
+    #[test]
+    fn enum_using_columns_returns_every_column_referencing_it_across_tables() {
+        fn column(family: ColumnTypeFamily) -> Column {
+            Column {
+                name: "color".to_owned(),
+                tpe: ColumnType::pure(family, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            }
+        }
+
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let color = schema.push_enum(ns, "Color".to_owned(), None);
+        let other = schema.push_enum(ns, "Other".to_owned(), None);
+
+        let cat = schema.push_table("Cat".to_owned(), ns, None);
+        let cat_color = schema.push_table_column(cat, column(ColumnTypeFamily::Enum(color)));
+
+        let dog = schema.push_table("Dog".to_owned(), ns, None);
+        let dog_color = schema.push_table_column(dog, column(ColumnTypeFamily::Enum(color)));
+        schema.push_table_column(dog, column(ColumnTypeFamily::Enum(other)));
+        schema.push_table_column(dog, column(ColumnTypeFamily::String));
+
+        let using: Vec<TableColumnId> = schema.walk(color).using_columns().map(|col| col.id).collect();
+
+        assert_eq!(using, vec![cat_color, dog_color]);
+    }
+
     #[test]
     fn unquoting_works() {
         let quoted_str = "'abc $$ def'".to_string();
@@ -926,4 +1327,281 @@ mod tests {
 
         assert_eq!(unquote_string("heh "), "heh ");
     }
+
+    #[test]
+    fn merge_appends_items_from_other_namespaces_with_remapped_ids() {
+        fn id_column() -> Column {
+            Column {
+                name: "id".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            }
+        }
+
+        let mut first = SqlSchema::default();
+        let ns_a = first.push_namespace("a".to_owned());
+        let table_a = first.push_table("Cat".to_owned(), ns_a, None);
+        first.push_table_column(table_a, id_column());
+
+        let mut second = SqlSchema::default();
+        let ns_b = second.push_namespace("b".to_owned());
+        let table_b = second.push_table("Dog".to_owned(), ns_b, None);
+        second.push_table_column(table_b, id_column());
+
+        first.merge(second);
+
+        let namespaces: Vec<_> = first.walk_namespaces().map(|ns| ns.name().to_owned()).collect();
+        assert_eq!(namespaces, vec!["a", "b"]);
+
+        let table_names: Vec<_> = first.table_walkers().map(|t| t.name().to_owned()).collect();
+        assert_eq!(table_names, vec!["Cat", "Dog"]);
+
+        let dog = first.table_walker("Dog").unwrap();
+        assert_eq!(dog.namespace(), Some("b"));
+        assert_eq!(dog.columns().map(|c| c.name().to_owned()).collect::<Vec<_>>(), vec!["id"]);
+    }
+
+    #[test]
+    fn to_sorted_json_is_independent_of_insertion_order() {
+        fn id_column() -> Column {
+            Column {
+                name: "id".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            }
+        }
+
+        fn name_column() -> Column {
+            Column {
+                name: "name".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            }
+        }
+
+        let mut first = SqlSchema::default();
+        let ns = first.push_namespace("public".to_owned());
+        let cat = first.push_table("Cat".to_owned(), ns, None);
+        first.push_table_column(cat, id_column());
+        first.push_table_column(cat, name_column());
+        let dog = first.push_table("Dog".to_owned(), ns, None);
+        first.push_table_column(dog, id_column());
+
+        let mut second = SqlSchema::default();
+        let ns = second.push_namespace("public".to_owned());
+        let dog = second.push_table("Dog".to_owned(), ns, None);
+        second.push_table_column(dog, id_column());
+        let cat = second.push_table("Cat".to_owned(), ns, None);
+        second.push_table_column(cat, name_column());
+        second.push_table_column(cat, id_column());
+
+        assert_eq!(first.to_sorted_json(), second.to_sorted_json());
+    }
+
+    #[test]
+    fn indexes_of_type_filters_across_tables() {
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let cat = schema.push_table("Cat".to_owned(), ns, None);
+        let dog = schema.push_table("Dog".to_owned(), ns, None);
+
+        schema.push_index(cat, "Cat_normal_idx".to_owned());
+        schema.push_unique_constraint(cat, "Cat_unique_idx".to_owned());
+        schema.push_fulltext_index(dog, "Dog_fulltext_idx".to_owned());
+        schema.push_unique_constraint(dog, "Dog_unique_idx".to_owned());
+
+        let all_index_names: Vec<_> = schema.walk_indexes().map(|i| i.name().to_owned()).collect();
+        assert_eq!(
+            all_index_names,
+            vec!["Cat_normal_idx", "Cat_unique_idx", "Dog_fulltext_idx", "Dog_unique_idx"]
+        );
+
+        let unique_index_names: Vec<_> = schema
+            .indexes_of_type(IndexType::Unique)
+            .map(|i| i.name().to_owned())
+            .collect();
+        assert_eq!(unique_index_names, vec!["Cat_unique_idx", "Dog_unique_idx"]);
+
+        let unique_index_tables: Vec<_> = schema
+            .indexes_of_type(IndexType::Unique)
+            .map(|i| i.table().name().to_owned())
+            .collect();
+        assert_eq!(unique_index_tables, vec!["Cat", "Dog"]);
+    }
+
+    #[test]
+    fn duplicate_indexes_groups_exact_duplicates() {
+        fn name_column() -> Column {
+            Column {
+                name: "name".to_owned(),
+                tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            }
+        }
+
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let cat = schema.push_table("Cat".to_owned(), ns, None);
+        let name_column = schema.push_table_column(cat, name_column());
+
+        let idx_a = schema.push_index(cat, "Cat_name_idx".to_owned());
+        schema.push_index_column(IndexColumn {
+            index_id: idx_a,
+            column_id: name_column,
+            sort_order: None,
+            length: None,
+        });
+
+        let idx_b = schema.push_index(cat, "Cat_name_idx_2".to_owned());
+        schema.push_index_column(IndexColumn {
+            index_id: idx_b,
+            column_id: name_column,
+            sort_order: None,
+            length: None,
+        });
+
+        // Same column, but a unique constraint rather than a normal index: not a duplicate.
+        let unique_idx = schema.push_unique_constraint(cat, "Cat_name_key".to_owned());
+        schema.push_index_column(IndexColumn {
+            index_id: unique_idx,
+            column_id: name_column,
+            sort_order: None,
+            length: None,
+        });
+
+        let duplicates = schema.duplicate_indexes();
+
+        assert_eq!(duplicates, vec![vec![idx_a, idx_b]]);
+    }
+
+    #[test]
+    fn check_constraints_round_trip_through_the_walker() {
+        fn column(name: &str, family: ColumnTypeFamily) -> Column {
+            Column {
+                name: name.to_owned(),
+                tpe: ColumnType::pure(family, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            }
+        }
+
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let cat = schema.push_table("Cat".to_owned(), ns, None);
+        let age_column = schema.push_table_column(cat, column("age", ColumnTypeFamily::Int));
+        let birth_column = schema.push_table_column(cat, column("birth_year", ColumnTypeFamily::Int));
+
+        // A single-column check, and a multi-column one (`CHECK (birth_year + age = 2024)`),
+        // which is still attributed to the table as a whole rather than to one of its columns.
+        schema
+            .check_constraints
+            .push((cat, "Cat_age_check".to_owned(), vec![age_column]));
+        schema
+            .check_constraints
+            .push((cat, "Cat_birth_year_check".to_owned(), vec![birth_column, age_column]));
+        schema.check_constraints.sort_by_key(|(id, _, _)| *id);
+
+        let table = schema.table_walker("Cat").unwrap();
+        assert!(table.has_check_constraints());
+        assert_eq!(
+            table.check_constraints().collect::<Vec<_>>(),
+            vec!["Cat_age_check", "Cat_birth_year_check"]
+        );
+
+        let all_check_constraint_names: Vec<_> = schema.walk_check_constraints().map(|c| c.name()).collect();
+        assert_eq!(all_check_constraint_names, vec!["Cat_age_check", "Cat_birth_year_check"]);
+
+        let check_constraint_tables: Vec<_> = schema
+            .walk_check_constraints()
+            .map(|c| c.table().name().to_owned())
+            .collect();
+        assert_eq!(check_constraint_tables, vec!["Cat", "Cat"]);
+
+        let multi_column_check = schema
+            .walk_check_constraints()
+            .find(|c| c.name() == "Cat_birth_year_check")
+            .unwrap();
+        let referenced_columns: Vec<_> = multi_column_check.columns().map(|c| c.name().to_owned()).collect();
+        assert_eq!(referenced_columns, vec!["birth_year", "age"]);
+    }
+
+    #[test]
+    fn find_table_by_is_needed_for_names_differing_only_by_case() {
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let cat = schema.push_table("cat".to_owned(), ns, None);
+        schema.push_table("CAT".to_owned(), ns, None);
+
+        // The exact-match method never confuses the two.
+        assert_eq!(schema.find_table("cat", None), Some(cat));
+        assert_eq!(schema.find_table("dog", None), None);
+
+        // The predicate-based method finds the first name matching the predicate.
+        assert_eq!(schema.find_table_by(|n| n.eq_ignore_ascii_case("cat"), None), Some(cat));
+    }
+
+    #[test]
+    fn find_view_by_is_needed_for_names_differing_only_by_case() {
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let view = schema.push_view("cat_view".to_owned(), ns, None, None);
+        schema.push_view("CAT_VIEW".to_owned(), ns, None, None);
+
+        assert_eq!(schema.find_view("cat_view", None), Some(view));
+        assert_eq!(
+            schema.find_view_by(|n| n.eq_ignore_ascii_case("cat_view"), None),
+            Some(view)
+        );
+    }
+
+    #[test]
+    fn find_enum_by_is_needed_for_names_differing_only_by_case() {
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let e = schema.push_enum(ns, "color".to_owned(), None);
+        schema.push_enum(ns, "COLOR".to_owned(), None);
+
+        assert_eq!(schema.find_enum("color", None), Some(e));
+        assert_eq!(schema.find_enum_by(|n| n.eq_ignore_ascii_case("color"), None), Some(e));
+    }
+
+    #[test]
+    fn type_family_summary_counts_columns_by_family() {
+        fn column(name: &str, family: ColumnTypeFamily) -> Column {
+            Column {
+                name: name.to_owned(),
+                tpe: ColumnType::pure(family, ColumnArity::Required),
+                auto_increment: false,
+                description: None,
+            }
+        }
+
+        let mut schema = SqlSchema::default();
+        let ns = schema.push_namespace("public".to_owned());
+        let cat = schema.push_table("Cat".to_owned(), ns, None);
+
+        schema.push_table_column(cat, column("id", ColumnTypeFamily::Int));
+        schema.push_table_column(cat, column("name", ColumnTypeFamily::String));
+        schema.push_table_column(cat, column("nickname", ColumnTypeFamily::String));
+        schema.push_table_column(cat, column("metadata", ColumnTypeFamily::Json));
+        schema.push_table_column(
+            cat,
+            column("location", ColumnTypeFamily::Unsupported("geometry".to_owned())),
+        );
+
+        let summary = schema.table_walker("Cat").unwrap().type_family_summary();
+
+        assert_eq!(summary.get(&ColumnTypeFamily::Int), Some(&1));
+        assert_eq!(summary.get(&ColumnTypeFamily::String), Some(&2));
+        assert_eq!(summary.get(&ColumnTypeFamily::Json), Some(&1));
+        assert_eq!(
+            summary.get(&ColumnTypeFamily::Unsupported("geometry".to_owned())),
+            Some(&1)
+        );
+        assert_eq!(summary.values().sum::<usize>(), 5);
+    }
 }
@@ -15,10 +15,55 @@ use psl::{
     builtin_connectors::{MsSqlType, MsSqlTypeParameter},
     datamodel_connector::NativeTypeInstance,
 };
-use quaint::prelude::Queryable;
 use regex::Regex;
 use std::{any::type_name, borrow::Cow, collections::HashMap, convert::TryInto};
 
+/// The database access the describer needs. Deliberately narrower than [`quaint::prelude::Queryable`]:
+/// it only exposes read operations, so a describer can never issue a statement that mutates the
+/// database or its session state, even by accident. This makes it safe to point a describer at a
+/// connection whose role only has read privileges.
+#[async_trait::async_trait]
+pub trait Connection: Sync {
+    async fn query_raw<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [quaint::prelude::Value<'a>],
+    ) -> quaint::Result<quaint::prelude::ResultSet>;
+
+    async fn version(&self) -> quaint::Result<Option<String>>;
+}
+
+#[cfg(feature = "mssql-native")]
+#[async_trait::async_trait]
+impl Connection for quaint::connector::Mssql {
+    async fn query_raw<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [quaint::prelude::Value<'a>],
+    ) -> quaint::Result<quaint::prelude::ResultSet> {
+        quaint::prelude::Queryable::query_raw(self, sql, params).await
+    }
+
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        quaint::prelude::Queryable::version(self).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Connection for quaint::single::Quaint {
+    async fn query_raw<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [quaint::prelude::Value<'a>],
+    ) -> quaint::Result<quaint::prelude::ResultSet> {
+        quaint::prelude::Queryable::query_raw(self, sql, params).await
+    }
+
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        quaint::prelude::Queryable::version(self).await
+    }
+}
+
 /// Matches a default value in the schema, that is not a string.
 ///
 /// Examples:
@@ -64,7 +109,7 @@ static DEFAULT_DB_GEN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\((.*)\)").unwrap
 static DEFAULT_SHARED_CONSTRAINT: Lazy<Regex> = Lazy::new(|| Regex::new(r"CREATE DEFAULT (.*)").unwrap());
 
 pub struct SqlSchemaDescriber<'a> {
-    conn: &'a dyn Queryable,
+    conn: &'a dyn Connection,
 }
 
 #[derive(Default)]
@@ -154,7 +199,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
 impl Parser for SqlSchemaDescriber<'_> {}
 
 impl<'a> SqlSchemaDescriber<'a> {
-    pub fn new(conn: &'a dyn Queryable) -> Self {
+    pub fn new(conn: &'a dyn Connection) -> Self {
         Self { conn }
     }
 
@@ -5,13 +5,56 @@ use bigdecimal::ToPrimitive;
 use indexmap::IndexMap;
 use indoc::indoc;
 use psl::{builtin_connectors::MySqlType, datamodel_connector::NativeTypeInstance};
-use quaint::{
-    prelude::{Queryable, ResultRow},
-    Value,
-};
+use quaint::{prelude::ResultRow, Value};
 use std::borrow::Cow;
 use tracing::trace;
 
+/// The database access the describer needs. Deliberately narrower than [`quaint::prelude::Queryable`]:
+/// it only exposes read operations, so a describer can never issue a statement that mutates the
+/// database or its session state, even by accident. This makes it safe to point a describer at a
+/// connection whose role only has read privileges.
+#[async_trait::async_trait]
+pub trait Connection: Sync {
+    async fn query_raw<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [quaint::prelude::Value<'a>],
+    ) -> quaint::Result<quaint::prelude::ResultSet>;
+
+    async fn version(&self) -> quaint::Result<Option<String>>;
+}
+
+#[cfg(feature = "mysql-native")]
+#[async_trait::async_trait]
+impl Connection for quaint::connector::Mysql {
+    async fn query_raw<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [quaint::prelude::Value<'a>],
+    ) -> quaint::Result<quaint::prelude::ResultSet> {
+        quaint::prelude::Queryable::query_raw(self, sql, params).await
+    }
+
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        quaint::prelude::Queryable::version(self).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Connection for quaint::single::Quaint {
+    async fn query_raw<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [quaint::prelude::Value<'a>],
+    ) -> quaint::Result<quaint::prelude::ResultSet> {
+        quaint::prelude::Queryable::query_raw(self, sql, params).await
+    }
+
+    async fn version(&self) -> quaint::Result<Option<String>> {
+        quaint::prelude::Queryable::version(self).await
+    }
+}
+
 /// Matches a default value in the schema, wrapped single quotes.
 ///
 /// Example:
@@ -51,10 +94,47 @@ pub enum Circumstances {
 }
 
 pub struct SqlSchemaDescriber<'a> {
-    conn: &'a dyn Queryable,
+    conn: &'a dyn Connection,
     circumstances: BitFlags<Circumstances>,
 }
 
+/// MySQL-specific schema information, currently limited to the character sets and collations of
+/// tables and columns that were read from `information_schema`.
+#[derive(Default, Debug)]
+pub struct MysqlSchemaExt {
+    /// The collation of tables, for tables where it could be read, sorted by `TableId`.
+    pub table_collations: Vec<(TableId, String)>,
+    /// The character set of columns for which one is defined (character/text types), sorted by
+    /// `TableColumnId`.
+    pub column_character_sets: Vec<(TableColumnId, String)>,
+    /// The collation of columns for which one is defined (character/text types), sorted by
+    /// `TableColumnId`.
+    pub column_collations: Vec<(TableColumnId, String)>,
+}
+
+impl MysqlSchemaExt {
+    pub fn table_collation(&self, table_id: TableId) -> Option<&str> {
+        self.table_collations
+            .binary_search_by_key(&table_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| self.table_collations[idx].1.as_str())
+    }
+
+    pub fn column_character_set(&self, column_id: TableColumnId) -> Option<&str> {
+        self.column_character_sets
+            .binary_search_by_key(&column_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| self.column_character_sets[idx].1.as_str())
+    }
+
+    pub fn column_collation(&self, column_id: TableColumnId) -> Option<&str> {
+        self.column_collations
+            .binary_search_by_key(&column_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| self.column_collations[idx].1.as_str())
+    }
+}
+
 #[async_trait::async_trait]
 impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
     async fn list_databases(&self) -> DescriberResult<Vec<String>> {
@@ -63,7 +143,11 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
 
     async fn get_metadata(&self, schema: &str) -> DescriberResult<SqlMetadata> {
         let mut sql_schema = SqlSchema::default();
-        let table_count = self.get_table_names(schema, &mut sql_schema).await?.len();
+        let mut mysql_ext = MysqlSchemaExt::default();
+        let table_count = self
+            .get_table_names(schema, &mut sql_schema, &mut mysql_ext)
+            .await?
+            .len();
         let size_in_bytes = self.get_size(schema).await?;
 
         Ok(SqlMetadata {
@@ -84,18 +168,36 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
 
         sql_schema.views = self.get_views(schema).await?;
 
-        let table_names = self.get_table_names(schema, &mut sql_schema).await?;
+        let mut mysql_ext = MysqlSchemaExt::default();
+
+        let table_names = self.get_table_names(schema, &mut sql_schema, &mut mysql_ext).await?;
         sql_schema.tables.reserve(table_names.len());
         sql_schema.table_columns.reserve(table_names.len());
 
         self.get_constraints(&table_names, &mut sql_schema).await?;
 
-        Self::get_all_columns(&table_names, self.conn, schema, &mut sql_schema, &flavour).await?;
+        Self::get_all_columns(
+            &table_names,
+            self.conn,
+            schema,
+            &mut sql_schema,
+            &flavour,
+            &mut mysql_ext,
+        )
+        .await?;
         push_foreign_keys(schema, &table_names, &mut sql_schema, self.conn).await?;
         push_indexes(&table_names, schema, &mut sql_schema, self.conn).await?;
 
         sql_schema.procedures = self.get_procedures(schema).await?;
 
+        mysql_ext.table_collations.sort_by_key(|(id, _)| *id);
+        mysql_ext.column_character_sets.sort_by_key(|(id, _)| *id);
+        mysql_ext.column_collations.sort_by_key(|(id, _)| *id);
+
+        sql_schema.connector_data = connector_data::ConnectorData {
+            data: Some(Box::new(mysql_ext)),
+        };
+
         Ok(sql_schema)
     }
 
@@ -109,7 +211,7 @@ async fn push_indexes(
     table_ids: &IndexMap<String, TableId>,
     schema_name: &str,
     sql_schema: &mut SqlSchema,
-    conn: &dyn Queryable,
+    conn: &dyn Connection,
 ) -> DescriberResult<()> {
     // We alias all the columns because MySQL column names are case-insensitive in queries, but
     // the information schema column names became upper-case in MySQL 8, causing the code
@@ -172,6 +274,7 @@ async fn push_indexes(
         let is_unique = !row.get_expect_bool("non_unique");
         let is_pk = index_name.eq_ignore_ascii_case("primary");
         let is_fulltext = row.get_string("index_type").as_deref() == Some("FULLTEXT");
+        let is_spatial = row.get_string("index_type").as_deref() == Some("SPATIAL");
 
         if seq_in_index == 1 {
             // new index!
@@ -189,6 +292,8 @@ async fn push_indexes(
                 sql_schema.push_unique_constraint(table_id, index_name)
             } else if is_fulltext {
                 sql_schema.push_fulltext_index(table_id, index_name)
+            } else if is_spatial {
+                sql_schema.push_spatial_index(table_id, index_name)
             } else {
                 sql_schema.push_index(table_id, index_name)
             };
@@ -215,7 +320,7 @@ impl Parser for SqlSchemaDescriber<'_> {}
 
 impl<'a> SqlSchemaDescriber<'a> {
     /// Constructor.
-    pub fn new(conn: &'a dyn Queryable, circumstances: BitFlags<Circumstances>) -> SqlSchemaDescriber<'a> {
+    pub fn new(conn: &'a dyn Connection, circumstances: BitFlags<Circumstances>) -> SqlSchemaDescriber<'a> {
         SqlSchemaDescriber { conn, circumstances }
     }
 
@@ -285,13 +390,15 @@ impl<'a> SqlSchemaDescriber<'a> {
         &self,
         schema: &str,
         sql_schema: &mut SqlSchema,
+        mysql_ext: &mut MysqlSchemaExt,
     ) -> DescriberResult<IndexMap<String, TableId>> {
         // Only consider tables for which we can read at least one column.
         let sql = r#"
             SELECT DISTINCT
               BINARY table_info.table_name AS table_name,
               table_info.create_options AS create_options,
-              table_info.table_comment AS table_comment
+              table_info.table_comment AS table_comment,
+              table_info.table_collation AS table_collation
             FROM information_schema.tables AS table_info
             JOIN information_schema.columns AS column_info
                 ON BINARY column_info.table_name = BINARY table_info.table_name
@@ -309,12 +416,13 @@ impl<'a> SqlSchemaDescriber<'a> {
                     .filter(|c| c.as_str() == "partitioned")
                     .is_some(),
                 row.get_string("table_comment").filter(|c| !c.is_empty()),
+                row.get_string("table_collation"),
             )
         });
 
         let mut map = IndexMap::default();
 
-        for (name, is_partition, description) in names {
+        for (name, is_partition, description, collation) in names {
             let cloned_name = name.clone();
             let id = if is_partition {
                 sql_schema.push_table_with_properties(
@@ -326,6 +434,11 @@ impl<'a> SqlSchemaDescriber<'a> {
             } else {
                 sql_schema.push_table(name, Default::default(), description)
             };
+
+            if let Some(collation) = collation {
+                mysql_ext.table_collations.push((id, collation));
+            }
+
             map.insert(cloned_name, id);
         }
 
@@ -360,10 +473,11 @@ impl<'a> SqlSchemaDescriber<'a> {
 
     async fn get_all_columns(
         table_ids: &IndexMap<String, TableId>,
-        conn: &dyn Queryable,
+        conn: &dyn Connection,
         schema_name: &str,
         sql_schema: &mut SqlSchema,
         flavour: &Flavour,
+        mysql_ext: &mut MysqlSchemaExt,
     ) -> DescriberResult<()> {
         // We alias all the columns because MySQL column names are case-insensitive in queries, but the
         // information schema column names became upper-case in MySQL 8, causing the code fetching
@@ -381,6 +495,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 is_nullable is_nullable,
                 extra extra,
                 table_name table_name,
+                character_set_name character_set_name,
+                collation_name collation_name,
                 IF(column_comment = '', NULL, column_comment) AS column_comment
             FROM information_schema.columns
             WHERE table_schema = ?
@@ -389,6 +505,7 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         let mut table_defaults = Vec::new();
         let mut view_defaults = Vec::new();
+        let mut table_column_charsets = Vec::new();
         let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
 
         for col in rows {
@@ -544,6 +661,11 @@ impl<'a> SqlSchemaDescriber<'a> {
             match container_id {
                 Either::Left(table_id) => {
                     table_defaults.push((table_id, default));
+                    table_column_charsets.push((
+                        table_id,
+                        col.get_string("character_set_name"),
+                        col.get_string("collation_name"),
+                    ));
                 }
                 Either::Right(view_id) => {
                     view_defaults.push((view_id, default));
@@ -574,6 +696,7 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         table_defaults.sort_by_key(|(table_id, _)| *table_id);
         view_defaults.sort_by_key(|(view_id, _)| *view_id);
+        table_column_charsets.sort_by_key(|(table_id, _, _)| *table_id);
 
         for (i, (_, default)) in table_defaults.into_iter().enumerate() {
             if let Some(default) = default {
@@ -581,6 +704,18 @@ impl<'a> SqlSchemaDescriber<'a> {
             }
         }
 
+        for (i, (_, character_set, collation)) in table_column_charsets.into_iter().enumerate() {
+            let column_id = TableColumnId(i as u32);
+
+            if let Some(character_set) = character_set {
+                mysql_ext.column_character_sets.push((column_id, character_set));
+            }
+
+            if let Some(collation) = collation {
+                mysql_ext.column_collations.push((column_id, collation));
+            }
+        }
+
         for (i, (_, default)) in view_defaults.into_iter().enumerate() {
             if let Some(default) = default {
                 sql_schema.push_view_default_value(ViewColumnId(i as u32), default);
@@ -759,11 +894,14 @@ impl<'a> SqlSchemaDescriber<'a> {
             };
 
             if constraint_type.as_str() == "check" {
-                sql_schema.check_constraints.push((table_id, constraint_name));
+                // `information_schema.CHECK_CONSTRAINTS` only gives us the check clause as text
+                // (`CHECK_CLAUSE`), not which columns it references, so we can't populate the
+                // referenced columns here without parsing SQL expressions.
+                sql_schema.check_constraints.push((table_id, constraint_name, Vec::new()));
             }
         }
 
-        sql_schema.check_constraints.sort_by_key(|(id, _)| *id);
+        sql_schema.check_constraints.sort_by_key(|(id, _, _)| *id);
 
         Ok(())
     }
@@ -815,7 +953,7 @@ async fn push_foreign_keys(
     schema_name: &str,
     table_ids: &IndexMap<String, TableId>,
     sql_schema: &mut SqlSchema,
-    conn: &dyn Queryable,
+    conn: &dyn Connection,
 ) -> DescriberResult<()> {
     // We alias all the columns because MySQL column names are case-insensitive in queries, but
     // the information schema column names became upper-case in MySQL 8, causing the code
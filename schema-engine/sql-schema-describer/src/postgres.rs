@@ -27,6 +27,10 @@ use std::{
 };
 use tracing::trace;
 
+/// The database access the describer needs. Deliberately narrower than [`quaint::prelude::Queryable`]:
+/// it only exposes read operations, so a describer can never issue a statement that mutates the
+/// database or its session state, even by accident. This makes it safe to point a describer at a
+/// connection whose role only has read privileges.
 #[async_trait::async_trait]
 pub trait Connection: Sync {
     async fn query_raw<'a>(
@@ -106,6 +110,10 @@ pub struct Sequence {
     pub cache_size: i64,
     /// Whether the sequence is a cockroachdb virtual sequence
     pub r#virtual: bool,
+    /// The column this sequence is tied to through `ALTER SEQUENCE ... OWNED BY`, if any. An
+    /// owned sequence is dropped automatically when its owning column (or the column's table) is
+    /// dropped, and is what `SERIAL`/`BIGSERIAL` columns set up implicitly.
+    pub owned_by: Option<TableColumnId>,
 }
 
 // We impl default manually to align with database defaults.
@@ -121,6 +129,7 @@ impl Default for Sequence {
             cycle: false,
             cache_size: 1,
             r#virtual: false,
+            owned_by: None,
         }
     }
 }
@@ -167,6 +176,16 @@ pub enum Circumstances {
     Cockroach,
     CockroachWithPostgresNativeTypes, // TODO: this is a temporary workaround
     CanPartitionTables,
+    /// Sample `pg_stats` for the null fraction of each column, exposed as
+    /// [`PostgresSchemaExt::column_null_fraction`]. Off by default: `pg_stats` only has data for
+    /// columns Postgres has run `ANALYZE` on, and reading it needs a bit more machinery than the
+    /// rest of the describer, so callers opt in explicitly.
+    CollectColumnStatistics,
+    /// Collect the table-level privileges the current role has on each table, from
+    /// `information_schema.role_table_grants`, exposed as [`PostgresSchemaExt::table_privileges`].
+    /// Off by default: like [`Circumstances::CollectColumnStatistics`], this is an extra round
+    /// trip only useful for access-aware tooling, so callers opt in explicitly.
+    CollectPrivileges,
 }
 
 pub struct SqlSchemaDescriber<'a> {
@@ -202,6 +221,75 @@ pub enum ConstraintOption {
     Deferrable,
 }
 
+/// The `STORAGE` setting of a column, controlling how Postgres handles TOASTing of values that
+/// don't fit inline in the row. See <https://www.postgresql.org/docs/current/storage-toast.html>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PostgresColumnStorage {
+    /// Prevents either compression or out-of-line storage. This is the only possible strategy
+    /// for columns of types that are not TOAST-able.
+    Plain,
+    /// Allows both compression and out-of-line storage.
+    Extended,
+    /// Allows out-of-line storage, but not compression.
+    External,
+    /// Allows compression, but not out-of-line storage. This is the default for `varchar` and
+    /// similar types that support compression but are usually short enough not to need TOASTing.
+    Main,
+}
+
+impl PostgresColumnStorage {
+    /// Parse the single-character code Postgres uses for `pg_attribute.attstorage` /
+    /// `pg_type.typstorage` (`p`, `e`, `x` or `m`).
+    fn from_attstorage(code: char) -> Option<Self> {
+        match code {
+            'p' => Some(Self::Plain),
+            'e' => Some(Self::External),
+            'x' => Some(Self::Extended),
+            'm' => Some(Self::Main),
+            _ => None,
+        }
+    }
+
+    /// The `STORAGE` keyword to use when rendering this setting in DDL.
+    pub fn to_ddl(self) -> &'static str {
+        match self {
+            Self::Plain => "PLAIN",
+            Self::Extended => "EXTENDED",
+            Self::External => "EXTERNAL",
+            Self::Main => "MAIN",
+        }
+    }
+}
+
+/// A table's `REPLICA IDENTITY` setting, controlling what old row data is written to the WAL
+/// for logical replication. See
+/// <https://www.postgresql.org/docs/current/sql-altertable.html#SQL-ALTERTABLE-REPLICA-IDENTITY>.
+/// The default (`d`, primary key only) is not represented: [`PostgresSchemaExt::replica_identity`]
+/// returns `None` for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplicaIdentity {
+    /// `REPLICA IDENTITY FULL`: the whole row is written to the WAL.
+    Full,
+    /// `REPLICA IDENTITY NOTHING`: no old row data is written to the WAL.
+    Nothing,
+    /// `REPLICA IDENTITY USING INDEX <name>`: the named unique index is used instead of the
+    /// primary key.
+    Index(String),
+}
+
+impl ReplicaIdentity {
+    /// Parse `pg_class.relreplident` (`d`, `n`, `f` or `i`), with the index name already
+    /// resolved for the `i` case. Returns `None` for the default (`d`).
+    fn from_relreplident(code: char, index_name: Option<String>) -> Option<Self> {
+        match code {
+            'f' => Some(Self::Full),
+            'n' => Some(Self::Nothing),
+            'i' => Some(Self::Index(index_name.expect("relreplident = 'i' without an index"))),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct PostgresSchemaExt {
     pub opclasses: Vec<(IndexColumnId, SQLOperatorClass)>,
@@ -211,10 +299,26 @@ pub struct PostgresSchemaExt {
     pub constraint_options: HashMap<Constraint, BitFlags<ConstraintOption>>,
     pub table_options: Vec<BTreeMap<String, String>>,
     pub exclude_constraints: Vec<(TableId, String)>,
+    /// The `COMMENT ON CONSTRAINT` text set on a constraint, sorted by `TableId`. Constraints of
+    /// any kind (primary key, foreign key, unique, check or exclude) can carry a comment.
+    pub constraint_comments: Vec<(TableId, String, String)>,
+    /// The `fillfactor` storage parameter for indexes that set a non-default value, sorted by `IndexId`.
+    pub index_fillfactor: Vec<(IndexId, u32)>,
     /// The schema's sequences.
     pub sequences: Vec<Sequence>,
     /// The extensions included in the schema(s).
     extensions: Vec<DatabaseExtension>,
+    /// The `STORAGE` setting for columns that set a non-default value, sorted by `TableColumnId`.
+    pub column_storage: Vec<(TableColumnId, PostgresColumnStorage)>,
+    /// The fraction of `NULL` values `pg_stats` reports for a column, sorted by `TableColumnId`.
+    /// Only populated when describing with [`Circumstances::CollectColumnStatistics`] set.
+    pub column_null_fraction: Vec<(TableColumnId, f64)>,
+    /// The `REPLICA IDENTITY` setting for tables that set a non-default value, sorted by `TableId`.
+    pub replica_identities: Vec<(TableId, ReplicaIdentity)>,
+    /// The distinct privileges (e.g. `SELECT`, `INSERT`) the current role has been granted on
+    /// the table, sorted by `TableId`. Only populated when describing with
+    /// [`Circumstances::CollectPrivileges`] set.
+    pub table_privileges: Vec<(TableId, Vec<String>)>,
 }
 
 impl PostgresSchemaExt {
@@ -226,6 +330,39 @@ impl PostgresSchemaExt {
         }
     }
 
+    /// The `fillfactor` storage parameter for the index, if it was set to a non-default value.
+    pub fn index_fillfactor(&self, index_id: IndexId) -> Option<u32> {
+        self.index_fillfactor
+            .binary_search_by_key(&index_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| self.index_fillfactor[idx].1)
+    }
+
+    /// The `STORAGE` setting for the column, if it was set to a non-default value.
+    pub fn column_storage(&self, column_id: TableColumnId) -> Option<PostgresColumnStorage> {
+        self.column_storage
+            .binary_search_by_key(&column_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| self.column_storage[idx].1)
+    }
+
+    /// The fraction of `NULL` values `pg_stats` reports for the column, if statistics were
+    /// collected and Postgres has sampled the column.
+    pub fn column_null_fraction(&self, column_id: TableColumnId) -> Option<f64> {
+        self.column_null_fraction
+            .binary_search_by_key(&column_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| self.column_null_fraction[idx].1)
+    }
+
+    /// The `REPLICA IDENTITY` setting for the table, if it was set to a non-default value.
+    pub fn replica_identity(&self, table_id: TableId) -> Option<&ReplicaIdentity> {
+        self.replica_identities
+            .binary_search_by_key(&table_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| &self.replica_identities[idx].1)
+    }
+
     pub fn get_opclass(&self, index_field_id: IndexColumnId) -> Option<&SQLOperatorClass> {
         let idx = self
             .opclasses
@@ -323,6 +460,34 @@ impl PostgresSchemaExt {
             .binary_search_by_key(&id, |(id, _)| *id)
             .is_ok()
     }
+
+    /// The `(constraint_name, comment)` pairs for constraints of the table that have a
+    /// `COMMENT ON CONSTRAINT` set.
+    pub fn constraint_comments(&self, table_id: TableId) -> impl ExactSizeIterator<Item = (&str, &str)> {
+        let low = self.constraint_comments.partition_point(|(id, _, _)| *id < table_id);
+        let high = self.constraint_comments[low..].partition_point(|(id, _, _)| *id <= table_id);
+
+        self.constraint_comments[low..low + high]
+            .iter()
+            .map(|(_, name, comment)| (name.as_str(), comment.as_str()))
+    }
+
+    pub fn has_constraint_comments(&self, table_id: TableId) -> bool {
+        let low = self.constraint_comments.partition_point(|(id, _, _)| *id < table_id);
+
+        self.constraint_comments
+            .get(low)
+            .is_some_and(|(id, _, _)| *id == table_id)
+    }
+
+    /// The distinct privileges the current role has been granted on the table, if privileges
+    /// were collected (see [`Circumstances::CollectPrivileges`]).
+    pub fn table_privileges(&self, table_id: TableId) -> Option<&[String]> {
+        self.table_privileges
+            .binary_search_by_key(&table_id, |(id, _)| *id)
+            .ok()
+            .map(|idx| self.table_privileges[idx].1.as_slice())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -619,21 +784,45 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
 
         // order matters
         self.get_constraints(&table_names, &mut sql_schema, &mut pg_ext).await?;
+        self.get_constraint_comments(&table_names, &sql_schema, &mut pg_ext).await?;
         self.get_views(&mut sql_schema).await?;
         self.get_enums(&mut sql_schema).await?;
-        self.get_columns(&mut sql_schema).await?;
+        self.get_columns(&mut sql_schema, &mut pg_ext).await?;
         self.get_foreign_keys(&table_names, &mut pg_ext, &mut sql_schema)
             .await?;
         self.get_indices(&table_names, &mut pg_ext, &mut sql_schema).await?;
+        self.get_replica_identities(&sql_schema, &mut pg_ext).await?;
 
         self.get_procedures(&mut sql_schema).await?;
         self.get_extensions(&mut pg_ext).await?;
 
         //Todo(matthias) understand this
         self.get_sequences(&sql_schema, &mut pg_ext).await?;
+
+        // `get_columns` runs before `get_sequences`, so column defaults referencing a sequence
+        // could not know yet whether it is a CockroachDB virtual sequence. Patch that in now.
+        for (_, default) in sql_schema.table_default_values.iter_mut() {
+            mark_virtual_sequence(default, &pg_ext);
+        }
+        for (_, default) in sql_schema.view_default_values.iter_mut() {
+            mark_virtual_sequence(default, &pg_ext);
+        }
+
+        if self.circumstances.contains(Circumstances::CollectColumnStatistics) {
+            self.get_column_statistics(&sql_schema, &mut pg_ext).await?;
+        }
+
+        if self.circumstances.contains(Circumstances::CollectPrivileges) {
+            self.get_privileges(&sql_schema, &mut pg_ext).await?;
+        }
+
         // Make sure the vectors we use binary search on are sorted.
         pg_ext.indexes.sort_by_key(|(id, _)| *id);
         pg_ext.opclasses.sort_by_key(|(id, _)| *id);
+        pg_ext.column_storage.sort_by_key(|(id, _)| *id);
+        pg_ext.column_null_fraction.sort_by_key(|(id, _)| *id);
+        pg_ext.replica_identities.sort_by_key(|(id, _)| *id);
+        pg_ext.table_privileges.sort_by_key(|(id, _)| *id);
 
         sql_schema.connector_data = connector_data::ConnectorData {
             data: Some(Box::new(pg_ext)),
@@ -652,6 +841,22 @@ impl<'a> SqlSchemaDescriber<'a> {
         SqlSchemaDescriber { conn, circumstances }
     }
 
+    /// Describe the database using a Postgres `search_path`-style, comma-separated list of
+    /// schemas (e.g. `"tenant_a, public"`), without reconfiguring the connector or its
+    /// connection string. Useful for introspecting a schema that isn't part of the connector's
+    /// own search path.
+    ///
+    /// Note this does not issue a `SET search_path` on the connection: [`Connection`] is
+    /// deliberately read-only, so a describer can never mutate session state, even to honor a
+    /// caller-supplied search path. Instead, the parsed schema names are passed to [`Self::describe`]
+    /// in order, which is how every other multi-schema case in this describer already scopes its
+    /// queries to specific namespaces.
+    pub async fn describe_with_search_path(&self, search_path: &str) -> DescriberResult<SqlSchema> {
+        let schemas: Vec<&str> = search_path.split(',').map(str::trim).filter(|s| !s.is_empty()).collect();
+
+        self.describe(&schemas).await
+    }
+
     fn is_cockroach(&self) -> bool {
         self.circumstances.contains(Circumstances::Cockroach)
     }
@@ -791,6 +996,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 row.get_expect_bool("is_partition"),
                 row.get_expect_bool("has_subclass"),
                 row.get_expect_bool("has_row_level_security"),
+                row.get_expect_bool("is_foreign"),
+                row.get_expect_bool("is_unlogged"),
                 row.get_string("description"),
             ));
 
@@ -799,7 +1006,17 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         let mut map = IndexMap::default();
 
-        for (table_name, namespace, is_partition, has_subclass, has_row_level_security, description) in names {
+        for (
+            table_name,
+            namespace,
+            is_partition,
+            has_subclass,
+            has_row_level_security,
+            is_foreign,
+            is_unlogged,
+            description,
+        ) in names
+        {
             let cloned_name = table_name.clone();
 
             let partition = if is_partition {
@@ -818,6 +1035,17 @@ impl<'a> SqlSchemaDescriber<'a> {
             } else {
                 BitFlags::empty()
             };
+            let foreign = if is_foreign {
+                BitFlags::from_flag(TableProperties::IsForeignTable)
+            } else {
+                BitFlags::empty()
+            };
+
+            let unlogged = if is_unlogged {
+                BitFlags::from_flag(TableProperties::Unlogged)
+            } else {
+                BitFlags::empty()
+            };
 
             let constraints_key = (namespace.clone(), cloned_name);
 
@@ -828,7 +1056,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let id = sql_schema.push_table_with_properties(
                 table_name,
                 sql_schema.get_namespace_id(&namespace).unwrap(),
-                partition | subclass | row_level_security,
+                partition | subclass | row_level_security | foreign | unlogged,
                 description,
             );
 
@@ -886,10 +1114,11 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
-    async fn get_columns(&self, sql_schema: &mut SqlSchema) -> DescriberResult<()> {
+    async fn get_columns(&self, sql_schema: &mut SqlSchema, pg_ext: &mut PostgresSchemaExt) -> DescriberResult<()> {
         let namespaces = &sql_schema.namespaces;
         let mut table_defaults = Vec::new();
         let mut view_defaults = Vec::new();
+        let mut table_storage = Vec::new();
 
         let is_visible_clause = if self.is_cockroach() {
             " AND info.is_hidden = 'NO'"
@@ -915,9 +1144,12 @@ impl<'a> SqlSchemaDescriber<'a> {
                 info.is_nullable,
                 info.is_identity,
                 info.character_maximum_length,
-                col_description(att.attrelid, ordinal_position) AS description
+                col_description(att.attrelid, ordinal_position) AS description,
+                att.attstorage,
+                typ.typstorage
             FROM information_schema.columns info
             JOIN pg_attribute att ON att.attname = info.column_name
+            JOIN pg_type typ ON typ.oid = att.atttypid
             JOIN (
                  SELECT pg_class.oid, relname, pg_namespace.nspname as namespace
                  FROM pg_class
@@ -975,7 +1207,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let description = col.get_string("description");
 
             let auto_increment = is_identity
-                || matches!(default.as_ref().map(|d| &d.kind), Some(DefaultKind::Sequence(_)))
+                || matches!(default.as_ref().map(|d| &d.kind), Some(DefaultKind::Sequence { .. }))
                 || (self.is_cockroach()
                     && matches!(
                         default.as_ref().map(|d| &d.kind),
@@ -985,6 +1217,21 @@ impl<'a> SqlSchemaDescriber<'a> {
             match container_id {
                 Either::Left(table_id) => {
                     table_defaults.push((table_id, default));
+
+                    // CockroachDB doesn't support TOAST, so its emulated attstorage/typstorage
+                    // catalog columns aren't meaningful here.
+                    let storage = if self.is_cockroach() {
+                        None
+                    } else {
+                        match (col.get_char("attstorage"), col.get_char("typstorage")) {
+                            (Some(attstorage), Some(typstorage)) if attstorage != typstorage => {
+                                PostgresColumnStorage::from_attstorage(attstorage)
+                            }
+                            _ => None,
+                        }
+                    };
+
+                    table_storage.push((table_id, storage));
                 }
                 Either::Right(view_id) => {
                     view_defaults.push((view_id, default));
@@ -1016,6 +1263,7 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         table_defaults.sort_by_key(|(table_id, _)| *table_id);
         view_defaults.sort_by_key(|(view_id, _)| *view_id);
+        table_storage.sort_by_key(|(table_id, _)| *table_id);
 
         for (i, (_, default)) in table_defaults.into_iter().enumerate() {
             if let Some(default) = default {
@@ -1023,6 +1271,12 @@ impl<'a> SqlSchemaDescriber<'a> {
             }
         }
 
+        for (i, (_, storage)) in table_storage.into_iter().enumerate() {
+            if let Some(storage) = storage {
+                pg_ext.column_storage.push((TableColumnId(i as u32), storage));
+            }
+        }
+
         for (i, (_, default)) in view_defaults.into_iter().enumerate() {
             if let Some(default) = default {
                 sql_schema.push_view_default_value(ViewColumnId(i as u32), default);
@@ -1294,7 +1548,17 @@ impl<'a> SqlSchemaDescriber<'a> {
 
             match constraint_type {
                 'c' => {
-                    sql_schema.check_constraints.push((table_id, constraint_name));
+                    // The columns referenced by the check expression, e.g. both `start` and `end`
+                    // for `CHECK (start < end)`. Constraints on constant expressions that
+                    // reference no column (`CHECK (true)`) yield an empty list.
+                    let columns = row
+                        .get_string_array("constraint_columns")
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|column_name| sql_schema.walk(table_id).column(&column_name).map(|c| c.id))
+                        .collect();
+
+                    sql_schema.check_constraints.push((table_id, constraint_name, columns));
                 }
                 'x' => {
                     pg_ext.exclude_constraints.push((table_id, constraint_name));
@@ -1303,12 +1567,44 @@ impl<'a> SqlSchemaDescriber<'a> {
             }
         }
 
-        sql_schema.check_constraints.sort_by_key(|(id, _)| *id);
+        sql_schema.check_constraints.sort_by_key(|(id, _, _)| *id);
         pg_ext.exclude_constraints.sort_by_key(|(id, _)| *id);
 
         Ok(())
     }
 
+    /// Fetch the `COMMENT ON CONSTRAINT` text for every constraint that has one, regardless of
+    /// its kind (primary key, foreign key, unique, check or exclude).
+    async fn get_constraint_comments(
+        &self,
+        table_names: &IndexMap<(String, String), TableId>,
+        sql_schema: &SqlSchema,
+        pg_ext: &mut PostgresSchemaExt,
+    ) -> DescriberResult<()> {
+        let namespaces = &sql_schema.namespaces;
+        let sql = include_str!("postgres/constraint_comments_query.sql");
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+
+        for row in rows {
+            let namespace = row.get_expect_string("namespace");
+            let table_name = row.get_expect_string("table_name");
+            let constraint_name = row.get_expect_string("constraint_name");
+            let comment = row.get_expect_string("comment");
+
+            let table_id = match table_names.get(&(namespace, table_name)) {
+                Some(id) => *id,
+                None => continue,
+            };
+
+            pg_ext.constraint_comments.push((table_id, constraint_name, comment));
+        }
+
+        pg_ext.constraint_comments.sort_by_key(|(id, _, _)| *id);
+
+        Ok(())
+    }
+
     async fn get_indices(
         &self,
         table_ids: &IndexMap<(String, String), TableId>,
@@ -1380,7 +1676,9 @@ impl<'a> SqlSchemaDescriber<'a> {
                   maximum_value::INT8 AS max_value,
                   increment::INT8 AS increment_by,
                   (CASE cycle_option WHEN 'yes' THEN TRUE ELSE FALSE END) AS cycle,
-                  0::INT8 AS cache_size
+                  -- information_schema has no cache size; report Postgres' own default (1)
+                  -- rather than 0, so an unremarkable sequence doesn't look customized.
+                  1::INT8 AS cache_size
               FROM information_schema.sequences
               WHERE sequence_schema = ANY ( $1 )
               ORDER BY sequence_name
@@ -1388,24 +1686,210 @@ impl<'a> SqlSchemaDescriber<'a> {
         };
 
         let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
-        let sequences = rows.into_iter().map(|seq| Sequence {
-            namespace_id: sql_schema
-                .get_namespace_id(&seq.get_expect_string("namespace"))
-                .unwrap(),
-            name: seq.get_expect_string("sequence_name"),
-            start_value: seq.get_expect_i64("start_value"),
-            min_value: seq.get_expect_i64("min_value"),
-            max_value: seq.get_expect_i64("max_value"),
-            increment_by: seq.get_expect_i64("increment_by"),
-            cycle: seq.get_expect_bool("cycle"),
-            cache_size: seq.get_expect_i64("cache_size"),
-            r#virtual: false,
+        let owners = self.get_sequence_owners(sql_schema, namespaces).await?;
+        let sequences = rows.into_iter().map(|seq| {
+            let namespace = seq.get_expect_string("namespace");
+            let name = seq.get_expect_string("sequence_name");
+            let owned_by = owners.get(&(namespace.clone(), name.clone())).copied();
+
+            Sequence {
+                namespace_id: sql_schema.get_namespace_id(&namespace).unwrap(),
+                name,
+                start_value: seq.get_expect_i64("start_value"),
+                min_value: seq.get_expect_i64("min_value"),
+                max_value: seq.get_expect_i64("max_value"),
+                increment_by: seq.get_expect_i64("increment_by"),
+                cycle: seq.get_expect_bool("cycle"),
+                cache_size: seq.get_expect_i64("cache_size"),
+                r#virtual: false,
+                owned_by,
+            }
         });
         postgres_ext.sequences.extend(sequences);
 
         Ok(())
     }
 
+    /// Reads `pg_depend` for the "auto" dependency (`deptype = 'a'`) that Postgres records from a
+    /// sequence to the column it's `OWNED BY`. This is what `SERIAL`/`BIGSERIAL` columns set up
+    /// implicitly, and what makes the sequence get dropped along with its owning column.
+    async fn get_sequence_owners(
+        &self,
+        sql_schema: &SqlSchema,
+        namespaces: &[String],
+    ) -> DescriberResult<HashMap<(String, String), TableColumnId>> {
+        let sql = indoc! {r#"
+            SELECT
+                seq_ns.nspname AS sequence_namespace,
+                seq_cl.relname AS sequence_name,
+                tab_ns.nspname AS table_namespace,
+                tab_cl.relname AS table_name,
+                att.attname AS column_name
+            FROM pg_depend dep
+            INNER JOIN pg_class seq_cl ON seq_cl.oid = dep.objid AND seq_cl.relkind = 'S'
+            INNER JOIN pg_namespace seq_ns ON seq_ns.oid = seq_cl.relnamespace
+            INNER JOIN pg_class tab_cl ON tab_cl.oid = dep.refobjid
+            INNER JOIN pg_namespace tab_ns ON tab_ns.oid = tab_cl.relnamespace
+            INNER JOIN pg_attribute att ON att.attrelid = dep.refobjid AND att.attnum = dep.refobjsubid
+            WHERE dep.deptype = 'a'
+            AND seq_ns.nspname = ANY ( $1 )
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+        let mut owners = HashMap::new();
+
+        for row in rows.into_iter() {
+            let sequence_namespace = row.get_expect_string("sequence_namespace");
+            let sequence_name = row.get_expect_string("sequence_name");
+            let table_namespace = row.get_expect_string("table_namespace");
+            let table_name = row.get_expect_string("table_name");
+            let column_name = row.get_expect_string("column_name");
+
+            let Some(column_id) = sql_schema
+                .table_walker_ns(&table_namespace, &table_name)
+                .and_then(|table| table.column(&column_name))
+                .map(|column| column.id)
+            else {
+                continue;
+            };
+
+            owners.insert((sequence_namespace, sequence_name), column_id);
+        }
+
+        Ok(owners)
+    }
+
+    /// Reads `pg_class.relreplident`, resolving the backing index name for `USING INDEX`
+    /// (`relreplident = 'i'`) via `pg_index.indisreplident`. Only non-default (`'d'`) settings
+    /// are recorded, matching the sparse storage used for the other `PostgresSchemaExt` fields.
+    async fn get_replica_identities(
+        &self,
+        sql_schema: &SqlSchema,
+        pg_ext: &mut PostgresSchemaExt,
+    ) -> DescriberResult<()> {
+        let namespaces = &sql_schema.namespaces;
+
+        let sql = indoc! {r#"
+            SELECT
+                pg_namespace.nspname AS namespace,
+                pg_class.relname AS table_name,
+                pg_class.relreplident AS replica_identity,
+                replident_index.relname AS replica_identity_index_name
+            FROM pg_class
+            INNER JOIN pg_namespace ON pg_class.relnamespace = pg_namespace.oid
+            LEFT JOIN pg_index ON pg_index.indrelid = pg_class.oid AND pg_index.indisreplident
+            LEFT JOIN pg_class replident_index ON replident_index.oid = pg_index.indexrelid
+            WHERE pg_class.relkind = 'r'
+            AND pg_namespace.nspname = ANY ( $1 )
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+
+        for row in rows.into_iter() {
+            let namespace = row.get_expect_string("namespace");
+            let table_name = row.get_expect_string("table_name");
+            let replica_identity = row.get_expect_char("replica_identity");
+            let index_name = row.get_string("replica_identity_index_name");
+
+            let Some(replica_identity) = ReplicaIdentity::from_relreplident(replica_identity, index_name) else {
+                continue;
+            };
+
+            let Some(table_id) = sql_schema.table_walker_ns(&namespace, &table_name).map(|table| table.id) else {
+                continue;
+            };
+
+            pg_ext.replica_identities.push((table_id, replica_identity));
+        }
+
+        Ok(())
+    }
+
+    /// Samples `pg_stats` for the fraction of `NULL` values per column. Only ever called when
+    /// [`Circumstances::CollectColumnStatistics`] is set: `pg_stats` is only populated for
+    /// columns Postgres has run `ANALYZE` on, so this is best-effort and meant to inform
+    /// introspection suggestions (e.g. making a column optional), not to be relied on for
+    /// correctness.
+    async fn get_column_statistics(
+        &self,
+        sql_schema: &SqlSchema,
+        pg_ext: &mut PostgresSchemaExt,
+    ) -> DescriberResult<()> {
+        let namespaces = &sql_schema.namespaces;
+
+        let sql = indoc! {r#"
+            SELECT
+                schemaname AS namespace,
+                tablename AS table_name,
+                attname AS column_name,
+                null_frac
+            FROM pg_stats
+            WHERE schemaname = ANY ( $1 )
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+
+        for row in rows.into_iter() {
+            let namespace = row.get_expect_string("namespace");
+            let table_name = row.get_expect_string("table_name");
+            let column_name = row.get_expect_string("column_name");
+            let null_frac = row.get_f64("null_frac");
+
+            let Some(null_frac) = null_frac else { continue };
+
+            let Some(column_id) = sql_schema
+                .table_walker_ns(&namespace, &table_name)
+                .and_then(|table| table.column(&column_name))
+                .map(|column| column.id)
+            else {
+                continue;
+            };
+
+            pg_ext.column_null_fraction.push((column_id, null_frac));
+        }
+
+        Ok(())
+    }
+
+    /// Collect the table-level privileges the current role has on each table, from
+    /// `information_schema.role_table_grants`. Column-level privileges
+    /// (`information_schema.role_column_grants`) are not collected: nothing in this describer
+    /// needs them yet, and they would need their own opt-in pass.
+    async fn get_privileges(&self, sql_schema: &SqlSchema, pg_ext: &mut PostgresSchemaExt) -> DescriberResult<()> {
+        let namespaces = &sql_schema.namespaces;
+
+        let sql = indoc! {r#"
+            SELECT
+                table_schema AS namespace,
+                table_name,
+                privilege_type
+            FROM information_schema.role_table_grants
+            WHERE table_schema = ANY ( $1 )
+                AND grantee = current_user
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+        let mut privileges: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+
+        for row in rows.into_iter() {
+            let namespace = row.get_expect_string("namespace");
+            let table_name = row.get_expect_string("table_name");
+            let privilege_type = row.get_expect_string("privilege_type");
+
+            privileges.entry((namespace, table_name)).or_default().push(privilege_type);
+        }
+
+        for ((namespace, table_name), privilege_types) in privileges {
+            let Some(table_id) = sql_schema.table_walker_ns(&namespace, &table_name).map(|table| table.id) else {
+                continue;
+            };
+
+            pg_ext.table_privileges.push((table_id, privilege_types));
+        }
+
+        Ok(())
+    }
+
     async fn get_enums(&self, sql_schema: &mut SqlSchema) -> DescriberResult<()> {
         let namespaces = &sql_schema.namespaces;
 
@@ -1448,6 +1932,12 @@ impl<'a> SqlSchemaDescriber<'a> {
     }
 }
 
+fn mark_virtual_sequence(default: &mut DefaultValue, pg_ext: &PostgresSchemaExt) {
+    if let DefaultKind::Sequence { name, r#virtual } = &mut default.kind {
+        *r#virtual = pg_ext.get_sequence(name).map(|(_, sequence)| sequence.r#virtual).unwrap_or(false);
+    }
+}
+
 fn group_next_index<T>(result_rows: &mut Vec<ResultRow>, index_rows: &mut Peekable<T>)
 where
     T: Iterator<Item = ResultRow>,
@@ -1549,6 +2039,18 @@ fn index_from_row(
                     .insert(Constraint::Index(index_id), constraint_options);
             }
 
+            if let Some(fillfactor) = row
+                .get_string_array("index_reloptions")
+                .unwrap_or_default()
+                .into_iter()
+                .find_map(|opt| {
+                    let (key, value) = opt.split_once('=')?;
+                    (key == "fillfactor").then(|| value.parse().ok())?
+                })
+            {
+                pg_ext.index_fillfactor.push((index_id, fillfactor));
+            }
+
             current_index = Some(index_id);
         }
 
@@ -1668,6 +2170,13 @@ fn get_column_type_postgresql(row: &ResultRow, schema: &SqlSchema) -> ColumnType
         "tsvector" | "_tsvector" => unsupported_type(),
         "txid_snapshot" | "_txid_snapshot" => unsupported_type(),
         "inet" | "_inet" => (String, Some(PostgresType::Inet)),
+        // range types
+        "int4range" | "_int4range" => (String, Some(PostgresType::Int4Range)),
+        "int8range" | "_int8range" => (String, Some(PostgresType::Int8Range)),
+        "numrange" | "_numrange" => (String, Some(PostgresType::NumRange)),
+        "tsrange" | "_tsrange" => (String, Some(PostgresType::TsRange)),
+        "tstzrange" | "_tstzrange" => (String, Some(PostgresType::TstzRange)),
+        "daterange" | "_daterange" => (String, Some(PostgresType::DateRange)),
         //geometric
         "box" | "_box" => unsupported_type(),
         "circle" | "_circle" => unsupported_type(),
@@ -2,9 +2,10 @@
 
 use crate::{
     getters::Getter, ids::*, parsers::Parser, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue,
-    DescriberResult, ForeignKeyAction, Lazy, PrismaValue, Regex, SQLSortOrder, SqlSchema,
+    DescriberResult, ForeignKeyAction, Lazy, PrismaValue, Regex, SQLSortOrder, SqlSchema, TableProperties,
 };
 use either::Either;
+use enumflags2::BitFlags;
 use indexmap::IndexMap;
 use quaint::{
     ast::{Value, ValueType},
@@ -16,6 +17,10 @@ use tracing::trace;
 #[cfg(feature = "sqlite-native")]
 pub(crate) mod native;
 
+/// The database access the describer needs. Deliberately narrower than [`quaint::prelude::Queryable`]:
+/// it only exposes read operations, so a describer can never issue a statement that mutates the
+/// database or its session state, even by accident. This makes it safe to point a describer at a
+/// connection whose role only has read privileges.
 #[async_trait::async_trait]
 pub trait Connection {
     async fn query_raw<'a>(
@@ -137,7 +142,17 @@ impl<'a> SqlSchemaDescriber<'a> {
 
             match r#type.as_str() {
                 "table" => {
-                    let id = schema.push_table(name, Default::default(), None);
+                    let mut properties = BitFlags::empty();
+
+                    if is_without_rowid(definition.as_deref()) {
+                        properties |= TableProperties::WithoutRowid;
+                    }
+
+                    if has_explicit_autoincrement(definition.as_deref()) {
+                        properties |= TableProperties::HasExplicitAutoincrement;
+                    }
+
+                    let id = schema.push_table_with_properties(name, Default::default(), properties, None);
                     map.insert(cloned_name, Either::Left(id));
                 }
                 "view" => {
@@ -575,6 +590,23 @@ fn is_table_ignored(table_name: &str) -> bool {
     SQLITE_IGNORED_TABLES.iter().any(|table| table_name == *table)
 }
 
+/// Whether the `CREATE TABLE` statement, as returned by `sqlite_master`, declares the table
+/// `WITHOUT ROWID`.
+fn is_without_rowid(definition: Option<&str>) -> bool {
+    definition
+        .map(|def| def.trim_end().trim_end_matches(';').trim_end())
+        .is_some_and(|def| def.to_lowercase().ends_with("without rowid"))
+}
+
+/// Whether the `CREATE TABLE` statement, as returned by `sqlite_master`, declares its integer
+/// primary key column with the explicit `AUTOINCREMENT` keyword. SQLite only allows this keyword
+/// once per table, on the single `INTEGER PRIMARY KEY` column, so a text search is unambiguous.
+fn has_explicit_autoincrement(definition: Option<&str>) -> bool {
+    static AUTOINCREMENT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\bautoincrement\b"#).unwrap());
+
+    definition.is_some_and(|def| AUTOINCREMENT_RE.is_match(def))
+}
+
 /// See https://www.sqlite.org/fileformat2.html
 /// + Cloudflare D1 specific tables
 const SQLITE_IGNORED_TABLES: &[&str] = &[
@@ -2,6 +2,7 @@
 
 #![deny(missing_docs)]
 
+mod check_constraint;
 mod column;
 mod default;
 mod r#enum;
@@ -14,6 +15,7 @@ mod view;
 
 use std::ops::Range;
 
+pub use check_constraint::CheckConstraintWalker;
 pub use column::{ColumnWalker, IndexColumnWalker, TableColumnWalker, ViewColumnWalker};
 pub use default::{DefaultValueWalker, TableDefaultValueWalker, ViewDefaultValueWalker};
 pub use foreign_key::ForeignKeyWalker;
@@ -0,0 +1,26 @@
+use crate::{CheckConstraintId, TableColumnId, TableColumnWalker, TableId, TableWalker, Walker};
+
+/// Traverse a check constraint.
+pub type CheckConstraintWalker<'a> = Walker<'a, CheckConstraintId>;
+
+impl<'a> CheckConstraintWalker<'a> {
+    fn get(self) -> &'a (TableId, String, Vec<TableColumnId>) {
+        &self.schema.check_constraints[self.id.0 as usize]
+    }
+
+    /// The name of the check constraint.
+    pub fn name(self) -> &'a str {
+        &self.get().1
+    }
+
+    /// Traverse to the table the check constraint belongs to.
+    pub fn table(self) -> TableWalker<'a> {
+        self.walk(self.get().0)
+    }
+
+    /// The columns referenced by the check constraint's expression, e.g. both `start` and `end`
+    /// for `CHECK (start < end)`. May be empty if the referenced columns could not be determined.
+    pub fn columns(self) -> impl ExactSizeIterator<Item = TableColumnWalker<'a>> {
+        self.get().2.iter().map(move |&column_id| self.walk(column_id))
+    }
+}
@@ -114,4 +114,21 @@ impl<'a> TableColumnWalker<'a> {
     pub fn table(self) -> TableWalker<'a> {
         self.walk(self.get().0)
     }
+
+    /// The zero-based position of the column among the other columns of its table, in
+    /// declaration order.
+    pub fn position(self) -> usize {
+        self.table().columns().position(|col| col.id == self.id).unwrap()
+    }
+
+    /// The column immediately preceding this one in the table, if any.
+    pub fn previous_sibling(self) -> Option<TableColumnWalker<'a>> {
+        let position = self.position();
+
+        if position == 0 {
+            None
+        } else {
+            self.table().columns().nth(position - 1)
+        }
+    }
 }
@@ -32,7 +32,7 @@ impl<'a> DefaultValueWalker<'a> {
     /// If the value is a squence, return it
     pub fn as_sequence(self) -> Option<&'a str> {
         match self.kind() {
-            DefaultKind::Sequence(name) => Some(name),
+            DefaultKind::Sequence { name, .. } => Some(name),
             _ => None,
         }
     }
@@ -49,7 +49,12 @@ impl<'a> DefaultValueWalker<'a> {
 
     /// True if referencing a sequence
     pub fn is_sequence(&self) -> bool {
-        matches!(self.kind(), DefaultKind::Sequence(_))
+        matches!(self.kind(), DefaultKind::Sequence { .. })
+    }
+
+    /// True if referencing a CockroachDB virtual sequence.
+    pub fn is_virtual_sequence(&self) -> bool {
+        matches!(self.kind(), DefaultKind::Sequence { r#virtual: true, .. })
     }
 
     /// True if value generation is handled in the database
@@ -1,4 +1,4 @@
-use crate::{Enum, EnumId, EnumVariant, EnumVariantId, Walker};
+use crate::{ColumnTypeFamily, Enum, EnumId, EnumVariant, EnumVariantId, TableColumnId, TableColumnWalker, Walker};
 
 /// Traverse an enum.
 pub type EnumWalker<'a> = Walker<'a, EnumId>;
@@ -40,6 +40,19 @@ impl<'a> EnumWalker<'a> {
     pub fn description(self) -> Option<&'a str> {
         self.get().description.as_deref()
     }
+
+    /// Every table column whose type is this enum. Unlike
+    /// [`SqlSchema::enum_used_in_tables`](crate::SqlSchema::enum_used_in_tables), which only says
+    /// whether any column uses it, this returns the columns themselves, so callers planning an
+    /// enum change know exactly what it would affect.
+    pub fn using_columns(self) -> impl Iterator<Item = TableColumnWalker<'a>> {
+        self.schema
+            .table_columns
+            .iter()
+            .enumerate()
+            .filter(move |(_, (_, column))| column.tpe.family == ColumnTypeFamily::Enum(self.id))
+            .map(move |(idx, _)| self.walk(TableColumnId(idx as u32)))
+    }
 }
 
 impl<'a> EnumVariantWalker<'a> {
@@ -1,8 +1,9 @@
+use std::collections::HashMap;
 use std::ops::Range;
 
 use crate::{
-    ForeignKeyId, ForeignKeyWalker, IndexColumnWalker, IndexId, IndexWalker, NamespaceId, Table, TableColumnId,
-    TableColumnWalker, TableId, TableProperties, Walker,
+    ColumnTypeFamily, ForeignKeyId, ForeignKeyWalker, IndexColumnWalker, IndexId, IndexWalker, NamespaceId, Table,
+    TableColumnId, TableColumnWalker, TableId, TableProperties, Walker,
 };
 
 /// Traverse a table.
@@ -110,22 +111,43 @@ impl<'a> TableWalker<'a> {
         self.table().properties.contains(TableProperties::HasRowLevelSecurity)
     }
 
+    /// Was the table created with `WITHOUT ROWID` (SQLite only)?
+    pub fn is_without_rowid(self) -> bool {
+        self.table().properties.contains(TableProperties::WithoutRowid)
+    }
+
+    /// Is the table a foreign table (Postgres only)?
+    pub fn is_foreign_table(self) -> bool {
+        self.table().properties.contains(TableProperties::IsForeignTable)
+    }
+
+    /// Was the table created with `UNLOGGED` (Postgres only)?
+    pub fn is_unlogged(self) -> bool {
+        self.table().properties.contains(TableProperties::Unlogged)
+    }
+
+    /// Was the table's single integer primary key column declared with the explicit
+    /// `AUTOINCREMENT` keyword, as opposed to implicitly aliasing the rowid (SQLite only)?
+    pub fn has_explicit_autoincrement(self) -> bool {
+        self.table().properties.contains(TableProperties::HasExplicitAutoincrement)
+    }
+
     /// Does the table have check constraints?
     pub fn has_check_constraints(self) -> bool {
         self.schema
             .check_constraints
-            .binary_search_by_key(&self.id, |(id, _)| *id)
+            .binary_search_by_key(&self.id, |(id, _, _)| *id)
             .is_ok()
     }
 
     /// The check constraint names for the table.
     pub fn check_constraints(self) -> impl ExactSizeIterator<Item = &'a str> {
-        let low = self.schema.check_constraints.partition_point(|(id, _)| *id < self.id);
-        let high = self.schema.check_constraints[low..].partition_point(|(id, _)| *id <= self.id);
+        let low = self.schema.check_constraints.partition_point(|(id, _, _)| *id < self.id);
+        let high = self.schema.check_constraints[low..].partition_point(|(id, _, _)| *id <= self.id);
 
         self.schema.check_constraints[low..low + high]
             .iter()
-            .map(|(_, name)| name.as_str())
+            .map(|(_, name, _)| name.as_str())
     }
 
     /// Description (comment) of the table.
@@ -133,6 +155,18 @@ impl<'a> TableWalker<'a> {
         self.table().description.as_deref()
     }
 
+    /// Count the table's columns by type family, e.g. to spot tables heavy in JSON or
+    /// Unsupported columns.
+    pub fn type_family_summary(self) -> HashMap<ColumnTypeFamily, usize> {
+        let mut summary: HashMap<ColumnTypeFamily, usize> = HashMap::new();
+
+        for column in self.columns() {
+            *summary.entry(column.column_type_family().clone()).or_insert(0) += 1;
+        }
+
+        summary
+    }
+
     /// Reference to the underlying `Table` struct.
     fn table(self) -> &'a Table {
         &self.schema.tables[self.id.0 as usize]
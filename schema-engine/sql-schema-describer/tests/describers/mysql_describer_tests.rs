@@ -3631,3 +3631,24 @@ fn index_length_and_sorting_is_handled(api: TestApi) {
     assert_eq!(Some(10), columns[0].length());
     assert_eq!(Some(20), columns[1].length());
 }
+
+#[test_connector(tags(Mysql))]
+fn column_character_set_and_collation_are_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            a  VARCHAR(191) CHARACTER SET latin1 COLLATE latin1_bin NOT NULL
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("a").unwrap();
+
+    let mysql_ext: &sql_schema_describer::mysql::MysqlSchemaExt = schema.downcast_connector_data();
+
+    assert_eq!(Some("latin1"), mysql_ext.column_character_set(column.id));
+    assert_eq!(Some("latin1_bin"), mysql_ext.column_collation(column.id));
+}
@@ -3,7 +3,10 @@ mod cockroach_describer_tests;
 use crate::test_api::*;
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
-use sql_schema_describer::{postgres::PostgresSchemaExt, *};
+use sql_schema_describer::{
+    postgres::{Circumstances, PostgresColumnStorage, PostgresSchemaExt, ReplicaIdentity},
+    *,
+};
 
 #[test_connector(tags(Postgres))]
 fn postgres_skips_nonexisting_namespaces(api: TestApi) {
@@ -60,6 +63,30 @@ fn postgres_many_namespaces(api: TestApi) {
         .assert_namespace("three");
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn foreign_tables_are_labeled_and_excluded_from_diffs(api: TestApi) {
+    let full_sql = r#"
+        CREATE TABLE local ( id INTEGER PRIMARY KEY );
+
+        CREATE EXTENSION IF NOT EXISTS file_fdw;
+        CREATE SERVER local_files FOREIGN DATA WRAPPER file_fdw;
+        CREATE FOREIGN TABLE remote (
+            id INTEGER
+        ) SERVER local_files OPTIONS (filename '/dev/null', format 'csv');
+    "#;
+
+    api.raw_cmd(full_sql);
+    let schema = api.describe();
+
+    let local = schema.table_walker("local").expect("local table should be described");
+    assert!(!local.is_foreign_table());
+
+    let remote = schema
+        .table_walker("remote")
+        .expect("foreign table should be described");
+    assert!(remote.is_foreign_table());
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn views_can_be_described(api: TestApi) {
     let full_sql = r#"
@@ -930,9 +957,10 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         9,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "User_bigserial_col_seq",
-                        ),
+                        kind: Sequence {
+                            name: "User_bigserial_col_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -941,9 +969,10 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         28,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "User_smallserial_col_seq",
-                        ),
+                        kind: Sequence {
+                            name: "User_smallserial_col_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -952,9 +981,10 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         29,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "User_serial_col_seq",
-                        ),
+                        kind: Sequence {
+                            name: "User_serial_col_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -963,9 +993,10 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                         30,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "User_primary_col_seq",
-                        ),
+                        kind: Sequence {
+                            name: "User_primary_col_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -1050,6 +1081,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 0,
                     virtual: false,
+                    owned_by: None,
                 },
                 Sequence {
                     namespace_id: NamespaceId(
@@ -1063,6 +1095,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 0,
                     virtual: false,
+                    owned_by: None,
                 },
                 Sequence {
                     namespace_id: NamespaceId(
@@ -1076,6 +1109,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 0,
                     virtual: false,
+                    owned_by: None,
                 },
                 Sequence {
                     namespace_id: NamespaceId(
@@ -1089,6 +1123,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 0,
                     virtual: false,
+                    owned_by: None,
                 },
             ],
             extensions: [
@@ -1099,6 +1134,8 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                     relocatable: false,
                 },
             ],
+            column_storage: [],
+            column_null_fraction: [],
         }
     "#]];
     expected_ext.assert_debug_eq(&ext);
@@ -1225,6 +1262,7 @@ fn postgres_sequences_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 0,
                     virtual: false,
+                    owned_by: None,
                 },
             ],
             extensions: [
@@ -1235,11 +1273,108 @@ fn postgres_sequences_must_work(api: TestApi) {
                     relocatable: false,
                 },
             ],
+            column_storage: [],
+            column_null_fraction: [],
         }
     "#]];
     expected_ext.assert_debug_eq(&ext);
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn postgres_sequence_ownership_must_be_captured(api: TestApi) {
+    let schema_name = api.schema_name();
+
+    api.raw_cmd(&format!(r#"CREATE TABLE "{schema_name}"."Book" (id INT4 NOT NULL)"#));
+    api.raw_cmd(&format!(r#"CREATE SEQUENCE "{schema_name}"."Book_id_seq""#));
+    api.raw_cmd(&format!(
+        r#"ALTER SEQUENCE "{schema_name}"."Book_id_seq" OWNED BY "{schema_name}"."Book"."id""#
+    ));
+
+    let schema = api.describe();
+    let ext = extract_ext(&schema);
+    let expected_ext = expect![[r#"
+        PostgresSchemaExt {
+            opclasses: [],
+            indexes: [],
+            expression_indexes: [],
+            index_null_position: {},
+            constraint_options: {},
+            table_options: [],
+            exclude_constraints: [],
+            constraint_comments: [],
+            index_fillfactor: [],
+            sequences: [
+                Sequence {
+                    namespace_id: NamespaceId(
+                        0,
+                    ),
+                    name: "Book_id_seq",
+                    start_value: 1,
+                    min_value: 1,
+                    max_value: 9223372036854775807,
+                    increment_by: 1,
+                    cycle: false,
+                    cache_size: 1,
+                    virtual: false,
+                    owned_by: Some(
+                        TableColumnId(
+                            0,
+                        ),
+                    ),
+                },
+            ],
+            extensions: [
+                DatabaseExtension {
+                    name: "plpgsql",
+                    schema: "pg_catalog",
+                    version: "1.0",
+                    relocatable: false,
+                },
+            ],
+            column_storage: [],
+            column_null_fraction: [],
+            replica_identities: [],
+            table_privileges: [],
+        }
+    "#]];
+    expected_ext.assert_debug_eq(&ext);
+
+    // Dropping the owning column should drop the sequence right along with it, confirming the
+    // ownership we just introspected is the real, enforced Postgres relationship and not just an
+    // artifact of the column and sequence names matching.
+    api.raw_cmd(&format!(r#"ALTER TABLE "{schema_name}"."Book" DROP COLUMN "id""#));
+    let schema_after_drop = api.describe();
+    assert!(extract_ext(&schema_after_drop).sequences.is_empty());
+}
+
+#[test_connector(tags(Postgres))]
+fn multi_column_check_constraints_are_table_level(api: TestApi) {
+    let full_sql = r#"
+        CREATE TABLE "Booking" (
+            id INTEGER PRIMARY KEY,
+            start_date DATE NOT NULL,
+            end_date DATE NOT NULL,
+            CONSTRAINT date_range_check CHECK (start_date < end_date)
+        );
+    "#;
+
+    api.raw_cmd(full_sql);
+    let schema = api.describe();
+
+    let table = schema.table_walker("Booking").unwrap();
+    assert!(table.has_check_constraints());
+    assert_eq!(table.check_constraints().collect::<Vec<_>>(), vec!["date_range_check"]);
+
+    // The constraint is attributed to the table as a whole, not to `start_date` or `end_date`
+    // individually, and it notes both of the columns its expression references.
+    let check = schema.walk_check_constraints().next().unwrap();
+    assert_eq!(check.table().name(), "Booking");
+
+    let mut referenced_columns: Vec<_> = check.columns().map(|c| c.name().to_owned()).collect();
+    referenced_columns.sort();
+    assert_eq!(referenced_columns, vec!["end_date", "start_date"]);
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
     let schema = r#"
@@ -1579,6 +1714,237 @@ fn index_sort_order_is_handled(api: TestApi) {
     assert_eq!(Some(SQLSortOrder::Desc), columns[0].sort_order());
 }
 
+#[test_connector(tags(Postgres))]
+fn index_fillfactor_is_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            a  INT NOT NULL
+        );
+
+        CREATE INDEX foo ON A (a) WITH (fillfactor = 70);
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let index = table.indexes().nth(1).unwrap();
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+
+    assert_eq!(Some(70), pg_ext.index_fillfactor(index.id));
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn column_storage_is_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            a  TEXT,
+            b  TEXT
+        );
+
+        ALTER TABLE A ALTER COLUMN a SET STORAGE EXTERNAL;
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table = schema.table_walkers().next().unwrap();
+    let column_a = table.column("a").unwrap();
+    let column_b = table.column("b").unwrap();
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+
+    assert_eq!(
+        Some(PostgresColumnStorage::External),
+        pg_ext.column_storage(column_a.id)
+    );
+    assert_eq!(None, pg_ext.column_storage(column_b.id));
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn replica_identity_is_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            a  TEXT
+        );
+
+        CREATE TABLE B (
+            id INT PRIMARY KEY,
+            a  TEXT UNIQUE NOT NULL
+        );
+
+        CREATE TABLE C (
+            id INT PRIMARY KEY
+        );
+
+        ALTER TABLE A REPLICA IDENTITY FULL;
+        ALTER TABLE B REPLICA IDENTITY USING INDEX "B_a_key";
+        ALTER TABLE C REPLICA IDENTITY NOTHING;
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+    let table_a = schema.table_walker("A").unwrap();
+    let table_b = schema.table_walker("B").unwrap();
+    let table_c = schema.table_walker("C").unwrap();
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+
+    assert_eq!(Some(&ReplicaIdentity::Full), pg_ext.replica_identity(table_a.id));
+    assert_eq!(
+        Some(&ReplicaIdentity::Index("B_a_key".to_owned())),
+        pg_ext.replica_identity(table_b.id)
+    );
+    assert_eq!(Some(&ReplicaIdentity::Nothing), pg_ext.replica_identity(table_c.id));
+}
+
+// Statistics collection is opt-in (Circumstances::CollectColumnStatistics), so this test
+// constructs its own describer instead of going through TestApi::describe().
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn column_null_fraction_is_captured(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            mostly_null TEXT
+        );
+
+        INSERT INTO A (id, mostly_null) VALUES (1, 'a'), (2, NULL), (3, NULL), (4, NULL);
+
+        ANALYZE A;
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema_name = api.schema_name();
+    let schema = api.block_on(
+        sql_schema_describer::postgres::SqlSchemaDescriber::new(
+            api.database(),
+            Circumstances::CollectColumnStatistics.into(),
+        )
+        .describe(&[schema_name]),
+    );
+
+    let schema = schema.unwrap();
+    let table = schema.table_walkers().next().unwrap();
+    let column = table.column("mostly_null").unwrap();
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+    let null_fraction = pg_ext
+        .column_null_fraction(column.id)
+        .expect("null fraction should be populated for an analyzed column");
+
+    assert!(
+        null_fraction > 0.5,
+        "expected a high null fraction for a mostly-null column, got {null_fraction}"
+    );
+}
+
+// The describer only ever needs to `SELECT` from `pg_catalog`/`information_schema`, so it must
+// keep working when pointed at a connection whose role has no write privileges at all.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn describe_succeeds_with_a_read_only_role(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            name TEXT
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema_name = api.schema_name();
+    let role_name = format!("{}_ro", api.db_name());
+
+    api.raw_cmd(&format!(r#"DROP ROLE IF EXISTS "{role_name}""#));
+    api.raw_cmd(&format!(
+        r#"CREATE ROLE "{role_name}" WITH LOGIN PASSWORD 'read-only-role-test'"#
+    ));
+    api.raw_cmd(&format!(
+        r#"GRANT USAGE ON SCHEMA "{schema_name}" TO "{role_name}""#
+    ));
+    api.raw_cmd(&format!(
+        r#"GRANT SELECT ON ALL TABLES IN SCHEMA "{schema_name}" TO "{role_name}""#
+    ));
+
+    let mut read_only_url: url::Url = api.connection_string().parse().unwrap();
+    read_only_url.set_username(&role_name).unwrap();
+    read_only_url.set_password(Some("read-only-role-test")).unwrap();
+
+    let read_only_connection = api.block_on(Quaint::new(read_only_url.as_str())).unwrap();
+
+    let schema = api.block_on(
+        sql_schema_describer::postgres::SqlSchemaDescriber::new(&read_only_connection, Default::default())
+            .describe(&[schema_name]),
+    );
+
+    let schema = schema.expect("describe() must succeed against a read-only role");
+    assert!(schema.table_walkers().any(|table| table.name() == "A"));
+}
+
+// Privilege collection is opt-in (Circumstances::CollectPrivileges), so this test constructs its
+// own describer instead of going through TestApi::describe().
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn table_privileges_are_captured_for_a_limited_grant_role(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE A (
+            id INT PRIMARY KEY,
+            name TEXT
+        );
+
+        CREATE TABLE B (
+            id INT PRIMARY KEY
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema_name = api.schema_name();
+    let role_name = format!("{}_limited", api.db_name());
+
+    api.raw_cmd(&format!(r#"DROP ROLE IF EXISTS "{role_name}""#));
+    api.raw_cmd(&format!(
+        r#"CREATE ROLE "{role_name}" WITH LOGIN PASSWORD 'limited-role-test'"#
+    ));
+    api.raw_cmd(&format!(r#"GRANT USAGE ON SCHEMA "{schema_name}" TO "{role_name}""#));
+    api.raw_cmd(&format!(r#"GRANT SELECT, INSERT ON "A" TO "{role_name}""#));
+
+    let mut limited_url: url::Url = api.connection_string().parse().unwrap();
+    limited_url.set_username(&role_name).unwrap();
+    limited_url.set_password(Some("limited-role-test")).unwrap();
+
+    let limited_connection = api.block_on(Quaint::new(limited_url.as_str())).unwrap();
+
+    let schema = api.block_on(
+        sql_schema_describer::postgres::SqlSchemaDescriber::new(
+            &limited_connection,
+            Circumstances::CollectPrivileges.into(),
+        )
+        .describe(&[schema_name]),
+    );
+
+    let schema = schema.unwrap();
+    let table_a = schema.table_walker("A").unwrap();
+    let table_b = schema.table_walker("B").unwrap();
+
+    let pg_ext: &PostgresSchemaExt = schema.downcast_connector_data();
+
+    let mut privileges_on_a: Vec<&str> = pg_ext
+        .table_privileges(table_a.id)
+        .expect("privileges should be populated for a table with grants")
+        .iter()
+        .map(String::as_str)
+        .collect();
+    privileges_on_a.sort();
+
+    assert_eq!(privileges_on_a, vec!["INSERT", "SELECT"]);
+    assert_eq!(pg_ext.table_privileges(table_b.id), None);
+}
+
 #[test_connector(tags(Postgres))]
 fn index_sort_order_composite_type_desc_desc_is_handled(api: TestApi) {
     let sql = indoc! {r#"
@@ -1826,6 +2192,8 @@ fn extensions_are_described_correctly(api: TestApi) {
                     relocatable: false,
                 },
             ],
+            column_storage: [],
+            column_null_fraction: [],
         }
     "#]];
 
@@ -1958,9 +2326,10 @@ fn multiple_schemas_with_same_table_names_are_described(api: TestApi) {
                         0,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "Table_0_id_0_seq",
-                        ),
+                        kind: Sequence {
+                            name: "Table_0_id_0_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -1982,9 +2351,10 @@ fn multiple_schemas_with_same_table_names_are_described(api: TestApi) {
                         2,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "Table_0_id_1_seq",
-                        ),
+                        kind: Sequence {
+                            name: "Table_0_id_1_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -2349,9 +2719,10 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                         1,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "Table_0_id_0_seq",
-                        ),
+                        kind: Sequence {
+                            name: "Table_0_id_0_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -2360,9 +2731,10 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                         2,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "Table_1_id_1_seq",
-                        ),
+                        kind: Sequence {
+                            name: "Table_1_id_1_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -2371,9 +2743,10 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                         4,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "Table_0_id_2_seq",
-                        ),
+                        kind: Sequence {
+                            name: "Table_0_id_2_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -2382,9 +2755,10 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                         5,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "Table_1_id_3_seq",
-                        ),
+                        kind: Sequence {
+                            name: "Table_1_id_3_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -2393,9 +2767,10 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                         7,
                     ),
                     DefaultValue {
-                        kind: Sequence(
-                            "Table_2_id_4_seq",
-                        ),
+                        kind: Sequence {
+                            name: "Table_2_id_4_seq",
+                            r#virtual: false,
+                        },
                         constraint_name: None,
                     },
                 ),
@@ -2649,9 +3024,10 @@ fn multiple_schemas_are_described(api: TestApi) {
                         },
                         default: Some(
                             DefaultValue {
-                                kind: Sequence(
-                                    "Table_0_id_0_seq",
-                                ),
+                                kind: Sequence {
+                                    name: "Table_0_id_0_seq",
+                                    r#virtual: false,
+                                },
                                 constraint_name: None,
                             },
                         ),
@@ -2674,9 +3050,10 @@ fn multiple_schemas_are_described(api: TestApi) {
                         },
                         default: Some(
                             DefaultValue {
-                                kind: Sequence(
-                                    "Table_1_id_1_seq",
-                                ),
+                                kind: Sequence {
+                                    name: "Table_1_id_1_seq",
+                                    r#virtual: false,
+                                },
                                 constraint_name: None,
                             },
                         ),
@@ -2717,9 +3094,10 @@ fn multiple_schemas_are_described(api: TestApi) {
                         },
                         default: Some(
                             DefaultValue {
-                                kind: Sequence(
-                                    "Table_2_id_2_seq",
-                                ),
+                                kind: Sequence {
+                                    name: "Table_2_id_2_seq",
+                                    r#virtual: false,
+                                },
                                 constraint_name: None,
                             },
                         ),
@@ -2742,9 +3120,10 @@ fn multiple_schemas_are_described(api: TestApi) {
                         },
                         default: Some(
                             DefaultValue {
-                                kind: Sequence(
-                                    "Table_3_id_3_seq",
-                                ),
+                                kind: Sequence {
+                                    name: "Table_3_id_3_seq",
+                                    r#virtual: false,
+                                },
                                 constraint_name: None,
                             },
                         ),
@@ -2988,6 +3367,25 @@ fn multiple_schemas_are_described(api: TestApi) {
     expected_schema.assert_debug_eq(&schema);
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn describe_with_search_path_finds_tables_outside_the_default_schema(api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE SCHEMA "other_schema";
+        CREATE TABLE "other_schema"."Cat" ("id" SERIAL PRIMARY KEY);
+        "#,
+    );
+
+    // The table is invisible when the search path doesn't include its schema.
+    let schema = api.describe_with_search_path(api.schema_name());
+    assert!(schema.table_walker("Cat").is_none());
+
+    // Adding the schema to the search path finds it, without reconfiguring the connector.
+    let search_path = format!("{}, other_schema", api.schema_name());
+    let schema = api.describe_with_search_path(&search_path);
+    assert!(schema.table_walker("Cat").is_some());
+}
+
 fn extract_ext(schema: &SqlSchema) -> &PostgresSchemaExt {
     schema.downcast_connector_data()
 }
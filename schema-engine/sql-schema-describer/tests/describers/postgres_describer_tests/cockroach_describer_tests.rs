@@ -465,6 +465,7 @@ fn cockroachdb_22_1_sequences_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 1,
                     virtual: false,
+                    owned_by: None,
                 },
                 Sequence {
                     namespace_id: NamespaceId(
@@ -478,6 +479,7 @@ fn cockroachdb_22_1_sequences_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 7,
                     virtual: false,
+                    owned_by: None,
                 },
                 Sequence {
                     namespace_id: NamespaceId(
@@ -491,9 +493,11 @@ fn cockroachdb_22_1_sequences_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 1,
                     virtual: false,
+                    owned_by: None,
                 },
             ],
             extensions: [],
+            column_storage: [],
         }
     "#]];
     expected_ext.assert_debug_eq(&ext);
@@ -543,6 +547,7 @@ fn cockroachdb_22_2_sequences_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 1,
                     virtual: false,
+                    owned_by: None,
                 },
                 Sequence {
                     namespace_id: NamespaceId(
@@ -556,6 +561,7 @@ fn cockroachdb_22_2_sequences_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 7,
                     virtual: false,
+                    owned_by: None,
                 },
                 Sequence {
                     namespace_id: NamespaceId(
@@ -569,9 +575,11 @@ fn cockroachdb_22_2_sequences_must_work(api: TestApi) {
                     cycle: false,
                     cache_size: 1,
                     virtual: false,
+                    owned_by: None,
                 },
             ],
             extensions: [],
+            column_storage: [],
         }
     "#]];
     expected_ext.assert_debug_eq(&ext);
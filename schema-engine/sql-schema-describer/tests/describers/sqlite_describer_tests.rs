@@ -245,6 +245,40 @@ fn sqlite_foreign_key_on_delete_must_be_handled(api: TestApi) {
     }
 }
 
+#[test_connector(tags(Sqlite))]
+fn sqlite_foreign_key_on_update_must_be_handled(api: TestApi) {
+    use sql_schema_describer::ForeignKeyAction::*;
+    let sql = "
+        CREATE TABLE City (id INTEGER NOT NULL PRIMARY KEY);
+        CREATE TABLE User (
+            id INTEGER NOT NULL PRIMARY KEY,
+            city INTEGER REFERENCES City(id) ON UPDATE NO ACTION,
+            city_cascade INTEGER REFERENCES City(id) ON UPDATE CASCADE,
+            city_restrict INTEGER REFERENCES City (id) ON UPDATE RESTRICT,
+            city_set_default INTEGER REFERENCES City(id) ON UPDATE SET DEFAULT,
+            city_set_null INTEGER REFERENCES City(id) ON UPDATE SET NULL
+        )";
+
+    api.raw_cmd(sql);
+
+    let expectations = [
+        ("city", NoAction),
+        ("city_cascade", Cascade),
+        ("city_restrict", Restrict),
+        ("city_set_default", SetDefault),
+        ("city_set_null", SetNull),
+    ];
+
+    let schema = api.describe();
+    let table = schema.table_walker("User").unwrap();
+
+    for (colname, expected_action) in expectations.into_iter() {
+        let column = table.column(colname).unwrap().id;
+        let action = table.foreign_key_for_column(column).unwrap().on_update_action();
+        assert_eq!(action, expected_action);
+    }
+}
+
 #[test_connector(tags(Sqlite))]
 fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_indexes(api: TestApi) {
     let sql = r#"
@@ -745,3 +779,25 @@ fn integer_primary_keys_autoincrement(api: TestApi) {
 
     expected.assert_debug_eq(&found);
 }
+
+#[test_connector(tags(Sqlite))]
+fn explicit_autoincrement_is_distinguished_from_implicit_rowid(api: TestApi) {
+    let sql = indoc! {r#"
+        CREATE TABLE "A" (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT
+        );
+
+        CREATE TABLE "B" (
+            id INTEGER PRIMARY KEY,
+            name TEXT
+        );
+    "#};
+
+    api.raw_cmd(sql);
+
+    let schema = api.describe();
+
+    assert!(schema.table_walker("A").unwrap().has_explicit_autoincrement());
+    assert!(!schema.table_walker("B").unwrap().has_explicit_autoincrement());
+}
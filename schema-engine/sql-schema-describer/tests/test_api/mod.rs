@@ -16,17 +16,18 @@ use test_setup::*;
 pub struct TestApi {
     db_name: &'static str,
     database: Quaint,
+    connection_string: String,
     tags: BitFlags<Tags>,
 }
 
 impl TestApi {
     pub(crate) fn new(args: TestApiArgs) -> Self {
         let tags = args.tags();
-        let (db_name, conn) = if tags.contains(Tags::Mysql) {
+        let (db_name, conn, connection_string) = if tags.contains(Tags::Mysql) {
             let (db_name, cs) = tok(args.create_mysql_database());
-            (db_name, tok(Quaint::new(&cs)).unwrap())
+            (db_name, tok(Quaint::new(&cs)).unwrap(), cs)
         } else if tags.contains(Tags::Postgres) {
-            let (db_name, q, _) = tok(args.create_postgres_database());
+            let (db_name, q, cs) = tok(args.create_postgres_database());
             if tags.contains(Tags::CockroachDb) {
                 tok(q.raw_cmd(
                     r#"
@@ -36,12 +37,16 @@ impl TestApi {
                 ))
                 .unwrap();
             }
-            (db_name, q)
+            (db_name, q, cs)
         } else if tags.contains(Tags::Mssql) {
-            let (q, _cs) = tok(args.create_mssql_database());
-            (args.test_function_name(), q)
+            let (q, cs) = tok(args.create_mssql_database());
+            (args.test_function_name(), q, cs)
         } else if tags.contains(Tags::Sqlite) {
-            (args.test_function_name(), Quaint::new_in_memory().unwrap())
+            (
+                args.test_function_name(),
+                Quaint::new_in_memory().unwrap(),
+                args.database_url().to_owned(),
+            )
         } else {
             unreachable!()
         };
@@ -50,6 +55,7 @@ impl TestApi {
             db_name,
             tags: args.tags(),
             database: conn,
+            connection_string,
         }
     }
 
@@ -74,6 +80,22 @@ impl TestApi {
         tok(self.describe_impl(schemas)).unwrap()
     }
 
+    /// Postgres only: describe using a `search_path`-style, comma-separated list of schemas.
+    pub(crate) fn describe_with_search_path(&self, search_path: &str) -> SqlSchema {
+        use postgres::Circumstances;
+
+        tok(sql_schema_describer::postgres::SqlSchemaDescriber::new(
+            &self.database,
+            if self.tags.contains(Tags::CockroachDb) {
+                Circumstances::Cockroach.into()
+            } else {
+                Default::default()
+            },
+        )
+        .describe_with_search_path(search_path))
+        .unwrap()
+    }
+
     pub(crate) fn describe_error(&self) -> DescriberError {
         tok(self.describe_impl(&[self.schema_name()])).unwrap_err()
     }
@@ -135,6 +157,12 @@ impl TestApi {
         &self.database
     }
 
+    /// The connection string used to open `database()`. Useful for opening additional
+    /// connections against the same test database, e.g. under a different role.
+    pub(crate) fn connection_string(&self) -> &str {
+        &self.connection_string
+    }
+
     pub(crate) fn schema_name(&self) -> &str {
         self.database.connection_info().schema_name()
     }